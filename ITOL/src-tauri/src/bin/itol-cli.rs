@@ -0,0 +1,262 @@
+//! ITOL 헤드리스 실행기 - CI에서 페이지/북을 실행하고 리포트를 남긴다.
+//!
+//! 종료 코드: 0 = 모두 통과, 1 = 실패한 노드 있음, 2 = 인자/로드 오류
+
+use itol_lib::flow_runner::{FlowEvent, FlowGraph, FlowRunOptions, FlowRunReport, NodeStatus};
+use itol_lib::{create_sqlite, environment, flow_report, flow_runner, oracle_installer};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+const USAGE: &str = "Usage: itol-cli (--page <id> | --book <id> | --file <flow.json>) [options]
+
+Options:
+  --env <name>         Use variables of a saved environment
+  --env-file <path>    Load variables from JSON ({\"key\": \"value\"} or Postman environment)
+  --var <key=value>    Set a variable (repeatable, overrides --env and --env-file)
+  --junit <path>       Write a JUnit XML report
+  --json <path>        Write a JSON report
+  --db <path>          SQLite database to use instead of the app database
+  --fail-fast          Skip remaining nodes after the first failure
+  --quiet              Only print the summary
+  -h, --help           Show this help";
+
+enum Target {
+    Page(i32),
+    Book(i32),
+    File(PathBuf),
+}
+
+struct CliArgs {
+    target: Target,
+    env: Option<String>,
+    env_file: Option<PathBuf>,
+    vars: Vec<(String, String)>,
+    junit: Option<PathBuf>,
+    json: Option<PathBuf>,
+    db: Option<PathBuf>,
+    fail_fast: bool,
+    quiet: bool,
+}
+
+/// 인자 파싱 - 도움말 요청이면 Ok(None)
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Option<CliArgs>, String> {
+    let mut target = None;
+    let mut parsed = CliArgs {
+        target: Target::Page(0),
+        env: None,
+        env_file: None,
+        vars: Vec::new(),
+        junit: None,
+        json: None,
+        db: None,
+        fail_fast: false,
+        quiet: false,
+    };
+
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("Missing value for {}", arg));
+        let parse_id = |v: String| v.parse::<i32>().map_err(|_| format!("Invalid id: {}", v));
+        let next = match arg.as_str() {
+            "--page" => Some(Target::Page(parse_id(value()?)?)),
+            "--book" => Some(Target::Book(parse_id(value()?)?)),
+            "--file" => Some(Target::File(PathBuf::from(value()?))),
+            "--env" => {
+                parsed.env = Some(value()?);
+                None
+            }
+            "--env-file" => {
+                parsed.env_file = Some(PathBuf::from(value()?));
+                None
+            }
+            "--var" => {
+                let pair = value()?;
+                let (key, val) = pair
+                    .split_once('=')
+                    .ok_or_else(|| format!("Invalid --var (expected key=value): {}", pair))?;
+                parsed.vars.push((key.to_string(), val.to_string()));
+                None
+            }
+            "--junit" => {
+                parsed.junit = Some(PathBuf::from(value()?));
+                None
+            }
+            "--json" => {
+                parsed.json = Some(PathBuf::from(value()?));
+                None
+            }
+            "--db" => {
+                parsed.db = Some(PathBuf::from(value()?));
+                None
+            }
+            "--fail-fast" => {
+                parsed.fail_fast = true;
+                None
+            }
+            "--quiet" => {
+                parsed.quiet = true;
+                None
+            }
+            "-h" | "--help" => return Ok(None),
+            other => return Err(format!("Unknown argument: {}", other)),
+        };
+        if let Some(next) = next {
+            if target.replace(next).is_some() {
+                return Err("Only one of --page, --book or --file can be given".to_string());
+            }
+        }
+    }
+
+    parsed.target = target.ok_or("One of --page, --book or --file is required")?;
+    Ok(Some(parsed))
+}
+
+/// --env < --env-file < --var 순서로 덮어쓴 변수
+async fn load_variables(args: &CliArgs) -> Result<HashMap<String, String>, String> {
+    let mut variables = HashMap::new();
+    if let Some(name) = &args.env {
+        let env = environment::get_environment_by_name(name)
+            .await
+            .map_err(|e| format!("Environment '{}' not found: {}", name, e))?;
+        variables.extend(env.variables);
+    }
+    if let Some(path) = &args.env_file {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        variables.extend(flow_runner::parse_variables(&content)?);
+    }
+    variables.extend(args.vars.iter().cloned());
+    Ok(variables)
+}
+
+async fn load_graphs(target: &Target) -> Result<Vec<FlowGraph>, String> {
+    match target {
+        Target::Page(id) => Ok(vec![FlowGraph::load_page(*id).await?]),
+        Target::Book(id) => FlowGraph::load_book(*id).await,
+        Target::File(path) => {
+            let content = std::fs::read_to_string(path)
+                .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+            let title = path.file_stem().and_then(|s| s.to_str()).unwrap_or("flow");
+            FlowGraph::from_export(&content, title)
+        }
+    }
+}
+
+fn print_event(event: FlowEvent<'_>) {
+    match event {
+        FlowEvent::NodeStarted { flow, node_name, .. } => println!("▶ [{}] {}", flow, node_name),
+        FlowEvent::NodeFinished { result, .. } => match result.status {
+            NodeStatus::Passed => println!("  ✔ {} ({} ms)", result.node_name, result.duration_ms),
+            NodeStatus::Failed | NodeStatus::Error => println!(
+                "  ✘ {} ({} ms): {}",
+                result.node_name,
+                result.duration_ms,
+                result.error.as_deref().unwrap_or("failed")
+            ),
+            NodeStatus::Skipped => println!(
+                "  ↷ {}: {}",
+                result.node_name,
+                result.logs.first().map(String::as_str).unwrap_or("skipped")
+            ),
+        },
+    }
+}
+
+async fn run(args: CliArgs) -> Result<bool, String> {
+    // 앱에서 지정한 Oracle Instant Client 사용
+    if let Err(e) = oracle_installer::apply_active_oracle_client() {
+        eprintln!("Warning: {}", e);
+    }
+
+    // DB를 쓰는 경우에만 테이블 준비
+    if !matches!(args.target, Target::File(_)) || args.env.is_some() {
+        create_sqlite().await?;
+    }
+
+    let variables = load_variables(&args).await?;
+    let graphs = load_graphs(&args.target).await?;
+    if graphs.is_empty() {
+        return Err("No pages to run".to_string());
+    }
+
+    let options = FlowRunOptions {
+        project_name: None,
+        run_id: format!("run_{}", chrono::Utc::now().timestamp_millis()),
+        variables,
+        fail_fast: args.fail_fast,
+        run_dir: None,
+        call_stack: Vec::new(),
+    };
+    let quiet = args.quiet;
+    let on_event = move |event: FlowEvent<'_>| {
+        if !quiet {
+            print_event(event);
+        }
+    };
+
+    let mut reports: Vec<FlowRunReport> = Vec::with_capacity(graphs.len());
+    for graph in &graphs {
+        let report = flow_runner::run_flow(graph, &options, &on_event).await?;
+        let failed = !report.success();
+        reports.push(report);
+        // fail-fast이면 실패한 페이지 이후는 실행하지 않음
+        if options.fail_fast && failed {
+            break;
+        }
+    }
+
+    let summary = flow_report::RunSummary::new(&reports);
+    println!(
+        "\n{} nodes: {} passed, {} failed, {} errors, {} skipped ({} pages)",
+        summary.total, summary.passed, summary.failed, summary.errors, summary.skipped, reports.len()
+    );
+    for report in &reports {
+        println!("  {} → {}", report.title, std::path::Path::new(&report.run_dir).join("report.html").display());
+    }
+
+    if let Some(path) = &args.junit {
+        flow_report::write_junit_report(path, &reports)?;
+        println!("JUnit report: {}", path.display());
+    }
+    if let Some(path) = &args.json {
+        flow_report::write_json_report(path, &reports)?;
+        println!("JSON report: {}", path.display());
+    }
+
+    Ok(summary.success)
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    if let Some(db) = &args.db {
+        std::env::set_var("ITOL_DB_PATH", db);
+    }
+
+    let runtime = match tokio::runtime::Builder::new_current_thread().enable_all().build() {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            eprintln!("Failed to start runtime: {}", e);
+            return ExitCode::from(2);
+        }
+    };
+
+    match runtime.block_on(run(args)) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            ExitCode::from(2)
+        }
+    }
+}
@@ -3,6 +3,7 @@ pub mod folder;
 pub mod execution;
 pub mod project_manager;
 pub mod oracle_installer;
+pub mod importer;
 
 use tauri::command;
 
@@ -108,13 +109,13 @@ pub fn check_path_access(path: String) -> Result<bool, String> {
 
 #[command]
 pub async fn rename_file_command(file_path: String, new_file_name: String) -> Result<String, String> {
-    Ok(folder::file::rename_file(file_path, new_file_name).await?)
+    folder::file::rename_file(file_path, new_file_name).await
 }
 
 #[command]
 pub async fn create_file_command(new_file_name: String, path: String) -> Result<String, String> {
     print!("new_file_name: {}, path: {}", new_file_name, path);
-    Ok(folder::file::create_file(new_file_name, path).await?)
+    folder::file::create_file(new_file_name, path).await
 }
 
 #[command]
@@ -160,11 +161,143 @@ pub async fn execute_db_command(params: execution::db_system::ExecuteDbParams) -
     execution::db_system::execute_db_query(params).await
 }
 
+/// 에디터의 서브플로우 노드 실행 (호출한 페이지 id로 실행 디렉터리를 정함)
+#[command]
+pub async fn run_subflow_command(
+    node: execution::flow_runner::FlowNode,
+    caller_page_id: i32,
+    run_id: String,
+) -> Result<execution::flow_runner::NodeRunResult, String> {
+    execution::flow_runner::run_subflow(node, caller_page_id, run_id).await
+}
+
 #[command]
 pub async fn test_db_connection_command(params: execution::db_system::TestConnectionParams) -> Result<String, String> {
     execution::db_system::test_connection(params.connection).await
 }
 
+#[command]
+pub async fn list_db_schemas_command(params: execution::db_schema::SchemaIntrospectionParams) -> Result<Vec<String>, String> {
+    execution::db_schema::list_schemas(params.connection).await
+}
+
+#[command]
+pub async fn list_db_tables_command(params: execution::db_schema::SchemaIntrospectionParams) -> Result<Vec<execution::db_schema::TableInfo>, String> {
+    execution::db_schema::list_tables(params.connection, params.schema).await
+}
+
+#[command]
+pub async fn list_db_columns_command(params: execution::db_schema::SchemaIntrospectionParams) -> Result<Vec<execution::db_schema::ColumnInfo>, String> {
+    let table = params.table.ok_or("Table name is required")?;
+    execution::db_schema::list_columns(params.connection, params.schema, table).await
+}
+
+#[command]
+pub async fn list_db_indexes_command(params: execution::db_schema::SchemaIntrospectionParams) -> Result<Vec<execution::db_schema::IndexInfo>, String> {
+    let table = params.table.ok_or("Table name is required")?;
+    execution::db_schema::list_indexes(params.connection, params.schema, table).await
+}
+
+#[command]
+pub async fn list_db_foreign_keys_command(params: execution::db_schema::SchemaIntrospectionParams) -> Result<Vec<execution::db_schema::ForeignKeyInfo>, String> {
+    let table = params.table.ok_or("Table name is required")?;
+    execution::db_schema::list_foreign_keys(params.connection, params.schema, table).await
+}
+
+// Connection Profile Commands
+/// 프로필 목록 (비밀번호는 빠져 있고 접속할 때 저장된 값으로 채워짐)
+#[command]
+pub async fn get_connection_profiles_command() -> Result<Vec<database::connection_profile::ConnectionProfile>, String> {
+    database::connection_profile::get_all_connection_profiles()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[command]
+pub async fn create_connection_profile_command(
+    name: String,
+    connection: execution::db_system::DatabaseConnection,
+) -> Result<i32, String> {
+    database::connection_profile::create_connection_profile(name, connection).await
+}
+
+#[command]
+pub async fn update_connection_profile_command(
+    id: i32,
+    name: String,
+    connection: execution::db_system::DatabaseConnection,
+) -> Result<(), String> {
+    database::connection_profile::update_connection_profile(id, name, connection).await
+}
+
+#[command]
+pub async fn delete_connection_profile_command(id: i32) -> Result<(), String> {
+    database::connection_profile::delete_connection_profile(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[command]
+pub async fn test_connection_profile_command(id: i32) -> Result<String, String> {
+    execution::db_system::test_connection(execution::db_system::DatabaseConnection::Profile { profile_id: id }).await
+}
+
+// Book Bundle Commands
+#[command]
+pub async fn export_book_bundle_command(book_id: i32, path: String) -> Result<(), String> {
+    let bundle = database::bundle::export_book_bundle(book_id).await?;
+    let path = std::path::Path::new(&path);
+    let content = database::bundle::bundle_to_string(&bundle, database::bundle::BundleFormat::from_path(path))?;
+    std::fs::write(path, content).map_err(|e| format!("Failed to write bundle: {}", e))
+}
+
+#[command]
+pub async fn import_book_bundle_command(
+    path: String,
+    parent_id: Option<i32>,
+    conflict: Option<database::bundle::ConflictStrategy>,
+) -> Result<database::bundle::BundleImportResult, String> {
+    let content = std::fs::read_to_string(&path).map_err(|e| format!("Failed to read bundle: {}", e))?;
+    let bundle = database::bundle::parse_bundle(&content)?;
+    database::bundle::import_book_bundle(&bundle, parent_id, conflict.unwrap_or_default()).await
+}
+
+// Database Maintenance Commands
+#[command]
+pub async fn check_database_integrity_command(repair: bool) -> Result<database::integrity::IntegrityReport, String> {
+    database::integrity::check_integrity(repair)
+        .await
+        .map_err(|e| format!("Failed to check database integrity: {}", e))
+}
+
+// Postman Import Commands
+#[command]
+pub async fn import_postman_collection_command(
+    path: String,
+    parent_id: Option<i32>,
+    conflict: Option<database::bundle::ConflictStrategy>,
+) -> Result<importer::postman::PostmanImportResult, String> {
+    let content = std::fs::read_to_string(&path).map_err(|e| format!("Failed to read collection: {}", e))?;
+    importer::postman::import_collection(&content, parent_id, conflict.unwrap_or_default()).await
+}
+
+#[command]
+pub async fn import_postman_environment_command(path: String) -> Result<i32, String> {
+    let content = std::fs::read_to_string(&path).map_err(|e| format!("Failed to read environment: {}", e))?;
+    importer::postman::import_environment(&content).await
+}
+
+// OpenAPI Import Commands
+#[command]
+pub async fn import_openapi_command(
+    path: String,
+    parent_id: Option<i32>,
+    conflict: Option<database::bundle::ConflictStrategy>,
+) -> Result<importer::openapi::OpenApiImportResult, String> {
+    let content = std::fs::read_to_string(&path).map_err(|e| format!("Failed to read OpenAPI document: {}", e))?;
+    importer::openapi::import_spec(&content, parent_id, conflict.unwrap_or_default()).await
+}
+
 // Project Manager Commands
 #[command]
 pub async fn add_project_command(
@@ -243,9 +376,46 @@ pub async fn check_oracle_installed() -> Result<oracle_installer::OracleInstallS
     oracle_installer::check_oracle_installed().await
 }
 
+/// 설치 진행률을 웹뷰로 보내는 콜백
+fn oracle_install_progress(app_handle: tauri::AppHandle) -> oracle_installer::ProgressCallback {
+    use tauri::Emitter;
+
+    std::sync::Arc::new(move |progress| {
+        let _ = app_handle.emit(oracle_installer::INSTALL_PROGRESS_EVENT, progress);
+    })
+}
+
+#[command]
+pub async fn install_oracle_client(
+    app_handle: tauri::AppHandle,
+    options: Option<oracle_installer::OracleDownloadOptions>,
+) -> Result<String, String> {
+    oracle_installer::install_oracle_client(options.unwrap_or_default(), oracle_install_progress(app_handle)).await
+}
+
+#[command]
+pub async fn install_oracle_client_from_zip_command(
+    app_handle: tauri::AppHandle,
+    params: oracle_installer::OracleArchiveInstallParams,
+) -> Result<oracle_installer::OracleClientInstall, String> {
+    oracle_installer::install_oracle_client_from_zip(params, oracle_install_progress(app_handle)).await
+}
+
+#[command]
+pub async fn list_oracle_clients_command() -> Result<Vec<oracle_installer::OracleClientInstall>, String> {
+    oracle_installer::list_oracle_clients().await
+}
+
+#[command]
+pub async fn set_active_oracle_client_command(
+    version: String,
+) -> Result<oracle_installer::OracleClientActivation, String> {
+    oracle_installer::set_active_oracle_client(version).await
+}
+
 #[command]
-pub async fn install_oracle_client() -> Result<String, String> {
-    oracle_installer::install_oracle_client().await
+pub async fn uninstall_oracle_client_command(version: String) -> Result<(), String> {
+    oracle_installer::uninstall_oracle_client(version).await
 }
 
 // Book CRUD Commands
@@ -336,6 +506,63 @@ pub async fn delete_page_command(id: i32) -> Result<(), String> {
         .map_err(|e| e.to_string())
 }
 
+/// 페이지 그래프 전체(노드/엣지/flow_data)를 한 트랜잭션으로 저장하고 저장된 결과를 반환
+#[command]
+pub async fn save_page_graph_command(
+    page_id: i32,
+    flow_data: Option<String>,
+    nodes: Vec<database::graph::GraphNodeInput>,
+    edges: Vec<database::graph::GraphEdgeInput>,
+) -> Result<database::graph::PageGraphSaveResult, String> {
+    database::graph::save_page_graph(page_id, flow_data, nodes, edges).await
+}
+
+// Page History Commands
+#[command]
+pub async fn list_page_versions_command(page_id: i32) -> Result<Vec<database::history::PageVersionInfo>, String> {
+    database::history::list_versions(page_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[command]
+pub async fn get_page_version_command(page_id: i32, version: i64) -> Result<database::history::PageVersion, String> {
+    database::history::get_version(page_id, version)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[command]
+pub async fn create_page_checkpoint_command(
+    page_id: i32,
+    message: Option<String>,
+    author: Option<String>,
+) -> Result<database::history::PageVersionInfo, String> {
+    database::history::create_checkpoint(page_id, author, message)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[command]
+pub async fn diff_page_versions_command(
+    page_id: i32,
+    from_version: i64,
+    to_version: Option<i64>,
+) -> Result<database::history::PageDiff, String> {
+    database::history::diff_versions(page_id, from_version, to_version)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[command]
+pub async fn restore_page_version_command(
+    page_id: i32,
+    version: i64,
+    author: Option<String>,
+) -> Result<database::graph::PageGraphSaveResult, String> {
+    database::history::restore_version(page_id, version, author).await
+}
+
 #[command]
 pub async fn debug_get_all_pages_command() -> Result<Vec<database::page::Page>, String> {
     use sqlx::Row;
@@ -368,6 +595,125 @@ pub async fn reorder_pages_command(
         .map_err(|e| e.to_string())
 }
 
+// ==================== Clone Commands ====================
+
+/// 북과 하위 북/페이지/노드/엣지 전체 복제 (parent_id가 없으면 원본과 같은 위치)
+#[command]
+pub async fn clone_book_command(
+    book_id: i32,
+    parent_id: Option<i32>,
+) -> Result<database::bundle::BundleImportResult, String> {
+    database::clone::clone_book(book_id, parent_id).await
+}
+
+/// 페이지 복제 (target_book_id가 없으면 같은 북)
+#[command]
+pub async fn clone_page_command(
+    page_id: i32,
+    target_book_id: Option<i32>,
+) -> Result<database::clone::PageCloneResult, String> {
+    database::clone::clone_page(page_id, target_book_id).await
+}
+
+/// 선택한 노드와 그 사이 엣지를 다른 페이지로 복사
+#[command]
+pub async fn copy_nodes_command(
+    source_page_id: i32,
+    node_ids: Vec<String>,
+    target_page_id: i32,
+    offset_x: Option<i32>,
+    offset_y: Option<i32>,
+) -> Result<database::clone::NodeCopyResult, String> {
+    let offset = match (offset_x, offset_y) {
+        (None, None) => None,
+        (x, y) => Some((x.unwrap_or(0), y.unwrap_or(0))),
+    };
+    database::clone::copy_nodes(source_page_id, node_ids, target_page_id, offset).await
+}
+
+// ==================== Backup Commands ====================
+
+/// 수동 백업 생성 (backups/manual-<시각>.db, 자동 백업 회전 대상 아님)
+#[command]
+pub async fn create_backup_command() -> Result<database::backup::BackupInfo, String> {
+    database::backup::create_backup().await
+}
+
+/// 백업 목록 (최신순, 크기/생성 시각 포함)
+#[command]
+pub async fn list_backups_command() -> Result<Vec<database::backup::BackupInfo>, String> {
+    database::backup::list_backups()
+}
+
+/// 백업으로 DB 복원 (현재 DB는 pre-restore 백업으로 남김)
+#[command]
+pub async fn restore_backup_command(file_name: String) -> Result<database::backup::BackupRestoreResult, String> {
+    database::backup::restore_backup(&file_name).await
+}
+
+// ==================== Trash Commands ====================
+
+/// 휴지통 목록 (함께 삭제된 하위 항목은 최상위 항목에 포함)
+#[command]
+pub async fn get_trash_command() -> Result<Vec<database::trash::TrashItem>, String> {
+    database::trash::list_trash()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[command]
+pub async fn restore_book_command(id: i32) -> Result<(), String> {
+    database::trash::restore_book(id).await
+}
+
+#[command]
+pub async fn restore_page_command(id: i32) -> Result<(), String> {
+    database::trash::restore_page(id).await
+}
+
+/// 휴지통 영구 삭제 (older_than_days가 없으면 전체 비우기)
+#[command]
+pub async fn purge_trash_command(older_than_days: Option<i64>) -> Result<database::trash::PurgeReport, String> {
+    database::trash::purge(older_than_days)
+        .await
+        .map_err(|e| format!("Failed to purge trash: {}", e))
+}
+
+// ==================== Search Commands ====================
+
+/// 북/페이지 제목과 노드 설정(URL, SQL, 스크립트 경로 등) 전문 검색
+#[command]
+pub async fn search_command(query: String, limit: Option<i64>) -> Result<Vec<database::search::SearchHit>, String> {
+    database::search::search(&query, limit)
+        .await
+        .map_err(|e| format!("Search failed: {}", e))
+}
+
+// ==================== Undo/Redo Commands ====================
+
+#[command]
+pub async fn undo_command(
+    scope: database::journal::JournalScope,
+) -> Result<Option<database::journal::JournalEntry>, String> {
+    database::journal::undo(scope).await
+}
+
+#[command]
+pub async fn redo_command(
+    scope: database::journal::JournalScope,
+) -> Result<Option<database::journal::JournalEntry>, String> {
+    database::journal::redo(scope).await
+}
+
+#[command]
+pub async fn get_undo_state_command(
+    scope: database::journal::JournalScope,
+) -> Result<database::journal::UndoState, String> {
+    database::journal::get_undo_state(scope)
+        .await
+        .map_err(|e| e.to_string())
+}
+
 // ==================== Node Commands ====================
 
 #[command]
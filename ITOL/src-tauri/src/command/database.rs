@@ -1,13 +1,33 @@
-use sqlx::{sqlite::SqlitePoolOptions, Pool, Sqlite};
+use sqlx::{sqlite::{SqliteConnectOptions, SqlitePoolOptions}, Pool, Sqlite};
+use std::str::FromStr;
 use std::sync::Arc;
-use tokio::sync::OnceCell;
+use tokio::sync::RwLock;
 
 pub mod book;
 pub mod page;
 pub mod node;
 pub mod edge;
+pub mod connection_profile;
+pub mod environment;
+pub mod bundle;
+pub mod graph;
+pub mod history;
+pub mod journal;
+pub mod search;
+pub mod clone;
+pub mod trash;
+pub mod migration;
+pub mod integrity;
+pub mod backup;
 
 pub fn get_sqlite_path() -> String {
+    // CI 등 헤드리스 실행에서는 ITOL_DB_PATH로 DB 파일을 직접 지정할 수 있음
+    if let Ok(path) = std::env::var("ITOL_DB_PATH") {
+        if !path.is_empty() {
+            return path;
+        }
+    }
+
     // 앱 데이터 디렉터리 가져오기 (Windows에서는 %APPDATA%, Linux에서는 ~/.config, macOS에서는 ~/Library/Application Support)
     let app_data_dir = dirs::data_local_dir()
         .expect("Failed to find local data directory");
@@ -29,115 +49,76 @@ pub fn get_sqlite_path() -> String {
     sqlite_path
 }
 
-pub static DB_POOL: OnceCell<Arc<Pool<Sqlite>>> = OnceCell::const_new();
+/// 백업 복원 시 풀을 교체할 수 있도록 RwLock으로 보관 (backup::restore_backup)
+pub static DB_POOL: RwLock<Option<Arc<Pool<Sqlite>>>> = RwLock::const_new(None);
 
-/// DB_POOL을 초기화하거나 이미 초기화된 풀을 반환
-pub async fn get_db_pool() -> Arc<Pool<Sqlite>> {
-    DB_POOL
-        .get_or_init(|| async {
-            let sqlite_path = get_sqlite_path();
-            println!("SQLite Path: {}", sqlite_path);
-            
-            // Ensure the database file exists
-            if !std::path::Path::new(&sqlite_path).exists() {
-                std::fs::File::create(&sqlite_path)
-                    .expect("Failed to create SQLite database file");
-            }
-            
-            let pool = SqlitePoolOptions::new()
-                .max_connections(5)
-                .connect(&format!("sqlite:///{}", sqlite_path))
-                .await
-                .expect("Failed to create SQLite pool");
-            Arc::new(pool)
-        })
+pub(crate) async fn open_pool(sqlite_path: &str) -> Result<Pool<Sqlite>, sqlx::Error> {
+    // ON DELETE CASCADE가 동작하도록 모든 연결에서 외래 키 검사 활성화
+    let options = SqliteConnectOptions::from_str(&format!("sqlite:///{}", sqlite_path))?.foreign_keys(true);
+    SqlitePoolOptions::new()
+        .max_connections(5)
+        .connect_with(options)
         .await
-        .clone()
-}
-
-pub async fn create_sqlite() {
-    let pool = get_db_pool().await;
-    println!("SQLite Table Book created");
-    create_table_book(&*pool).await.unwrap();
-    println!("SQLite Table Page created");
-    create_table_page(&*pool).await.unwrap();
-    println!("SQLite Table Node created");
-    create_table_node(&*pool).await.unwrap();
-    println!("SQLite Table Edge created");
-    create_table_edge(&*pool).await.unwrap();
 }
 
-async fn create_table_book(pool: &Pool<Sqlite>) -> Result<(), sqlx::Error> {
-    // 테이블 생성 쿼리 실행
-    sqlx::query(
-        "CREATE TABLE IF NOT EXISTS Book (
-            id INTEGER PRIMARY KEY AUTOINCREMENT,
-            title TEXT NOT NULL,
-            parent_id INTEGER,
-            FOREIGN KEY (parent_id) REFERENCES Book(id) ON DELETE CASCADE
-        )"
-    )
-    .execute(pool)
-    .await?;
+/// 풀을 열고 마이그레이션까지 끝낸 뒤 DB_POOL에 저장 (마이그레이션이 실패하면 저장하지 않음)
+async fn init_pool(guard: &mut Option<Arc<Pool<Sqlite>>>) -> Result<(Arc<Pool<Sqlite>>, migration::MigrationReport), String> {
+    let sqlite_path = get_sqlite_path();
+    println!("SQLite Path: {}", sqlite_path);
     
-    Ok(())
-}
-
-async fn create_table_page(pool: &Pool<Sqlite>) -> Result<(), sqlx::Error> {
-    // 테이블 생성 쿼리 실행
-    sqlx::query(
-        "CREATE TABLE IF NOT EXISTS Page (
-            id INTEGER PRIMARY KEY AUTOINCREMENT,
-            fk_book_id INTEGER NOT NULL,
-            title TEXT NOT NULL,
-            flow_data TEXT,
-            display_order INTEGER NOT NULL DEFAULT 0,
-            FOREIGN KEY (fk_book_id) REFERENCES Book(id) ON DELETE CASCADE
-        )"
-    )
-    .execute(pool)
-    .await?;
-    
-    // Add display_order column if it doesn't exist (for existing databases)
-    let _ = sqlx::query("ALTER TABLE Page ADD COLUMN display_order INTEGER NOT NULL DEFAULT 0")
-        .execute(pool)
-        .await;
+    // Ensure the database file exists
+    if !std::path::Path::new(&sqlite_path).exists() {
+        std::fs::File::create(&sqlite_path)
+            .map_err(|e| format!("Failed to create SQLite database file {}: {}", sqlite_path, e))?;
+    }
     
-    Ok(())
+    let pool = open_pool(&sqlite_path)
+        .await
+        .map_err(|e| format!("Failed to create SQLite pool: {}", e))?;
+    let report = match migration::migrate(&pool, Some(std::path::Path::new(&sqlite_path))).await {
+        Ok(report) => report,
+        Err(e) => {
+            pool.close().await;
+            return Err(e);
+        }
+    };
+    if !report.applied.is_empty() {
+        println!(
+            "SQLite schema migrated from version {} to {}",
+            report.from_version, report.to_version
+        );
+    }
+
+    let pool = Arc::new(pool);
+    *guard = Some(pool.clone());
+    Ok((pool, report))
 }
 
-async fn create_table_node(pool: &Pool<Sqlite>) -> Result<(), sqlx::Error> {
-    // 테이블 생성 쿼리 실행
-    sqlx::query(
-        "CREATE TABLE IF NOT EXISTS Node (
-            id STRING,
-            fk_page_id INTEGER NOT NULL,
-            data TEXT NOT NULL,
-            type STRING NOT NULL,
-            position_x INTEGER NOT NULL,
-            position_y INTEGER NOT NULL,
-            FOREIGN KEY (fk_page_id) REFERENCES Page(id) ON DELETE CASCADE
-            UNIQUE (id, fk_page_id)
-        )"
-    )
-    .execute(pool)
-    .await?;
-    Ok(())
+/// DB_POOL을 초기화하거나 이미 초기화된 풀을 반환
+///
+/// 처음 열 때 마이그레이션이 끝날 때까지 기다리므로 스키마가 덜 바뀐 DB를 내보내지 않음.
+/// 백업 복원 시 풀이 교체되므로 반환된 풀은 보관하지 말고 작업마다 다시 가져올 것
+pub async fn get_db_pool() -> Arc<Pool<Sqlite>> {
+    if let Some(pool) = DB_POOL.read().await.as_ref() {
+        return pool.clone();
+    }
+
+    let mut guard = DB_POOL.write().await;
+    if let Some(pool) = guard.as_ref() {
+        return pool.clone();
+    }
+
+    match init_pool(&mut guard).await {
+        Ok((pool, _)) => pool,
+        Err(e) => panic!("Failed to prepare SQLite database: {}", e),
+    }
 }
 
-async fn create_table_edge(pool: &Pool<Sqlite>) -> Result<(), sqlx::Error> {
-    // 테이블 생성 쿼리 실행
-    sqlx::query("CREATE TABLE IF NOT EXISTS Edge (
-            id INTEGER PRIMARY KEY AUTOINCREMENT,
-            fk_page_id INTEGER NOT NULL,
-            source TEXT NOT NULL,
-            target TEXT NOT NULL,
-            sourceHandle TEXT,
-            targetHandle TEXT,
-            FOREIGN KEY (fk_page_id) REFERENCES Page(id) ON DELETE CASCADE
-        )")
-    .execute(pool) // removed the extra '&' here
-    .await?;
-    
-    Ok(())
+/// 스키마 마이그레이션 적용 (앱/CLI 시작 시 호출)
+pub async fn create_sqlite() -> Result<migration::MigrationReport, String> {
+    let mut guard = DB_POOL.write().await;
+    match guard.as_ref() {
+        Some(pool) => migration::migrate(pool, Some(std::path::Path::new(&get_sqlite_path()))).await,
+        None => init_pool(&mut guard).await.map(|(_, report)| report),
+    }
 }
@@ -0,0 +1,485 @@
+use super::migration::{self, MigrationReport};
+use super::{get_db_pool, get_sqlite_path, open_pool, DB_POOL};
+use serde::{Deserialize, Serialize};
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
+use sqlx::{Pool, Sqlite};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+/// 자동 백업 주기 (마지막 자동 백업이 이보다 오래되면 새로 만듦)
+pub const AUTO_BACKUP_INTERVAL_HOURS: i64 = 24;
+/// 보관할 자동 백업 수 (수동/복원 전 백업은 지우지 않음)
+pub const AUTO_BACKUP_KEEP: usize = 7;
+/// 자동 백업이 필요한지 확인하는 간격
+const SCHEDULE_CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum BackupKind {
+    Auto,
+    Manual,
+    /// 복원 직전의 DB
+    PreRestore,
+}
+
+impl BackupKind {
+    fn prefix(self) -> &'static str {
+        match self {
+            BackupKind::Auto => "auto",
+            BackupKind::Manual => "manual",
+            BackupKind::PreRestore => "pre-restore",
+        }
+    }
+
+    fn from_file_name(file_name: &str) -> Option<Self> {
+        [BackupKind::PreRestore, BackupKind::Manual, BackupKind::Auto]
+            .into_iter()
+            .find(|kind| file_name.starts_with(&format!("{}-", kind.prefix())))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupInfo {
+    pub file_name: String,
+    pub path: String,
+    pub kind: BackupKind,
+    pub size: u64,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupRestoreResult {
+    pub restored: BackupInfo,
+    /// 복원 전에 현재 DB를 저장한 백업
+    pub safety_backup: BackupInfo,
+    /// 이전 버전 백업이면 최신 스키마로 마이그레이션한 결과
+    pub migration: MigrationReport,
+}
+
+/// 백업 디렉터리 (ttol.db 옆의 backups/)
+pub fn backup_dir() -> PathBuf {
+    let sqlite_path = PathBuf::from(get_sqlite_path());
+    sqlite_path
+        .parent()
+        .unwrap_or(Path::new("."))
+        .join("backups")
+}
+
+fn backup_info(path: &Path) -> Option<BackupInfo> {
+    let file_name = path.file_name()?.to_str()?.to_string();
+    if !file_name.ends_with(".db") {
+        return None;
+    }
+    let kind = BackupKind::from_file_name(&file_name)?;
+    let metadata = std::fs::metadata(path).ok()?;
+    let created_at = metadata
+        .modified()
+        .ok()
+        .map(|time| chrono::DateTime::<chrono::Local>::from(time).to_rfc3339())
+        .unwrap_or_default();
+    Some(BackupInfo {
+        file_name,
+        path: path.display().to_string(),
+        kind,
+        size: metadata.len(),
+        created_at,
+    })
+}
+
+/// 백업 목록 (최신순)
+pub fn list_backups_in(dir: &Path) -> Result<Vec<BackupInfo>, String> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let entries = std::fs::read_dir(dir)
+        .map_err(|e| format!("Failed to read backup directory {}: {}", dir.display(), e))?;
+    let mut backups: Vec<BackupInfo> = entries
+        .filter_map(|entry| backup_info(&entry.ok()?.path()))
+        .collect();
+    // 파일 이름에 생성 시각이 들어 있으므로 같은 시각이면 이름으로 정렬
+    backups.sort_by(|a, b| {
+        b.created_at
+            .cmp(&a.created_at)
+            .then_with(|| b.file_name.cmp(&a.file_name))
+    });
+    Ok(backups)
+}
+
+pub fn list_backups() -> Result<Vec<BackupInfo>, String> {
+    list_backups_in(&backup_dir())
+}
+
+/// VACUUM INTO로 열린 DB의 일관된 사본 생성 (<kind>-<시각>.db)
+pub async fn create_backup_in(
+    pool: &Pool<Sqlite>,
+    dir: &Path,
+    kind: BackupKind,
+) -> Result<BackupInfo, String> {
+    std::fs::create_dir_all(dir)
+        .map_err(|e| format!("Failed to create backup directory {}: {}", dir.display(), e))?;
+
+    let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S%3f").to_string();
+    let path = (1..)
+        .map(|n| match n {
+            1 => dir.join(format!("{}-{}.db", kind.prefix(), stamp)),
+            n => dir.join(format!("{}-{}-{}.db", kind.prefix(), stamp, n)),
+        })
+        .find(|path| !path.exists())
+        .unwrap_or_else(|| dir.join(format!("{}-{}.db", kind.prefix(), stamp)));
+
+    sqlx::query("VACUUM INTO ?")
+        .bind(path.to_string_lossy().to_string())
+        .execute(pool)
+        .await
+        .map_err(|e| format!("Failed to back up database to {}: {}", path.display(), e))?;
+    backup_info(&path).ok_or_else(|| format!("Backup file {} was not created", path.display()))
+}
+
+/// 수동 백업
+pub async fn create_backup() -> Result<BackupInfo, String> {
+    let pool = get_db_pool().await;
+    create_backup_in(&pool, &backup_dir(), BackupKind::Manual).await
+}
+
+/// 오래된 자동 백업 삭제, 삭제한 파일 이름 반환
+pub fn rotate_backups_in(dir: &Path, keep: usize) -> Result<Vec<String>, String> {
+    let mut removed = Vec::new();
+    for backup in list_backups_in(dir)?
+        .into_iter()
+        .filter(|backup| backup.kind == BackupKind::Auto)
+        .skip(keep)
+    {
+        std::fs::remove_file(&backup.path)
+            .map_err(|e| format!("Failed to remove backup {}: {}", backup.path, e))?;
+        removed.push(backup.file_name);
+    }
+    Ok(removed)
+}
+
+/// 마지막 자동 백업이 주기보다 오래됐으면 새로 만들고 회전
+pub async fn run_scheduled_backup_in(
+    pool: &Pool<Sqlite>,
+    dir: &Path,
+) -> Result<Option<BackupInfo>, String> {
+    let threshold = chrono::Local::now() - chrono::Duration::hours(AUTO_BACKUP_INTERVAL_HOURS);
+    let latest = list_backups_in(dir)?
+        .into_iter()
+        .find(|backup| backup.kind == BackupKind::Auto);
+    let recent = latest
+        .and_then(|backup| chrono::DateTime::parse_from_rfc3339(&backup.created_at).ok())
+        .is_some_and(|created_at| created_at > threshold);
+    if recent {
+        return Ok(None);
+    }
+
+    let backup = create_backup_in(pool, dir, BackupKind::Auto).await?;
+    rotate_backups_in(dir, AUTO_BACKUP_KEEP)?;
+    Ok(Some(backup))
+}
+
+/// 앱이 실행되는 동안 주기적으로 자동 백업 (앱 시작 시 spawn)
+pub async fn run_backup_scheduler() {
+    loop {
+        let pool = get_db_pool().await;
+        match run_scheduled_backup_in(&pool, &backup_dir()).await {
+            Ok(Some(backup)) => log::info!("Database backed up to {}", backup.path),
+            Ok(None) => {}
+            Err(e) => log::error!("Scheduled backup failed: {}", e),
+        }
+        drop(pool);
+        tokio::time::sleep(SCHEDULE_CHECK_INTERVAL).await;
+    }
+}
+
+/// 백업 파일이 온전하고 이 빌드가 읽을 수 있는 스키마인지 확인 (읽기 전용으로 열어 확인)
+pub async fn verify_backup(path: &Path) -> Result<(), String> {
+    let options = SqliteConnectOptions::new().filename(path).read_only(true);
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect_with(options)
+        .await
+        .map_err(|e| format!("Failed to open backup {}: {}", path.display(), e))?;
+
+    let result = async {
+        let integrity: String = sqlx::query_scalar("PRAGMA integrity_check")
+            .fetch_one(&pool)
+            .await
+            .map_err(|e| format!("Failed to check backup: {}", e))?;
+        if integrity != "ok" {
+            return Err(format!(
+                "Backup {} is corrupted: {}",
+                path.display(),
+                integrity
+            ));
+        }
+
+        let has_versions: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name = 'schema_version'",
+        )
+        .fetch_one(&pool)
+        .await
+        .map_err(|e| format!("Failed to check backup: {}", e))?;
+        if has_versions > 0 {
+            let version: i64 =
+                sqlx::query_scalar("SELECT COALESCE(MAX(version), 0) FROM schema_version")
+                    .fetch_one(&pool)
+                    .await
+                    .map_err(|e| format!("Failed to check backup: {}", e))?;
+            if version > migration::latest_version() {
+                return Err(format!(
+                    "Backup schema version {} is newer than this build (version {})",
+                    version,
+                    migration::latest_version()
+                ));
+            }
+        }
+        Ok(())
+    }
+    .await;
+    pool.close().await;
+    result
+}
+
+/// DB 파일을 백업 파일로 교체 (임시 파일에 복사 후 rename, 남은 WAL/SHM 파일은 삭제)
+fn replace_database_file(backup_path: &Path, db_path: &Path) -> Result<(), String> {
+    let temp_path = db_path.with_extension("restore-tmp");
+    std::fs::copy(backup_path, &temp_path)
+        .map_err(|e| format!("Failed to copy backup {}: {}", backup_path.display(), e))?;
+    for suffix in ["-wal", "-shm"] {
+        let sidecar = PathBuf::from(format!("{}{}", db_path.display(), suffix));
+        if sidecar.exists() {
+            std::fs::remove_file(&sidecar)
+                .map_err(|e| format!("Failed to remove {}: {}", sidecar.display(), e))?;
+        }
+    }
+    std::fs::rename(&temp_path, db_path)
+        .map_err(|e| format!("Failed to replace database {}: {}", db_path.display(), e))
+}
+
+/// 백업으로 DB 복원
+///
+/// 현재 DB를 먼저 pre-restore 백업으로 남기고, DB_POOL 쓰기 잠금을 잡은 채로
+/// 기존 풀을 닫고(진행 중인 쿼리는 끝날 때까지 대기) 파일을 교체한 뒤 새 풀을 연다.
+/// 복원 전에 받아 둔 `Arc<Pool>`은 닫힌 풀이 되어 PoolClosed 오류를 내므로,
+/// 풀은 보관하지 말고 작업마다 `get_db_pool()`로 다시 가져와야 한다.
+pub async fn restore_backup(file_name: &str) -> Result<BackupRestoreResult, String> {
+    // 아직 열리지 않았으면 먼저 열어 둠
+    get_db_pool().await;
+    restore_backup_in(&DB_POOL, &backup_dir(), &get_sqlite_path(), file_name).await
+}
+
+/// `slot`의 풀을 `dir`의 백업으로 교체 (`db_path`는 풀이 연 DB 파일)
+pub async fn restore_backup_in(
+    slot: &RwLock<Option<Arc<Pool<Sqlite>>>>,
+    dir: &Path,
+    db_path: &str,
+    file_name: &str,
+) -> Result<BackupRestoreResult, String> {
+    // 백업 디렉터리 밖의 파일은 복원하지 않음
+    if Path::new(file_name)
+        .file_name()
+        .and_then(|name| name.to_str())
+        != Some(file_name)
+    {
+        return Err(format!("Invalid backup name: {}", file_name));
+    }
+    let backup_path = dir.join(file_name);
+    let restored =
+        backup_info(&backup_path).ok_or_else(|| format!("Backup {} not found", file_name))?;
+    verify_backup(&backup_path).await?;
+
+    // 잠금을 잡은 뒤 백업해야 pre-restore 백업과 교체 사이에 쓰기가 끼어들지 않음
+    let mut guard = slot.write().await;
+    let current = guard
+        .take()
+        .ok_or_else(|| "Database is not open".to_string())?;
+    let safety_backup = match create_backup_in(&current, dir, BackupKind::PreRestore).await {
+        Ok(backup) => backup,
+        Err(e) => {
+            *guard = Some(current);
+            return Err(e);
+        }
+    };
+    current.close().await;
+
+    // 파일 교체에 실패해도 기존(또는 교체된) 파일로 풀을 다시 열어 앱이 계속 동작하게 함
+    let replaced = replace_database_file(&backup_path, Path::new(db_path));
+    let pool = Arc::new(
+        open_pool(db_path)
+            .await
+            .map_err(|e| format!("Failed to reopen database {}: {}", db_path, e))?,
+    );
+    *guard = Some(pool.clone());
+    drop(guard);
+    replaced?;
+
+    let migration = migration::migrate(&pool, Some(Path::new(db_path))).await?;
+    log::info!("Database restored from {}", restored.path);
+    Ok(BackupRestoreResult {
+        restored,
+        safety_backup,
+        migration,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backup_list_rotate_and_verify() {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let dir = std::env::temp_dir().join(format!("itol-backup-{}", std::process::id()));
+                let _ = std::fs::remove_dir_all(&dir);
+                std::fs::create_dir_all(&dir).unwrap();
+                let db_path = dir.join("ttol.db");
+                let options = SqliteConnectOptions::new()
+                    .filename(&db_path)
+                    .create_if_missing(true);
+                let pool = SqlitePoolOptions::new()
+                    .max_connections(1)
+                    .connect_with(options)
+                    .await
+                    .unwrap();
+                migration::migrate(&pool, None).await.unwrap();
+                sqlx::query("INSERT INTO Book (title) VALUES ('Orders')")
+                    .execute(&pool)
+                    .await
+                    .unwrap();
+
+                let backups = dir.join("backups");
+                let manual = create_backup_in(&pool, &backups, BackupKind::Manual)
+                    .await
+                    .unwrap();
+                assert!(manual.file_name.starts_with("manual-") && manual.size > 0);
+                for _ in 0..3 {
+                    create_backup_in(&pool, &backups, BackupKind::Auto)
+                        .await
+                        .unwrap();
+                }
+                // 최근 자동 백업이 있으면 건너뜀
+                assert!(run_scheduled_backup_in(&pool, &backups)
+                    .await
+                    .unwrap()
+                    .is_none());
+
+                let removed = rotate_backups_in(&backups, 2).unwrap();
+                assert_eq!(removed.len(), 1);
+                let list = list_backups_in(&backups).unwrap();
+                assert_eq!(
+                    list.iter().filter(|b| b.kind == BackupKind::Auto).count(),
+                    2
+                );
+                assert_eq!(
+                    list.iter().filter(|b| b.kind == BackupKind::Manual).count(),
+                    1
+                );
+
+                // 백업은 읽기 전용으로 열어도 온전하고 데이터가 들어 있음
+                verify_backup(Path::new(&manual.path)).await.unwrap();
+                let copy = SqlitePoolOptions::new()
+                    .connect_with(
+                        SqliteConnectOptions::new()
+                            .filename(&manual.path)
+                            .read_only(true),
+                    )
+                    .await
+                    .unwrap();
+                let title: String = sqlx::query_scalar("SELECT title FROM Book")
+                    .fetch_one(&copy)
+                    .await
+                    .unwrap();
+                assert_eq!(title, "Orders");
+                copy.close().await;
+
+                std::fs::write(backups.join("manual-broken.db"), b"not a database").unwrap();
+                assert!(verify_backup(&backups.join("manual-broken.db"))
+                    .await
+                    .is_err());
+
+                pool.close().await;
+                let _ = std::fs::remove_dir_all(&dir);
+            });
+    }
+
+    #[test]
+    fn test_restore_backup_brings_back_old_data() {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let dir = std::env::temp_dir().join(format!("itol-restore-{}", std::process::id()));
+                let _ = std::fs::remove_dir_all(&dir);
+                std::fs::create_dir_all(&dir).unwrap();
+                let db_path = dir.join("ttol.db").display().to_string();
+                std::fs::File::create(&db_path).unwrap();
+                let pool = open_pool(&db_path).await.unwrap();
+                migration::migrate(&pool, None).await.unwrap();
+                sqlx::query("INSERT INTO Book (title) VALUES ('Orders')")
+                    .execute(&pool)
+                    .await
+                    .unwrap();
+
+                let backups = dir.join("backups");
+                let backup = create_backup_in(&pool, &backups, BackupKind::Manual)
+                    .await
+                    .unwrap();
+                sqlx::query("UPDATE Book SET title = 'Changed'")
+                    .execute(&pool)
+                    .await
+                    .unwrap();
+                sqlx::query("INSERT INTO Book (title) VALUES ('Extra')")
+                    .execute(&pool)
+                    .await
+                    .unwrap();
+
+                let slot = RwLock::new(Some(Arc::new(pool)));
+                let old_pool = slot.read().await.as_ref().unwrap().clone();
+                let result = restore_backup_in(&slot, &backups, &db_path, &backup.file_name)
+                    .await
+                    .unwrap();
+                assert_eq!(result.restored.file_name, backup.file_name);
+                assert_eq!(result.safety_backup.kind, BackupKind::PreRestore);
+
+                // 복원 전에 받아 둔 풀은 닫혀 있음
+                assert!(old_pool.is_closed());
+                let pool = slot.read().await.as_ref().unwrap().clone();
+                let titles: Vec<String> = sqlx::query_scalar("SELECT title FROM Book ORDER BY id")
+                    .fetch_all(&*pool)
+                    .await
+                    .unwrap();
+                assert_eq!(titles, vec!["Orders".to_string()]);
+
+                // pre-restore 백업에는 복원 직전 데이터가 남아 있음
+                let copy = SqlitePoolOptions::new()
+                    .connect_with(
+                        SqliteConnectOptions::new()
+                            .filename(&result.safety_backup.path)
+                            .read_only(true),
+                    )
+                    .await
+                    .unwrap();
+                let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM Book")
+                    .fetch_one(&copy)
+                    .await
+                    .unwrap();
+                assert_eq!(count, 2);
+                copy.close().await;
+
+                let err = restore_backup_in(&slot, &backups, &db_path, "../ttol.db")
+                    .await
+                    .unwrap_err();
+                assert!(err.contains("Invalid backup name"), "{}", err);
+
+                pool.close().await;
+                let _ = std::fs::remove_dir_all(&dir);
+            });
+    }
+}
@@ -1,7 +1,16 @@
 use super::get_db_pool;
+use super::journal::{self, JournalScope, Selection};
+use super::trash;
 use sqlx::Row;
 use serde::{Deserialize, Serialize};
 
+/// 북 하위 트리 CTE - 첫 번째 바인딩이 루트 북 id (순환 방지를 위해 깊이 제한)
+pub(crate) const BOOK_TREE: &str = "WITH RECURSIVE tree(id, depth) AS (
+    SELECT id, 0 FROM Book WHERE id = ?
+    UNION ALL
+    SELECT b.id, t.depth + 1 FROM Book b JOIN tree t ON b.parent_id = t.id WHERE t.depth < 64
+)";
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Book {
     pub id: i32,
@@ -13,7 +22,7 @@ pub async fn get_book_by_id(id: i32) -> Result<Book, sqlx::Error> {
     // 두 번 역참조하여 내부의 Pool<Sqlite>에 대한 참조를 얻음
     let pool = get_db_pool().await;
 
-    let book = sqlx::query("SELECT id, title, parent_id FROM Book WHERE id = ?")
+    let book = sqlx::query("SELECT id, title, parent_id FROM Book WHERE id = ? AND deleted_at IS NULL")
         .bind(id)
         .map(|row: sqlx::sqlite::SqliteRow| {
             Book {
@@ -30,7 +39,7 @@ pub async fn get_book_by_id(id: i32) -> Result<Book, sqlx::Error> {
 pub async fn get_all_books() -> Result<Vec<Book>, sqlx::Error> {
     let pool = get_db_pool().await;
 
-    let books = sqlx::query("SELECT id, title, parent_id FROM Book ORDER BY parent_id, id")
+    let books = sqlx::query("SELECT id, title, parent_id FROM Book WHERE deleted_at IS NULL ORDER BY parent_id, id")
         .map(|row: sqlx::sqlite::SqliteRow| {
             Book {
                 id: row.get(0),
@@ -46,24 +55,33 @@ pub async fn get_all_books() -> Result<Vec<Book>, sqlx::Error> {
 pub async fn create_book(title: String, parent_id: Option<i32>) -> Result<i32, sqlx::Error> {
     let pool = get_db_pool().await;
 
+    let mut tx = pool.begin().await?;
     let result = sqlx::query("INSERT INTO Book (title, parent_id) VALUES (?, ?)")
         .bind(title)
         .bind(parent_id)
-        .execute(&*pool)
+        .execute(&mut *tx)
         .await?;
+    let id = result.last_insert_rowid() as i32;
+    journal::record(&mut tx, JournalScope::Book(id), "create_book", &Selection::Book(id), Vec::new()).await?;
+    tx.commit().await?;
     
-    Ok(result.last_insert_rowid() as i32)
+    Ok(id)
 }
 
 pub async fn update_book(id: i32, title: String, parent_id: Option<i32>) -> Result<(), sqlx::Error> {
     let pool = get_db_pool().await;
 
+    let mut tx = pool.begin().await?;
+    let selection = Selection::Book(id);
+    let before = journal::capture(&mut tx, &selection).await?;
     sqlx::query("UPDATE Book SET title = ?, parent_id = ? WHERE id = ?")
         .bind(title)
         .bind(parent_id)
         .bind(id)
-        .execute(&*pool)
+        .execute(&mut *tx)
         .await?;
+    journal::record(&mut tx, JournalScope::Book(id), "update_book", &selection, before).await?;
+    tx.commit().await?;
     
     Ok(())
 }
@@ -71,10 +89,13 @@ pub async fn update_book(id: i32, title: String, parent_id: Option<i32>) -> Resu
 pub async fn delete_book(id: i32) -> Result<(), sqlx::Error> {
     let pool = get_db_pool().await;
 
-    sqlx::query("DELETE FROM Book WHERE id = ?")
-        .bind(id)
-        .execute(&*pool)
-        .await?;
+    // 휴지통으로 이동 (하위 북/페이지까지 같은 시각으로 표시, 영구 삭제는 trash::purge)
+    let mut tx = pool.begin().await?;
+    let selection = Selection::BookTree(id);
+    let before = journal::capture(&mut tx, &selection).await?;
+    trash::trash_book(&mut tx, id).await?;
+    journal::record(&mut tx, JournalScope::Book(id), "delete_book", &selection, before).await?;
+    tx.commit().await?;
     
     Ok(())
 }
\ No newline at end of file
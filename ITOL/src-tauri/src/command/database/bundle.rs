@@ -0,0 +1,509 @@
+use super::book::BOOK_TREE;
+use super::get_db_pool;
+use super::history;
+use super::journal::{self, JournalScope, Selection};
+use super::trash;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::{Pool, Row, Sqlite};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+/// 번들 파일 식별자와 현재 버전 (구조가 바뀌면 올리고 parse_bundle에서 변환)
+pub const BUNDLE_FORMAT: &str = "itol-bundle";
+pub const BUNDLE_VERSION: u32 = 1;
+
+/// 북 하위 트리를 공유/버전 관리하기 위한 번들
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BookBundle {
+    pub format: String,
+    pub version: u32,
+    #[serde(default)]
+    pub exported_at: Option<String>,
+    pub book: BundleBook,
+}
+
+/// id는 내보낸 DB 기준이며 가져올 때 새 id로 다시 매핑됨
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleBook {
+    #[serde(default)]
+    pub id: Option<i32>,
+    pub title: String,
+    #[serde(default)]
+    pub pages: Vec<BundlePage>,
+    #[serde(default)]
+    pub books: Vec<BundleBook>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BundlePage {
+    #[serde(default)]
+    pub id: Option<i32>,
+    pub title: String,
+    #[serde(default)]
+    pub display_order: i32,
+    #[serde(default)]
+    pub flow_data: Option<Value>,
+    #[serde(default)]
+    pub nodes: Vec<BundleNode>,
+    #[serde(default)]
+    pub edges: Vec<BundleEdge>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleNode {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub node_type: String,
+    #[serde(default)]
+    pub position_x: i32,
+    #[serde(default)]
+    pub position_y: i32,
+    pub data: Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleEdge {
+    pub source: String,
+    pub target: String,
+    #[serde(default)]
+    pub source_handle: Option<String>,
+    #[serde(default)]
+    pub target_handle: Option<String>,
+}
+
+/// 같은 위치에 같은 이름의 북이 있을 때 처리 방법
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConflictStrategy {
+    /// "제목 (2)" 처럼 이름을 바꿔서 가져옴
+    #[default]
+    Rename,
+    /// 가져오지 않음
+    Skip,
+    /// 기존 북(하위 포함)을 지우고 가져옴
+    Replace,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleImportResult {
+    /// 가져온 최상위 북 id (skip이면 None)
+    pub book_id: Option<i32>,
+    pub title: String,
+    pub books: usize,
+    pub pages: usize,
+    pub nodes: usize,
+    pub edges: usize,
+    pub skipped: bool,
+    pub replaced_book_id: Option<i32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BundleFormat {
+    Json,
+    Yaml,
+}
+
+impl BundleFormat {
+    /// 확장자로 형식 결정 (.yaml/.yml 이외는 JSON)
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()).map(str::to_ascii_lowercase).as_deref() {
+            Some("yaml") | Some("yml") => BundleFormat::Yaml,
+            _ => BundleFormat::Json,
+        }
+    }
+}
+
+pub fn bundle_to_string(bundle: &BookBundle, format: BundleFormat) -> Result<String, String> {
+    match format {
+        BundleFormat::Json => serde_json::to_string_pretty(bundle).map_err(|e| format!("Failed to serialize bundle: {}", e)),
+        BundleFormat::Yaml => serde_yaml::to_string(bundle).map_err(|e| format!("Failed to serialize bundle: {}", e)),
+    }
+}
+
+/// JSON 또는 YAML 번들 파싱 및 형식/버전 확인
+pub fn parse_bundle(content: &str) -> Result<BookBundle, String> {
+    let bundle: BookBundle = if content.trim_start().starts_with('{') {
+        serde_json::from_str(content).map_err(|e| format!("Invalid bundle JSON: {}", e))?
+    } else {
+        serde_yaml::from_str(content).map_err(|e| format!("Invalid bundle YAML: {}", e))?
+    };
+
+    if bundle.format != BUNDLE_FORMAT {
+        return Err(format!("Not an ITOL bundle (format: {})", bundle.format));
+    }
+    if bundle.version == 0 || bundle.version > BUNDLE_VERSION {
+        return Err(format!(
+            "Unsupported bundle version {} (supported up to {})",
+            bundle.version, BUNDLE_VERSION
+        ));
+    }
+    Ok(bundle)
+}
+
+/// 노드 데이터 안의 "pageId" 값을 새 페이지 id로 변경 (번들 밖 페이지는 그대로)
+fn remap_page_refs(value: &mut Value, page_ids: &HashMap<i32, i32>) {
+    match value {
+        Value::Object(map) => {
+            for (key, item) in map.iter_mut() {
+                if key == "pageId" {
+                    if let Some(new_id) = item.as_i64().and_then(|id| page_ids.get(&(id as i32))) {
+                        *item = Value::from(*new_id);
+                        continue;
+                    }
+                }
+                remap_page_refs(item, page_ids);
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(|item| remap_page_refs(item, page_ids)),
+        _ => {}
+    }
+}
+
+/// "제목", "제목 (2)", "제목 (3)" ... 중 사용되지 않은 첫 이름
+pub(crate) fn unique_title(title: &str, existing: &HashSet<String>) -> String {
+    if !existing.contains(title) {
+        return title.to_string();
+    }
+    (2..)
+        .map(|n| format!("{} ({})", title, n))
+        .find(|candidate| !existing.contains(candidate))
+        .unwrap_or_else(|| title.to_string())
+}
+
+async fn export_book(pool: &Pool<Sqlite>, book_id: i32, visited: &mut HashSet<i32>) -> Result<BundleBook, sqlx::Error> {
+    visited.insert(book_id);
+    let title: String = sqlx::query_scalar("SELECT title FROM Book WHERE id = ?")
+        .bind(book_id)
+        .fetch_one(pool)
+        .await?;
+
+    let mut pages = Vec::new();
+    let page_rows = sqlx::query("SELECT id, title, flow_data, display_order FROM Page WHERE fk_book_id = ? AND deleted_at IS NULL ORDER BY display_order, id")
+        .bind(book_id)
+        .fetch_all(pool)
+        .await?;
+    for row in page_rows {
+        let page_id: i32 = row.get(0);
+        let flow_data: Option<String> = row.get(2);
+
+        let nodes = sqlx::query("SELECT id, type, position_x, position_y, data FROM Node WHERE fk_page_id = ? ORDER BY rowid")
+            .bind(page_id)
+            .fetch_all(pool)
+            .await?
+            .into_iter()
+            .map(|n| {
+                let data: String = n.get(4);
+                BundleNode {
+                    id: n.get(0),
+                    node_type: n.get(1),
+                    position_x: n.get(2),
+                    position_y: n.get(3),
+                    data: serde_json::from_str(&data).unwrap_or(Value::String(data)),
+                }
+            })
+            .collect();
+        let edges = sqlx::query("SELECT source, target, sourceHandle, targetHandle FROM Edge WHERE fk_page_id = ? ORDER BY id")
+            .bind(page_id)
+            .fetch_all(pool)
+            .await?
+            .into_iter()
+            .map(|e| BundleEdge {
+                source: e.get(0),
+                target: e.get(1),
+                source_handle: e.get(2),
+                target_handle: e.get(3),
+            })
+            .collect();
+
+        pages.push(BundlePage {
+            id: Some(page_id),
+            title: row.get(1),
+            display_order: row.get(3),
+            // flow_data는 읽기 쉽도록 JSON 그대로 포함
+            flow_data: flow_data.map(|s| serde_json::from_str(&s).unwrap_or(Value::String(s))),
+            nodes,
+            edges,
+        });
+    }
+
+    let child_ids: Vec<i32> = sqlx::query_scalar("SELECT id FROM Book WHERE parent_id = ? AND deleted_at IS NULL ORDER BY id")
+        .bind(book_id)
+        .fetch_all(pool)
+        .await?;
+    let mut books = Vec::new();
+    for child_id in child_ids {
+        if !visited.contains(&child_id) {
+            books.push(Box::pin(export_book(pool, child_id, visited)).await?);
+        }
+    }
+
+    Ok(BundleBook { id: Some(book_id), title, pages, books })
+}
+
+pub async fn export_book_bundle_with(pool: &Pool<Sqlite>, book_id: i32) -> Result<BookBundle, String> {
+    let book = export_book(pool, book_id, &mut HashSet::new())
+        .await
+        .map_err(|e| format!("Failed to export book {}: {}", book_id, e))?;
+    Ok(BookBundle {
+        format: BUNDLE_FORMAT.to_string(),
+        version: BUNDLE_VERSION,
+        exported_at: Some(chrono::Utc::now().to_rfc3339()),
+        book,
+    })
+}
+
+/// 북과 하위 북/페이지/노드/엣지를 번들로 내보내기
+pub async fn export_book_bundle(book_id: i32) -> Result<BookBundle, String> {
+    let pool = get_db_pool().await;
+    export_book_bundle_with(&pool, book_id).await
+}
+
+pub async fn import_book_bundle_with(
+    pool: &Pool<Sqlite>,
+    bundle: &BookBundle,
+    parent_id: Option<i32>,
+    strategy: ConflictStrategy,
+) -> Result<BundleImportResult, String> {
+    let map_err = |e: sqlx::Error| format!("Failed to import bundle: {}", e);
+    let mut tx = pool.begin().await.map_err(map_err)?;
+    let result = import_book_bundle_tx(&mut tx, bundle, parent_id, strategy).await?;
+    tx.commit().await.map_err(map_err)?;
+    Ok(result)
+}
+
+/// 호출자의 트랜잭션 안에서 번들 가져오기 (커밋은 호출자가 함)
+pub(crate) async fn import_book_bundle_tx(
+    tx: &mut sqlx::Transaction<'_, Sqlite>,
+    bundle: &BookBundle,
+    parent_id: Option<i32>,
+    strategy: ConflictStrategy,
+) -> Result<BundleImportResult, String> {
+    let map_err = |e: sqlx::Error| format!("Failed to import bundle: {}", e);
+    let mut result = BundleImportResult::default();
+
+    // 같은 부모 아래 같은 제목의 북이 있으면 충돌
+    let siblings = sqlx::query("SELECT id, title FROM Book WHERE parent_id IS ? AND deleted_at IS NULL")
+        .bind(parent_id)
+        .fetch_all(&mut **tx)
+        .await
+        .map_err(map_err)?;
+    let existing = siblings
+        .iter()
+        .find(|row| row.get::<String, _>(1) == bundle.book.title)
+        .map(|row| row.get::<i32, _>(0));
+
+    let mut root_title = bundle.book.title.clone();
+    if let Some(existing_id) = existing {
+        match strategy {
+            ConflictStrategy::Skip => {
+                result.title = root_title;
+                result.skipped = true;
+                return Ok(result);
+            }
+            ConflictStrategy::Replace => {
+                // 기존 북은 지우지 않고 휴지통으로 옮김 (휴지통에서 복원 가능)
+                trash::trash_book(tx, existing_id).await.map_err(map_err)?;
+                result.replaced_book_id = Some(existing_id);
+            }
+            ConflictStrategy::Rename => {
+                let titles: HashSet<String> = siblings.iter().map(|row| row.get(1)).collect();
+                root_title = unique_title(&root_title, &titles);
+            }
+        }
+    }
+
+    // 1단계: 북/페이지 생성하며 이전 id → 새 id 매핑
+    let mut page_ids: HashMap<i32, i32> = HashMap::new();
+    let mut created_pages: Vec<(i32, &BundlePage)> = Vec::new();
+    let mut stack: Vec<(&BundleBook, Option<i32>, String)> = vec![(&bundle.book, parent_id, root_title.clone())];
+    while let Some((book, parent, title)) = stack.pop() {
+        let book_id = sqlx::query("INSERT INTO Book (title, parent_id) VALUES (?, ?)")
+            .bind(&title)
+            .bind(parent)
+            .execute(&mut **tx)
+            .await
+            .map_err(map_err)?
+            .last_insert_rowid() as i32;
+        if result.book_id.is_none() {
+            result.book_id = Some(book_id);
+        }
+        result.books += 1;
+
+        for page in &book.pages {
+            let page_id = sqlx::query("INSERT INTO Page (fk_book_id, title, flow_data, display_order) VALUES (?, ?, NULL, ?)")
+                .bind(book_id)
+                .bind(&page.title)
+                .bind(page.display_order)
+                .execute(&mut **tx)
+                .await
+                .map_err(map_err)?
+                .last_insert_rowid() as i32;
+            if let Some(old_id) = page.id {
+                page_ids.insert(old_id, page_id);
+            }
+            created_pages.push((page_id, page));
+        }
+        for child in book.books.iter().rev() {
+            stack.push((child, Some(book_id), child.title.clone()));
+        }
+    }
+
+    // 2단계: 페이지 참조를 새 id로 바꿔서 flow_data/노드/엣지 저장
+    for (page_id, page) in created_pages {
+        if let Some(flow_data) = &page.flow_data {
+            let mut flow_data = flow_data.clone();
+            remap_page_refs(&mut flow_data, &page_ids);
+            let flow_data = match flow_data {
+                Value::String(s) => s,
+                other => other.to_string(),
+            };
+            sqlx::query("UPDATE Page SET flow_data = ? WHERE id = ?")
+                .bind(flow_data)
+                .bind(page_id)
+                .execute(&mut **tx)
+                .await
+                .map_err(map_err)?;
+        }
+        result.pages += 1;
+
+        for node in &page.nodes {
+            let mut data = node.data.clone();
+            remap_page_refs(&mut data, &page_ids);
+            // JSON이 아니던 data는 문자열로 내보냈으므로 그대로 저장
+            let data = match data {
+                Value::String(s) => s,
+                other => other.to_string(),
+            };
+            sqlx::query("INSERT INTO Node (id, fk_page_id, data, type, position_x, position_y) VALUES (?, ?, ?, ?, ?, ?)")
+                .bind(&node.id)
+                .bind(page_id)
+                .bind(data)
+                .bind(&node.node_type)
+                .bind(node.position_x)
+                .bind(node.position_y)
+                .execute(&mut **tx)
+                .await
+                .map_err(map_err)?;
+            result.nodes += 1;
+        }
+        for edge in &page.edges {
+            sqlx::query("INSERT INTO Edge (fk_page_id, source, target, sourceHandle, targetHandle) VALUES (?, ?, ?, ?, ?)")
+                .bind(page_id)
+                .bind(&edge.source)
+                .bind(&edge.target)
+                .bind(&edge.source_handle)
+                .bind(&edge.target_handle)
+                .execute(&mut **tx)
+                .await
+                .map_err(map_err)?;
+            result.edges += 1;
+        }
+    }
+
+    result.title = root_title;
+    Ok(result)
+}
+
+/// 새로 만든 북 트리의 페이지마다 message로 첫 버전을 남기고, 전체를 하나의 되돌리기 단위(label)로 기록
+pub(crate) async fn record_created_book(
+    tx: &mut sqlx::Transaction<'_, Sqlite>,
+    book_id: i32,
+    label: &str,
+    message: &str,
+) -> Result<(), sqlx::Error> {
+    let page_ids: Vec<i32> = sqlx::query_scalar(&format!(
+        "{} SELECT Page.id FROM Page JOIN tree ON Page.fk_book_id = tree.id ORDER BY Page.id",
+        BOOK_TREE
+    ))
+    .bind(book_id)
+    .fetch_all(&mut **tx)
+    .await?;
+    for page_id in page_ids {
+        history::record_version(tx, page_id, None, Some(message.to_string()), true).await?;
+    }
+    journal::record(tx, JournalScope::Book(book_id), label, &Selection::BookTree(book_id), Vec::new()).await
+}
+
+/// 번들을 parent_id 아래(없으면 최상위)에 가져오기 - 전체가 하나의 트랜잭션
+pub async fn import_book_bundle(
+    bundle: &BookBundle,
+    parent_id: Option<i32>,
+    strategy: ConflictStrategy,
+) -> Result<BundleImportResult, String> {
+    let pool = get_db_pool().await;
+    import_book_bundle_with(&pool, bundle, parent_id, strategy).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::database::migration;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_bundle_checks_format_and_version() {
+        let yaml = "format: itol-bundle\nversion: 1\nbook:\n  title: Orders\n  pages:\n    - title: Create\n";
+        let bundle = parse_bundle(yaml).unwrap();
+        assert_eq!(bundle.book.pages[0].title, "Create");
+
+        assert!(parse_bundle(r#"{"format": "itol-bundle", "version": 99, "book": {"title": "x"}}"#).is_err());
+        assert!(parse_bundle(r#"{"format": "other", "version": 1, "book": {"title": "x"}}"#).is_err());
+    }
+
+    #[test]
+    fn test_export_import_remaps_ids_and_handles_conflicts() {
+        let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+        runtime.block_on(async {
+            let pool = migration::empty_test_pool().await;
+            sqlx::query("INSERT INTO Book (id, title, parent_id) VALUES (1, 'Orders', NULL), (2, 'Admin', 1)")
+                .execute(&pool).await.unwrap();
+            sqlx::query("INSERT INTO Page (id, fk_book_id, title, flow_data, display_order) VALUES (10, 1, 'Create', '{\"nodes\":[]}', 0), (11, 2, 'Cleanup', NULL, 0)")
+                .execute(&pool).await.unwrap();
+            sqlx::query("INSERT INTO Node (id, fk_page_id, data, type, position_x, position_y) VALUES ('a', 10, ?, 'apiNode', 0, 0), ('b', 10, '{}', 'dbNode', 100, 0), ('c', 11, 'not json', 'fileNode', 0, 0)")
+                .bind(json!({ "type": "subflow", "pageId": 11 }).to_string())
+                .execute(&pool).await.unwrap();
+            sqlx::query("INSERT INTO Edge (fk_page_id, source, target) VALUES (10, 'a', 'b')")
+                .execute(&pool).await.unwrap();
+
+            let bundle = export_book_bundle_with(&pool, 1).await.unwrap();
+            let text = bundle_to_string(&bundle, BundleFormat::Yaml).unwrap();
+            let bundle = parse_bundle(&text).unwrap();
+            assert_eq!(bundle.book.books[0].title, "Admin");
+
+            // 이름 충돌 → "Orders (2)" 로 가져오고 pageId 참조는 새 페이지로 변경
+            let imported = import_book_bundle_with(&pool, &bundle, None, ConflictStrategy::Rename).await.unwrap();
+            assert_eq!(imported.title, "Orders (2)");
+            assert_eq!((imported.books, imported.pages, imported.nodes, imported.edges), (2, 2, 3, 1));
+            let new_cleanup: i32 = sqlx::query_scalar("SELECT id FROM Page WHERE title = 'Cleanup' AND id != 11")
+                .fetch_one(&pool).await.unwrap();
+            let data: String = sqlx::query_scalar("SELECT n.data FROM Node n JOIN Page p ON p.id = n.fk_page_id WHERE n.id = 'a' AND p.fk_book_id = ?")
+                .bind(imported.book_id.unwrap())
+                .fetch_one(&pool).await.unwrap();
+            assert_eq!(serde_json::from_str::<Value>(&data).unwrap()["pageId"], json!(new_cleanup));
+            // JSON이 아닌 data는 따옴표 없이 그대로 돌아옴
+            let raw: String = sqlx::query_scalar("SELECT data FROM Node WHERE id = 'c' AND fk_page_id = ?")
+                .bind(new_cleanup)
+                .fetch_one(&pool).await.unwrap();
+            assert_eq!(raw, "not json");
+
+            let skipped = import_book_bundle_with(&pool, &bundle, None, ConflictStrategy::Skip).await.unwrap();
+            assert!(skipped.skipped && skipped.book_id.is_none());
+
+            let replaced = import_book_bundle_with(&pool, &bundle, None, ConflictStrategy::Replace).await.unwrap();
+            assert_eq!(replaced.replaced_book_id, Some(1));
+            let orders: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM Book WHERE title = 'Orders' AND deleted_at IS NULL")
+                .fetch_one(&pool).await.unwrap();
+            assert_eq!(orders, 1);
+            // 교체된 북은 노드와 함께 휴지통에 남음
+            let trashed = trash::list_trash_with(&pool).await.unwrap();
+            assert_eq!((trashed.len(), trashed[0].id, trashed[0].pages, trashed[0].nodes), (1, 1, 2, 3));
+        });
+    }
+}
@@ -0,0 +1,601 @@
+use super::bundle::{self, BundleBook, BundleImportResult, BundlePage, ConflictStrategy};
+use super::edge::Edge;
+use super::get_db_pool;
+use super::graph::{self, GraphEdgeInput, GraphNodeInput};
+use super::history;
+use super::journal::{self, JournalScope, Selection};
+use super::node::Node;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::{Pool, Row, Sqlite, SqliteConnection};
+use std::collections::{HashMap, HashSet};
+
+/// 같은 페이지에 붙여넣을 때 원본과 겹치지 않도록 기본으로 옮기는 거리
+const SAME_PAGE_OFFSET: i32 = 40;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PageCloneResult {
+    pub page_id: i32,
+    pub book_id: i32,
+    pub title: String,
+    /// 원본 노드 id → 새 노드 id
+    pub node_ids: HashMap<String, String>,
+    pub nodes: usize,
+    pub edges: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeCopyResult {
+    pub page_id: i32,
+    /// 원본 노드 id → 새 노드 id
+    pub node_ids: HashMap<String, String>,
+    pub nodes: Vec<Node>,
+    pub edges: Vec<Edge>,
+}
+
+/// 복사한 노드의 새 id 발급
+/// 프런트엔드 규칙("api-node-<ms>")을 따라 숫자 꼬리만 현재 시각 기준으로 바꿈
+struct NodeIdAllocator {
+    base: i64,
+    used: HashSet<String>,
+}
+
+impl NodeIdAllocator {
+    fn new(used: HashSet<String>) -> Self {
+        Self { base: chrono::Utc::now().timestamp_millis(), used }
+    }
+
+    fn allocate(&mut self, old_id: &str) -> String {
+        let prefix = match old_id.rsplit_once('-') {
+            Some((prefix, suffix)) if !prefix.is_empty() && suffix.chars().all(|c| c.is_ascii_digit()) => prefix,
+            _ => old_id,
+        };
+        loop {
+            let candidate = format!("{}-{}", prefix, self.base);
+            self.base += 1;
+            if self.used.insert(candidate.clone()) {
+                return candidate;
+            }
+        }
+    }
+}
+
+/// 노드 data 안에서 같은 페이지의 다른 노드 id를 값으로 가지는 필드
+/// (서브플로우 노드의 outputNodeId는 호출되는 페이지(pageId)의 노드이므로 remap_subflow_outputs에서 처리)
+const NODE_REF_FIELDS: [&str; 2] = ["sourceNodeId", "referenceNodeId"];
+
+fn remap_id(value: Option<&mut Value>, node_ids: &HashMap<String, String>) {
+    if let Some(Value::String(id)) = value {
+        if let Some(new_id) = node_ids.get(id.as_str()) {
+            *id = new_id.clone();
+        }
+    }
+}
+
+/// 노드 data 안의 노드 id 참조를 새 id로 변경
+/// 참조 필드(NODE_REF_FIELDS)의 값과 문자열 안의 "{{nodeId.path}}" 템플릿만 바꿈
+fn remap_node_data(value: &mut Value, node_ids: &HashMap<String, String>) {
+    match value {
+        Value::String(text) if text.contains("{{") => *text = remap_templates(text, node_ids),
+        Value::Array(items) => items.iter_mut().for_each(|item| remap_node_data(item, node_ids)),
+        Value::Object(map) => {
+            for (key, item) in map.iter_mut() {
+                if NODE_REF_FIELDS.contains(&key.as_str()) && item.is_string() {
+                    remap_id(Some(item), node_ids);
+                } else {
+                    remap_node_data(item, node_ids);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// flow_data(React Flow 상태)의 nodes[].id/data와 edges[].source/target 변경
+fn remap_flow_data(value: &mut Value, node_ids: &HashMap<String, String>) {
+    if let Some(Value::Array(nodes)) = value.get_mut("nodes") {
+        for node in nodes {
+            remap_id(node.get_mut("id"), node_ids);
+            if let Some(data) = node.get_mut("data") {
+                remap_node_data(data, node_ids);
+            }
+        }
+    }
+    if let Some(Value::Array(edges)) = value.get_mut("edges") {
+        for edge in edges {
+            remap_id(edge.get_mut("source"), node_ids);
+            remap_id(edge.get_mut("target"), node_ids);
+        }
+    }
+}
+
+/// 서브플로우 노드({pageId, outputNodeId})의 출력 노드 id를 호출되는 페이지(pageId)의 새 id로 변경
+/// 호출되는 페이지가 함께 복제되지 않았으면 그대로 둠
+fn remap_subflow_outputs(value: &mut Value, page_node_ids: &HashMap<i32, &HashMap<String, String>>) {
+    match value {
+        Value::Array(items) => items.iter_mut().for_each(|item| remap_subflow_outputs(item, page_node_ids)),
+        Value::Object(map) => {
+            let called = map.get("pageId").and_then(Value::as_i64).and_then(|id| page_node_ids.get(&(id as i32)));
+            if let Some(node_ids) = called {
+                remap_id(map.get_mut("outputNodeId"), node_ids);
+            }
+            map.values_mut().for_each(|item| remap_subflow_outputs(item, page_node_ids));
+        }
+        _ => {}
+    }
+}
+
+fn remap_templates(text: &str, node_ids: &HashMap<String, String>) -> String {
+    node_ids.iter().fold(text.to_string(), |text, (old_id, new_id)| {
+        text.replace(&format!("{{{{{}.", old_id), &format!("{{{{{}.", new_id))
+    })
+}
+
+/// 노드 data/flow_data 문자열 안의 참조 변경 (JSON이 아니면 템플릿만 바꿈)
+fn remap_json_text(
+    text: &str,
+    node_ids: &HashMap<String, String>,
+    remap: fn(&mut Value, &HashMap<String, String>),
+) -> String {
+    match serde_json::from_str::<Value>(text) {
+        Ok(mut value) => {
+            remap(&mut value, node_ids);
+            serde_json::to_string(&value).unwrap_or_else(|_| text.to_string())
+        }
+        Err(_) => remap_templates(text, node_ids),
+    }
+}
+
+/// 노드/엣지 목록을 새 id로 복사 (양 끝이 모두 선택된 엣지만 유지)
+fn copy_graph(
+    nodes: &[Node],
+    edges: &[Edge],
+    allocator: &mut NodeIdAllocator,
+    offset: (i32, i32),
+) -> (HashMap<String, String>, Vec<GraphNodeInput>, Vec<GraphEdgeInput>) {
+    let node_ids: HashMap<String, String> = nodes
+        .iter()
+        .map(|node| (node.id.clone(), allocator.allocate(&node.id)))
+        .collect();
+
+    let node_inputs = nodes
+        .iter()
+        .map(|node| GraphNodeInput {
+            id: node_ids[&node.id].clone(),
+            data: remap_json_text(&node.data, &node_ids, remap_node_data),
+            node_type: node.node_type.clone(),
+            position_x: node.position_x + offset.0,
+            position_y: node.position_y + offset.1,
+        })
+        .collect();
+    let edge_inputs = edges
+        .iter()
+        .filter_map(|edge| {
+            Some(GraphEdgeInput {
+                id: None,
+                source: node_ids.get(&edge.source)?.clone(),
+                target: node_ids.get(&edge.target)?.clone(),
+                source_handle: edge.source_handle.clone(),
+                target_handle: edge.target_handle.clone(),
+            })
+        })
+        .collect();
+    (node_ids, node_inputs, edge_inputs)
+}
+
+fn collect_pages<'a>(book: &'a mut BundleBook, pages: &mut Vec<&'a mut BundlePage>) {
+    pages.extend(book.pages.iter_mut());
+    for child in &mut book.books {
+        collect_pages(child, pages);
+    }
+}
+
+/// 북 트리 안 모든 페이지의 노드 id를 새로 발급 (페이지마다 따로 매핑)
+fn remap_bundle_book(book: &mut BundleBook, allocator: &mut NodeIdAllocator) {
+    let mut pages = Vec::new();
+    collect_pages(book, &mut pages);
+    let node_maps: Vec<HashMap<String, String>> = pages
+        .iter()
+        .map(|page| {
+            page.nodes
+                .iter()
+                .map(|node| (node.id.clone(), allocator.allocate(&node.id)))
+                .collect()
+        })
+        .collect();
+    let page_node_ids: HashMap<i32, &HashMap<String, String>> = pages
+        .iter()
+        .zip(&node_maps)
+        .filter_map(|(page, node_ids)| Some((page.id?, node_ids)))
+        .collect();
+
+    for (page, node_ids) in pages.into_iter().zip(&node_maps) {
+        for node in &mut page.nodes {
+            node.id = node_ids[&node.id].clone();
+            remap_node_data(&mut node.data, node_ids);
+            remap_subflow_outputs(&mut node.data, &page_node_ids);
+        }
+        for edge in &mut page.edges {
+            if let Some(new_id) = node_ids.get(&edge.source) {
+                edge.source = new_id.clone();
+            }
+            if let Some(new_id) = node_ids.get(&edge.target) {
+                edge.target = new_id.clone();
+            }
+        }
+        if let Some(flow_data) = page.flow_data.as_mut() {
+            remap_flow_data(flow_data, node_ids);
+            remap_subflow_outputs(flow_data, &page_node_ids);
+        }
+    }
+}
+
+/// 북과 하위 북/페이지/노드/엣지 전체를 복제
+/// parent_id가 None이면 원본과 같은 부모 아래에 "제목 (2)" 형태로 만듦
+pub async fn clone_book_with(
+    pool: &Pool<Sqlite>,
+    book_id: i32,
+    parent_id: Option<i32>,
+) -> Result<BundleImportResult, String> {
+    let original_parent: Option<i32> = sqlx::query_scalar("SELECT parent_id FROM Book WHERE id = ?")
+        .bind(book_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| format!("Failed to clone book {}: {}", book_id, e))?
+        .ok_or_else(|| format!("Book {} not found", book_id))?;
+
+    let mut bundle = bundle::export_book_bundle_with(pool, book_id).await?;
+    remap_bundle_book(&mut bundle.book, &mut NodeIdAllocator::new(HashSet::new()));
+
+    let map_err = |e: sqlx::Error| format!("Failed to clone book {}: {}", book_id, e);
+    let mut tx = pool.begin().await.map_err(map_err)?;
+    let result =
+        bundle::import_book_bundle_tx(&mut tx, &bundle, parent_id.or(original_parent), ConflictStrategy::Rename).await?;
+    if let Some(new_book_id) = result.book_id {
+        // 복제 전체를 하나의 되돌리기 단위로 기록
+        bundle::record_created_book(&mut tx, new_book_id, "clone_book", &format!("Cloned from book {}", book_id))
+            .await
+            .map_err(map_err)?;
+    }
+    tx.commit().await.map_err(map_err)?;
+    Ok(result)
+}
+
+pub async fn clone_book(book_id: i32, parent_id: Option<i32>) -> Result<BundleImportResult, String> {
+    let pool = get_db_pool().await;
+    clone_book_with(&pool, book_id, parent_id).await
+}
+
+async fn page_node_ids(conn: &mut SqliteConnection, page_id: i32) -> Result<HashSet<String>, sqlx::Error> {
+    sqlx::query_scalar("SELECT id FROM Node WHERE fk_page_id = ?")
+        .bind(page_id)
+        .fetch_all(&mut *conn)
+        .await
+        .map(|ids| ids.into_iter().collect())
+}
+
+/// 페이지 하나를 복제해 대상 북(기본: 같은 북)의 마지막에 추가
+pub async fn clone_page_with(
+    pool: &Pool<Sqlite>,
+    page_id: i32,
+    target_book_id: Option<i32>,
+) -> Result<PageCloneResult, String> {
+    let map_err = |e: sqlx::Error| format!("Failed to clone page {}: {}", page_id, e);
+    let mut tx = pool.begin().await.map_err(map_err)?;
+
+    let row = sqlx::query("SELECT fk_book_id, title FROM Page WHERE id = ?")
+        .bind(page_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(map_err)?
+        .ok_or_else(|| format!("Page {} not found", page_id))?;
+    let book_id = target_book_id.unwrap_or_else(|| row.get(0));
+    let source_title: String = row.get(1);
+
+    let titles: HashSet<String> = sqlx::query_scalar("SELECT title FROM Page WHERE fk_book_id = ? AND deleted_at IS NULL")
+        .bind(book_id)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(map_err)?
+        .into_iter()
+        .collect();
+    let title = bundle::unique_title(&source_title, &titles);
+    let max_order: Option<i32> = sqlx::query_scalar("SELECT MAX(display_order) FROM Page WHERE fk_book_id = ?")
+        .bind(book_id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(map_err)?;
+    let new_page_id = sqlx::query("INSERT INTO Page (fk_book_id, title, display_order) VALUES (?, ?, ?)")
+        .bind(book_id)
+        .bind(&title)
+        .bind(max_order.unwrap_or(-1) + 1)
+        .execute(&mut *tx)
+        .await
+        .map_err(map_err)?
+        .last_insert_rowid() as i32;
+
+    let source = graph::load_page_graph(&mut tx, page_id).await.map_err(map_err)?;
+    let (node_ids, nodes, edges) = copy_graph(
+        &source.nodes,
+        &source.edges,
+        &mut NodeIdAllocator::new(HashSet::new()),
+        (0, 0),
+    );
+    let flow_data = source.flow_data.as_deref().map(|text| remap_json_text(text, &node_ids, remap_flow_data));
+    graph::apply_page_graph(&mut tx, new_page_id, flow_data, &nodes, &edges).await?;
+    history::record_version(&mut tx, new_page_id, None, Some(format!("Cloned from page {}", page_id)), true)
+        .await
+        .map_err(map_err)?;
+    journal::record(
+        &mut tx,
+        JournalScope::Page(new_page_id),
+        "clone_page",
+        &Selection::Page(new_page_id),
+        Vec::new(),
+    )
+    .await
+    .map_err(map_err)?;
+    tx.commit().await.map_err(map_err)?;
+
+    Ok(PageCloneResult {
+        page_id: new_page_id,
+        book_id,
+        title,
+        node_ids,
+        nodes: nodes.len(),
+        edges: edges.len(),
+    })
+}
+
+pub async fn clone_page(page_id: i32, target_book_id: Option<i32>) -> Result<PageCloneResult, String> {
+    let pool = get_db_pool().await;
+    clone_page_with(&pool, page_id, target_book_id).await
+}
+
+/// 선택한 노드들과 그 사이의 엣지를 다른(또는 같은) 페이지에 복사
+/// offset이 없으면 같은 페이지일 때만 살짝 옮겨서 붙여넣음
+pub async fn copy_nodes_with(
+    pool: &Pool<Sqlite>,
+    source_page_id: i32,
+    node_ids: Vec<String>,
+    target_page_id: i32,
+    offset: Option<(i32, i32)>,
+) -> Result<NodeCopyResult, String> {
+    let map_err = |e: sqlx::Error| format!("Failed to copy nodes to page {}: {}", target_page_id, e);
+    let mut tx = pool.begin().await.map_err(map_err)?;
+
+    let source = graph::load_page_graph(&mut tx, source_page_id).await.map_err(map_err)?;
+    let selected: HashSet<&str> = node_ids.iter().map(String::as_str).collect();
+    if let Some(missing) = node_ids.iter().find(|id| !source.nodes.iter().any(|node| &node.id == *id)) {
+        return Err(format!("Node '{}' not found in page {}", missing, source_page_id));
+    }
+    let nodes: Vec<Node> = source
+        .nodes
+        .into_iter()
+        .filter(|node| selected.contains(node.id.as_str()))
+        .collect();
+
+    let target = graph::load_page_graph(&mut tx, target_page_id).await.map_err(map_err)?;
+    let default_offset = if source_page_id == target_page_id { SAME_PAGE_OFFSET } else { 0 };
+    let used = page_node_ids(&mut tx, target_page_id).await.map_err(map_err)?;
+    let (id_map, copied_nodes, copied_edges) = copy_graph(
+        &nodes,
+        &source.edges,
+        &mut NodeIdAllocator::new(used),
+        offset.unwrap_or((default_offset, default_offset)),
+    );
+
+    // 기존 그래프 + 복사본을 함께 적용 (기존 노드/엣지는 그대로 유지됨)
+    let all_nodes: Vec<GraphNodeInput> = target
+        .nodes
+        .iter()
+        .map(|node| GraphNodeInput {
+            id: node.id.clone(),
+            data: node.data.clone(),
+            node_type: node.node_type.clone(),
+            position_x: node.position_x,
+            position_y: node.position_y,
+        })
+        .chain(copied_nodes)
+        .collect();
+    let all_edges: Vec<GraphEdgeInput> = target
+        .edges
+        .iter()
+        .map(|edge| GraphEdgeInput {
+            id: Some(edge.id),
+            source: edge.source.clone(),
+            target: edge.target.clone(),
+            source_handle: edge.source_handle.clone(),
+            target_handle: edge.target_handle.clone(),
+        })
+        .chain(copied_edges)
+        .collect();
+
+    let selection = Selection::PageGraph(target_page_id);
+    let before = journal::capture(&mut tx, &selection).await.map_err(map_err)?;
+    graph::apply_page_graph(&mut tx, target_page_id, target.flow_data, &all_nodes, &all_edges).await?;
+    journal::record(&mut tx, JournalScope::Page(target_page_id), "copy_nodes", &selection, before)
+        .await
+        .map_err(map_err)?;
+
+    let graph = graph::load_page_graph(&mut tx, target_page_id).await.map_err(map_err)?;
+    tx.commit().await.map_err(map_err)?;
+
+    let new_ids: HashSet<&str> = id_map.values().map(String::as_str).collect();
+    Ok(NodeCopyResult {
+        page_id: target_page_id,
+        nodes: graph
+            .nodes
+            .into_iter()
+            .filter(|node| new_ids.contains(node.id.as_str()))
+            .collect(),
+        edges: graph
+            .edges
+            .into_iter()
+            .filter(|edge| new_ids.contains(edge.source.as_str()) && new_ids.contains(edge.target.as_str()))
+            .collect(),
+        node_ids: id_map,
+    })
+}
+
+pub async fn copy_nodes(
+    source_page_id: i32,
+    node_ids: Vec<String>,
+    target_page_id: i32,
+    offset: Option<(i32, i32)>,
+) -> Result<NodeCopyResult, String> {
+    let pool = get_db_pool().await;
+    copy_nodes_with(&pool, source_page_id, node_ids, target_page_id, offset).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::database::migration;
+
+    /// migration::test_pool의 Book 1 아래에 하위 북 2를 두고 Page 10을 그 북으로 옮김
+    async fn test_pool() -> Pool<Sqlite> {
+        let pool = migration::test_pool().await;
+        sqlx::query("UPDATE Book SET title = 'Root' WHERE id = 1")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO Book (id, title, parent_id) VALUES (2, 'Child', 1)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("UPDATE Page SET fk_book_id = 2, title = 'Login', flow_data = ? WHERE id = 10")
+            .bind(r#"{"nodes":[{"id":"api-node-1"},{"id":"api-node-2"}],"edges":[{"source":"api-node-1","target":"api-node-2"}]}"#)
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO Node (id, fk_page_id, data, type, position_x, position_y) VALUES ('api-node-1', 10, '{\"url\":\"/login\"}', 'apiNode', 0, 0), ('api-node-2', 10, ?, 'apiNode', 0, 200), ('db', 10, ?, 'dbNode', 300, 0)")
+            .bind(r#"{"headers":[{"key":"Authorization","value":"Bearer {{api-node-1.body.token}}"}]}"#)
+            .bind(r#"{"query":"SELECT 1","label":"api-node-1","params":[{"sourceNodeId":"api-node-1"}]}"#)
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO Edge (fk_page_id, source, target) VALUES (10, 'api-node-1', 'api-node-2'), (10, 'api-node-2', 'db')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        pool
+    }
+
+    #[test]
+    fn test_clone_page_and_book_remap_node_ids() {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let pool = test_pool().await;
+
+                let cloned = clone_page_with(&pool, 10, None).await.unwrap();
+                assert_eq!(cloned.title, "Login (2)");
+                assert_eq!(cloned.book_id, 2);
+                assert_eq!((cloned.nodes, cloned.edges), (3, 2));
+                let new_first = &cloned.node_ids["api-node-1"];
+                let new_second = &cloned.node_ids["api-node-2"];
+                assert!(new_first.starts_with("api-node-") && new_first != "api-node-1");
+                assert!(cloned.node_ids["db"].starts_with("db-"));
+
+                let mut conn = pool.acquire().await.unwrap();
+                let copy = graph::load_page_graph(&mut conn, cloned.page_id).await.unwrap();
+                let second = copy.nodes.iter().find(|node| &node.id == new_second).unwrap();
+                assert!(second.data.contains(&format!("{{{{{}.body.token}}}}", new_first)));
+                assert!(copy.edges.iter().any(|edge| &edge.source == new_first && &edge.target == new_second));
+                assert!(copy.flow_data.unwrap().contains(new_first.as_str()));
+                // 참조 필드만 바꾸고, 우연히 id와 같은 일반 문자열은 그대로 둠
+                let db = copy.nodes.iter().find(|node| node.id == cloned.node_ids["db"]).unwrap();
+                let data: Value = serde_json::from_str(&db.data).unwrap();
+                assert_eq!(data["label"], "api-node-1");
+                assert_eq!(data["params"][0]["sourceNodeId"], new_first.as_str());
+                drop(conn);
+
+                // 하위 북의 Login을 호출하는 서브플로우 페이지
+                sqlx::query("INSERT INTO Page (id, fk_book_id, title) VALUES (20, 1, 'Suite')").execute(&pool).await.unwrap();
+                sqlx::query("INSERT INTO Node VALUES ('subflow-node-1', 20, ?, 'subflowNode', 0, 0)")
+                    .bind(r#"{"type":"subflow","pageId":10,"outputNodeId":"api-node-2","inputs":[]}"#)
+                    .execute(&pool)
+                    .await
+                    .unwrap();
+
+                // 하위 북까지 복제, 원본은 그대로
+                let result = clone_book_with(&pool, 1, None).await.unwrap();
+                assert_eq!(result.title, "Root (2)");
+                assert_eq!((result.books, result.pages, result.nodes, result.edges), (2, 3, 7, 4));
+                let originals: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM Node WHERE id IN ('api-node-1', 'api-node-2', 'db')")
+                    .fetch_one(&pool)
+                    .await
+                    .unwrap();
+                assert_eq!(originals, 3);
+
+                // 복제된 서브플로우는 복제된 Login과 그 페이지의 새 출력 노드를 가리킴
+                let subflow: String = sqlx::query_scalar(
+                    "SELECT data FROM Node WHERE type = 'subflowNode' AND fk_page_id != 20",
+                )
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+                let subflow: Value = serde_json::from_str(&subflow).unwrap();
+                let (page_id, output) = (subflow["pageId"].as_i64().unwrap(), subflow["outputNodeId"].as_str().unwrap());
+                assert_ne!(page_id, 10);
+                let output_exists: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM Node WHERE fk_page_id = ? AND id = ?")
+                    .bind(page_id)
+                    .bind(output)
+                    .fetch_one(&pool)
+                    .await
+                    .unwrap();
+                assert_eq!(output_exists, 1);
+                let versions: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM PageVersion WHERE message = 'Cloned from book 1'")
+                    .fetch_one(&pool)
+                    .await
+                    .unwrap();
+                assert_eq!(versions, 3);
+
+                // 되돌리기로 복제한 북 트리 전체 삭제
+                let new_book_id = result.book_id.unwrap();
+                journal::undo_with(&pool, JournalScope::Book(new_book_id)).await.unwrap().unwrap();
+                let books: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM Book").fetch_one(&pool).await.unwrap();
+                assert_eq!(books, 2);
+                let nodes: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM Node").fetch_one(&pool).await.unwrap();
+                assert_eq!(nodes, 7);
+            });
+    }
+
+    #[test]
+    fn test_copy_nodes_keeps_internal_edges() {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let pool = test_pool().await;
+
+                let result = copy_nodes_with(&pool, 10, vec!["api-node-1".into(), "api-node-2".into()], 10, None)
+                    .await
+                    .unwrap();
+                assert_eq!(result.nodes.len(), 2);
+                assert_eq!(result.edges.len(), 1);
+                let first = result.nodes.iter().find(|node| node.id == result.node_ids["api-node-1"]).unwrap();
+                assert_eq!((first.position_x, first.position_y), (SAME_PAGE_OFFSET, SAME_PAGE_OFFSET));
+
+                let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM Node WHERE fk_page_id = 10")
+                    .fetch_one(&pool)
+                    .await
+                    .unwrap();
+                assert_eq!(total, 5);
+
+                // 되돌리기로 복사 전체 취소
+                journal::undo_with(&pool, JournalScope::Page(10)).await.unwrap();
+                let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM Node WHERE fk_page_id = 10")
+                    .fetch_one(&pool)
+                    .await
+                    .unwrap();
+                assert_eq!(total, 3);
+
+                assert!(copy_nodes_with(&pool, 10, vec!["missing".into()], 10, None).await.is_err());
+            });
+    }
+}
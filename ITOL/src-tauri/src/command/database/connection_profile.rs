@@ -0,0 +1,235 @@
+use super::get_db_pool;
+use crate::command::execution::db_system::DatabaseConnection;
+use sqlx::{Pool, Row, Sqlite};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConnectionProfile {
+    pub id: i32,
+    pub name: String,
+    pub connection: DatabaseConnection,
+}
+
+/// connection 컬럼(JSON 문자열)을 DatabaseConnection으로 변환
+fn map_profile(row: sqlx::sqlite::SqliteRow) -> Result<ConnectionProfile, sqlx::Error> {
+    let connection: String = row.get(2);
+    Ok(ConnectionProfile {
+        id: row.get(0),
+        name: row.get(1),
+        connection: serde_json::from_str(&connection).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+    })
+}
+
+/// 저장 전 검증: 이름이 비어 있거나 다른 프로필을 참조하면 거부
+fn validate(name: &str, connection: &DatabaseConnection) -> Result<(), String> {
+    if name.trim().is_empty() {
+        return Err("Connection profile name is required".to_string());
+    }
+    if let DatabaseConnection::Profile { .. } = connection {
+        return Err("A connection profile cannot reference another profile".to_string());
+    }
+    Ok(())
+}
+
+fn connection_to_json(connection: &DatabaseConnection) -> Result<String, String> {
+    serde_json::to_string(connection).map_err(|e| format!("Failed to serialize connection: {}", e))
+}
+
+/// 비밀번호를 포함한 전체 프로필 (접속 시점에 resolve_connection에서만 사용)
+pub async fn get_connection_profile_by_id(id: i32) -> Result<ConnectionProfile, sqlx::Error> {
+    let pool = get_db_pool().await;
+    get_connection_profile_by_id_with(&pool, id).await
+}
+
+pub async fn get_connection_profile_by_id_with(pool: &Pool<Sqlite>, id: i32) -> Result<ConnectionProfile, sqlx::Error> {
+    let row = sqlx::query("SELECT id, name, connection FROM ConnectionProfile WHERE id = ?")
+        .bind(id)
+        .fetch_one(pool)
+        .await?;
+    map_profile(row)
+}
+
+/// 프로필 목록 (비밀번호와 SSH 비밀 값은 빠짐)
+pub async fn get_all_connection_profiles() -> Result<Vec<ConnectionProfile>, sqlx::Error> {
+    let pool = get_db_pool().await;
+    get_all_connection_profiles_with(&pool).await
+}
+
+pub async fn get_all_connection_profiles_with(pool: &Pool<Sqlite>) -> Result<Vec<ConnectionProfile>, sqlx::Error> {
+    let rows = sqlx::query("SELECT id, name, connection FROM ConnectionProfile ORDER BY name, id")
+        .fetch_all(pool)
+        .await?;
+    rows.into_iter()
+        .map(|row| {
+            let profile = map_profile(row)?;
+            Ok(ConnectionProfile {
+                connection: profile.connection.without_secrets(),
+                ..profile
+            })
+        })
+        .collect()
+}
+
+pub async fn create_connection_profile(name: String, connection: DatabaseConnection) -> Result<i32, String> {
+    let pool = get_db_pool().await;
+    create_connection_profile_with(&pool, name, connection).await
+}
+
+pub async fn create_connection_profile_with(
+    pool: &Pool<Sqlite>,
+    name: String,
+    connection: DatabaseConnection,
+) -> Result<i32, String> {
+    validate(&name, &connection)?;
+
+    let result = sqlx::query("INSERT INTO ConnectionProfile (name, connection) VALUES (?, ?)")
+        .bind(name.trim())
+        .bind(connection_to_json(&connection)?)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("Failed to save connection profile: {}", e))?;
+
+    Ok(result.last_insert_rowid() as i32)
+}
+
+/// 프로필 수정 (비어 있는 비밀 값은 저장된 값을 유지)
+pub async fn update_connection_profile(id: i32, name: String, connection: DatabaseConnection) -> Result<(), String> {
+    let pool = get_db_pool().await;
+    update_connection_profile_with(&pool, id, name, connection).await
+}
+
+pub async fn update_connection_profile_with(
+    pool: &Pool<Sqlite>,
+    id: i32,
+    name: String,
+    connection: DatabaseConnection,
+) -> Result<(), String> {
+    validate(&name, &connection)?;
+
+    let stored = get_connection_profile_by_id_with(pool, id)
+        .await
+        .map_err(|e| format!("Failed to load connection profile {}: {}", id, e))?;
+    let connection = connection.keep_secrets_from(&stored.connection);
+
+    sqlx::query("UPDATE ConnectionProfile SET name = ?, connection = ? WHERE id = ?")
+        .bind(name.trim())
+        .bind(connection_to_json(&connection)?)
+        .bind(id)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("Failed to save connection profile: {}", e))?;
+
+    Ok(())
+}
+
+pub async fn delete_connection_profile(id: i32) -> Result<(), sqlx::Error> {
+    let pool = get_db_pool().await;
+    delete_connection_profile_with(&pool, id).await
+}
+
+pub async fn delete_connection_profile_with(pool: &Pool<Sqlite>, id: i32) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM ConnectionProfile WHERE id = ?")
+        .bind(id)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::database::migration;
+
+    fn postgres(password: Option<&str>) -> DatabaseConnection {
+        serde_json::from_value(serde_json::json!({
+            "type": "postgresql",
+            "host": "db.local",
+            "username": "app",
+            "password": password,
+            "sshTunnel": { "host": "bastion", "username": "ops", "passphrase": password }
+        }))
+        .unwrap()
+    }
+
+    fn secrets(connection: &DatabaseConnection) -> (Option<String>, Option<String>) {
+        match connection {
+            DatabaseConnection::PostgreSQL { password, ssh_tunnel, .. } => (
+                password.clone(),
+                ssh_tunnel.as_ref().and_then(|tunnel| tunnel.passphrase.clone()),
+            ),
+            other => panic!("unexpected connection {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_connection_profile_list_masks_secrets() {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let pool = migration::empty_test_pool().await;
+                let id = create_connection_profile_with(&pool, " prod ".to_string(), postgres(Some("s3cret")))
+                    .await
+                    .unwrap();
+
+                let listed = get_all_connection_profiles_with(&pool).await.unwrap();
+                assert_eq!(listed.len(), 1);
+                assert_eq!(listed[0].name, "prod");
+                assert_eq!(secrets(&listed[0].connection), (None, None));
+
+                // 접속 시점에는 저장된 비밀번호를 그대로 사용
+                let full = get_connection_profile_by_id_with(&pool, id).await.unwrap();
+                assert_eq!(secrets(&full.connection), (Some("s3cret".to_string()), Some("s3cret".to_string())));
+
+                // 목록에서 받은 (비밀 값이 빠진) 접속 정보로 수정해도 비밀번호 유지
+                update_connection_profile_with(&pool, id, "prod-2".to_string(), listed[0].connection.clone())
+                    .await
+                    .unwrap();
+                let full = get_connection_profile_by_id_with(&pool, id).await.unwrap();
+                assert_eq!(full.name, "prod-2");
+                assert_eq!(secrets(&full.connection).0.as_deref(), Some("s3cret"));
+
+                update_connection_profile_with(&pool, id, "prod-2".to_string(), postgres(Some("rotated")))
+                    .await
+                    .unwrap();
+                let full = get_connection_profile_by_id_with(&pool, id).await.unwrap();
+                assert_eq!(secrets(&full.connection).0.as_deref(), Some("rotated"));
+
+                delete_connection_profile_with(&pool, id).await.unwrap();
+                assert!(get_all_connection_profiles_with(&pool).await.unwrap().is_empty());
+            });
+    }
+
+    #[test]
+    fn test_connection_profile_validation() {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let pool = migration::empty_test_pool().await;
+
+                let err = create_connection_profile_with(&pool, "  ".to_string(), postgres(None))
+                    .await
+                    .unwrap_err();
+                assert!(err.contains("name is required"), "{}", err);
+
+                let err = create_connection_profile_with(
+                    &pool,
+                    "loop".to_string(),
+                    DatabaseConnection::Profile { profile_id: 1 },
+                )
+                .await
+                .unwrap_err();
+                assert!(err.contains("another profile"), "{}", err);
+
+                let err = update_connection_profile_with(&pool, 99, "missing".to_string(), postgres(None))
+                    .await
+                    .unwrap_err();
+                assert!(err.contains("profile 99"), "{}", err);
+                assert!(get_all_connection_profiles_with(&pool).await.unwrap().is_empty());
+            });
+    }
+}
@@ -1,4 +1,5 @@
 use super::get_db_pool;
+use super::journal::{self, JournalScope, Selection};
 use sqlx::Row;
 use serde::{Deserialize, Serialize};
 
@@ -41,16 +42,20 @@ pub async fn create_edge(
 ) -> Result<i32, sqlx::Error> {
     let pool = get_db_pool().await;
 
+    let mut tx = pool.begin().await?;
     let result = sqlx::query("INSERT INTO Edge (fk_page_id, source, target, sourceHandle, targetHandle) VALUES (?, ?, ?, ?, ?)")
         .bind(fk_page_id)
         .bind(source)
         .bind(target)
         .bind(source_handle)
         .bind(target_handle)
-        .execute(&*pool)
+        .execute(&mut *tx)
         .await?;
+    let id = result.last_insert_rowid() as i32;
+    journal::record(&mut tx, JournalScope::Page(fk_page_id), "create_edge", &Selection::Edge(id), Vec::new()).await?;
+    tx.commit().await?;
     
-    Ok(result.last_insert_rowid() as i32)
+    Ok(id)
 }
 
 pub async fn update_edge(
@@ -63,6 +68,9 @@ pub async fn update_edge(
 ) -> Result<(), sqlx::Error> {
     let pool = get_db_pool().await;
 
+    let mut tx = pool.begin().await?;
+    let selection = Selection::Edge(id);
+    let before = journal::capture(&mut tx, &selection).await?;
     sqlx::query("UPDATE Edge SET source = ?, target = ?, sourceHandle = ?, targetHandle = ? WHERE id = ? AND fk_page_id = ?")
         .bind(source)
         .bind(target)
@@ -70,8 +78,10 @@ pub async fn update_edge(
         .bind(target_handle)
         .bind(id)
         .bind(fk_page_id)
-        .execute(&*pool)
+        .execute(&mut *tx)
         .await?;
+    journal::record(&mut tx, JournalScope::Page(fk_page_id), "update_edge", &selection, before).await?;
+    tx.commit().await?;
     
     Ok(())
 }
@@ -79,11 +89,16 @@ pub async fn update_edge(
 pub async fn delete_edge(id: i32, fk_page_id: i32) -> Result<(), sqlx::Error> {
     let pool = get_db_pool().await;
 
+    let mut tx = pool.begin().await?;
+    let selection = Selection::Edge(id);
+    let before = journal::capture(&mut tx, &selection).await?;
     sqlx::query("DELETE FROM Edge WHERE id = ? AND fk_page_id = ?")
         .bind(id)
         .bind(fk_page_id)
-        .execute(&*pool)
+        .execute(&mut *tx)
         .await?;
+    journal::record(&mut tx, JournalScope::Page(fk_page_id), "delete_edge", &selection, before).await?;
+    tx.commit().await?;
     
     Ok(())
 }
@@ -91,10 +106,15 @@ pub async fn delete_edge(id: i32, fk_page_id: i32) -> Result<(), sqlx::Error> {
 pub async fn delete_edges_by_page_id(page_id: i32) -> Result<(), sqlx::Error> {
     let pool = get_db_pool().await;
 
+    let mut tx = pool.begin().await?;
+    let selection = Selection::PageGraph(page_id);
+    let before = journal::capture(&mut tx, &selection).await?;
     sqlx::query("DELETE FROM Edge WHERE fk_page_id = ?")
         .bind(page_id)
-        .execute(&*pool)
+        .execute(&mut *tx)
         .await?;
+    journal::record(&mut tx, JournalScope::Page(page_id), "delete_edges", &selection, before).await?;
+    tx.commit().await?;
     
     Ok(())
 }
@@ -0,0 +1,174 @@
+use super::bundle::{unique_title, ConflictStrategy};
+use super::get_db_pool;
+use sqlx::{Pool, Row, Sqlite};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// 실행 환경 (dev/staging/prod 등) - 노드 데이터의 {{변수}}를 치환하는 값 묶음
+///
+/// Postman 환경 가져오기로 저장하고 itol-cli --env 로 선택한다 (앱 화면에는 아직 선택기가 없음).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Environment {
+    pub id: i32,
+    pub name: String,
+    pub variables: HashMap<String, String>,
+}
+
+/// variables 컬럼(JSON 문자열)을 HashMap으로 변환
+fn map_environment(row: sqlx::sqlite::SqliteRow) -> Result<Environment, sqlx::Error> {
+    let variables: String = row.get(2);
+    Ok(Environment {
+        id: row.get(0),
+        name: row.get(1),
+        variables: serde_json::from_str(&variables).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+    })
+}
+
+fn variables_to_json(variables: &HashMap<String, String>) -> Result<String, sqlx::Error> {
+    serde_json::to_string(variables).map_err(|e| sqlx::Error::Encode(Box::new(e)))
+}
+
+pub async fn get_all_environments_with(pool: &Pool<Sqlite>) -> Result<Vec<Environment>, sqlx::Error> {
+    let rows = sqlx::query("SELECT id, name, variables FROM Environment ORDER BY name, id")
+        .fetch_all(pool)
+        .await?;
+    rows.into_iter().map(map_environment).collect()
+}
+
+/// 이름으로 환경 조회 (itol-cli --env)
+pub async fn get_environment_by_name(name: &str) -> Result<Environment, sqlx::Error> {
+    let pool = get_db_pool().await;
+    get_environment_by_name_with(&pool, name).await
+}
+
+pub async fn get_environment_by_name_with(pool: &Pool<Sqlite>, name: &str) -> Result<Environment, sqlx::Error> {
+    let row = sqlx::query("SELECT id, name, variables FROM Environment WHERE name = ?")
+        .bind(name)
+        .fetch_one(pool)
+        .await?;
+    map_environment(row)
+}
+
+/// 같은 이름의 환경이 있으면 변수를 교체하고, 없으면 새로 생성
+pub async fn save_environment(name: String, variables: HashMap<String, String>) -> Result<i32, sqlx::Error> {
+    let pool = get_db_pool().await;
+    save_environment_with(&pool, name, variables).await
+}
+
+pub async fn save_environment_with(
+    pool: &Pool<Sqlite>,
+    name: String,
+    variables: HashMap<String, String>,
+) -> Result<i32, sqlx::Error> {
+    sqlx::query("INSERT INTO Environment (name, variables) VALUES (?, ?) ON CONFLICT(name) DO UPDATE SET variables = excluded.variables")
+        .bind(&name)
+        .bind(variables_to_json(&variables)?)
+        .execute(pool)
+        .await?;
+
+    sqlx::query_scalar("SELECT id FROM Environment WHERE name = ?")
+        .bind(name)
+        .fetch_one(pool)
+        .await
+}
+
+/// 트랜잭션 안에서 환경 추가 - 같은 이름이 있으면 strategy에 따라 이름 변경/건너뜀/변수 교체
+///
+/// 저장한 환경의 (id, 이름)을 반환하고 건너뛰면 None.
+pub(crate) async fn insert_environment_tx(
+    tx: &mut sqlx::Transaction<'_, Sqlite>,
+    name: &str,
+    variables: &HashMap<String, String>,
+    strategy: ConflictStrategy,
+) -> Result<Option<(i32, String)>, sqlx::Error> {
+    let names: HashSet<String> = sqlx::query_scalar("SELECT name FROM Environment")
+        .fetch_all(&mut **tx)
+        .await?
+        .into_iter()
+        .collect();
+
+    let name = match (names.contains(name), strategy) {
+        (false, _) => name.to_string(),
+        (true, ConflictStrategy::Skip) => return Ok(None),
+        (true, ConflictStrategy::Replace) => {
+            sqlx::query("UPDATE Environment SET variables = ? WHERE name = ?")
+                .bind(variables_to_json(variables)?)
+                .bind(name)
+                .execute(&mut **tx)
+                .await?;
+            let id = sqlx::query_scalar("SELECT id FROM Environment WHERE name = ?")
+                .bind(name)
+                .fetch_one(&mut **tx)
+                .await?;
+            return Ok(Some((id, name.to_string())));
+        }
+        (true, ConflictStrategy::Rename) => unique_title(name, &names),
+    };
+
+    let id = sqlx::query("INSERT INTO Environment (name, variables) VALUES (?, ?)")
+        .bind(&name)
+        .bind(variables_to_json(variables)?)
+        .execute(&mut **tx)
+        .await?
+        .last_insert_rowid() as i32;
+    Ok(Some((id, name)))
+}
+
+pub async fn delete_environment_with(pool: &Pool<Sqlite>, id: i32) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM Environment WHERE id = ?")
+        .bind(id)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::database::migration;
+
+    fn vars(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn test_environment_save_list_resolve_and_delete() {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let pool = migration::empty_test_pool().await;
+
+                let dev = save_environment_with(&pool, "dev".to_string(), vars(&[("baseUrl", "http://localhost")]))
+                    .await
+                    .unwrap();
+                let prod = save_environment_with(&pool, "prod".to_string(), vars(&[("baseUrl", "https://api")]))
+                    .await
+                    .unwrap();
+
+                // 같은 이름으로 저장하면 id를 유지한 채 변수만 교체
+                let again = save_environment_with(&pool, "dev".to_string(), vars(&[("token", "t")]))
+                    .await
+                    .unwrap();
+                assert_eq!(again, dev);
+
+                let names: Vec<_> = get_all_environments_with(&pool)
+                    .await
+                    .unwrap()
+                    .into_iter()
+                    .map(|env| env.name)
+                    .collect();
+                assert_eq!(names, vec!["dev", "prod"]);
+
+                let resolved = get_environment_by_name_with(&pool, "dev").await.unwrap();
+                assert_eq!(resolved.variables, vars(&[("token", "t")]));
+                assert!(get_environment_by_name_with(&pool, "staging").await.is_err());
+
+                delete_environment_with(&pool, prod).await.unwrap();
+                assert!(get_environment_by_name_with(&pool, "prod").await.is_err());
+                assert_eq!(get_all_environments_with(&pool).await.unwrap().len(), 1);
+            });
+    }
+}
@@ -0,0 +1,348 @@
+use super::edge::Edge;
+use super::get_db_pool;
+use super::history;
+use super::journal::{self, JournalScope, Selection};
+use super::node::Node;
+use serde::{Deserialize, Serialize};
+use sqlx::{Pool, Row, Sqlite, SqliteConnection};
+use std::collections::{HashMap, HashSet};
+
+/// 저장할 노드 (fk_page_id는 페이지 기준으로 채움)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GraphNodeInput {
+    pub id: String,
+    pub data: String,
+    pub node_type: String,
+    pub position_x: i32,
+    pub position_y: i32,
+}
+
+/// 저장할 엣지 (id가 없으면 같은 source/target/handle의 기존 엣지와 매칭하거나 새로 생성)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GraphEdgeInput {
+    #[serde(default)]
+    pub id: Option<i32>,
+    pub source: String,
+    pub target: String,
+    #[serde(default)]
+    pub source_handle: Option<String>,
+    #[serde(default)]
+    pub target_handle: Option<String>,
+}
+
+/// 저장 후 DB에 있는 그대로의 페이지 그래프
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PageGraph {
+    pub page_id: i32,
+    pub flow_data: Option<String>,
+    pub nodes: Vec<Node>,
+    pub edges: Vec<Edge>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GraphChanges {
+    pub nodes_created: usize,
+    pub nodes_updated: usize,
+    pub nodes_deleted: usize,
+    pub edges_created: usize,
+    pub edges_updated: usize,
+    pub edges_deleted: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PageGraphSaveResult {
+    pub graph: PageGraph,
+    pub changes: GraphChanges,
+}
+
+pub(crate) async fn load_page_graph(conn: &mut SqliteConnection, page_id: i32) -> Result<PageGraph, sqlx::Error> {
+    let flow_data: Option<String> = sqlx::query_scalar("SELECT flow_data FROM Page WHERE id = ?")
+        .bind(page_id)
+        .fetch_one(&mut *conn)
+        .await?;
+    let nodes = sqlx::query("SELECT id, fk_page_id, data, type, position_x, position_y FROM Node WHERE fk_page_id = ? ORDER BY rowid")
+        .bind(page_id)
+        .map(|row: sqlx::sqlite::SqliteRow| Node {
+            id: row.get(0),
+            fk_page_id: row.get(1),
+            data: row.get(2),
+            node_type: row.get(3),
+            position_x: row.get(4),
+            position_y: row.get(5),
+        })
+        .fetch_all(&mut *conn)
+        .await?;
+    let edges = sqlx::query("SELECT id, fk_page_id, source, target, sourceHandle, targetHandle FROM Edge WHERE fk_page_id = ? ORDER BY id")
+        .bind(page_id)
+        .map(|row: sqlx::sqlite::SqliteRow| Edge {
+            id: row.get(0),
+            fk_page_id: row.get(1),
+            source: row.get(2),
+            target: row.get(3),
+            source_handle: row.get(4),
+            target_handle: row.get(5),
+        })
+        .fetch_all(&mut *conn)
+        .await?;
+    Ok(PageGraph { page_id, flow_data, nodes, edges })
+}
+
+fn same_endpoints(edge: &Edge, input: &GraphEdgeInput) -> bool {
+    edge.source == input.source
+        && edge.target == input.target
+        && edge.source_handle == input.source_handle
+        && edge.target_handle == input.target_handle
+}
+
+/// 페이지의 노드/엣지/flow_data를 입력 목록과 같아지도록 변경분만 적용 (하나의 트랜잭션)
+pub(crate) async fn apply_page_graph(
+    conn: &mut SqliteConnection,
+    page_id: i32,
+    flow_data: Option<String>,
+    nodes: &[GraphNodeInput],
+    edges: &[GraphEdgeInput],
+) -> Result<GraphChanges, String> {
+    let map_err = |e: sqlx::Error| format!("Failed to save page {}: {}", page_id, e);
+    let mut changes = GraphChanges::default();
+
+    let mut seen = HashSet::new();
+    if let Some(duplicate) = nodes.iter().find(|node| !seen.insert(node.id.as_str())) {
+        return Err(format!("Duplicate node id '{}' in page {}", duplicate.id, page_id));
+    }
+
+    let updated = sqlx::query("UPDATE Page SET flow_data = ? WHERE id = ?")
+        .bind(flow_data)
+        .bind(page_id)
+        .execute(&mut *conn)
+        .await
+        .map_err(map_err)?;
+    if updated.rows_affected() == 0 {
+        return Err(format!("Page {} not found", page_id));
+    }
+
+    let current = load_page_graph(conn, page_id).await.map_err(map_err)?;
+
+    // 노드: 없어진 것 삭제 (연결된 엣지는 CASCADE), 바뀐 것 수정, 새 것 추가
+    let existing: HashMap<&str, &Node> = current.nodes.iter().map(|node| (node.id.as_str(), node)).collect();
+    for node in &current.nodes {
+        if !seen.contains(node.id.as_str()) {
+            sqlx::query("DELETE FROM Node WHERE id = ? AND fk_page_id = ?")
+                .bind(&node.id)
+                .bind(page_id)
+                .execute(&mut *conn)
+                .await
+                .map_err(map_err)?;
+            changes.nodes_deleted += 1;
+        }
+    }
+    for node in nodes {
+        match existing.get(node.id.as_str()) {
+            Some(old)
+                if old.data == node.data
+                    && old.node_type == node.node_type
+                    && old.position_x == node.position_x
+                    && old.position_y == node.position_y => {}
+            Some(_) => {
+                sqlx::query("UPDATE Node SET data = ?, type = ?, position_x = ?, position_y = ? WHERE id = ? AND fk_page_id = ?")
+                    .bind(&node.data)
+                    .bind(&node.node_type)
+                    .bind(node.position_x)
+                    .bind(node.position_y)
+                    .bind(&node.id)
+                    .bind(page_id)
+                    .execute(&mut *conn)
+                    .await
+                    .map_err(map_err)?;
+                changes.nodes_updated += 1;
+            }
+            None => {
+                sqlx::query("INSERT INTO Node (id, fk_page_id, data, type, position_x, position_y) VALUES (?, ?, ?, ?, ?, ?)")
+                    .bind(&node.id)
+                    .bind(page_id)
+                    .bind(&node.data)
+                    .bind(&node.node_type)
+                    .bind(node.position_x)
+                    .bind(node.position_y)
+                    .execute(&mut *conn)
+                    .await
+                    .map_err(map_err)?;
+                changes.nodes_created += 1;
+            }
+        }
+    }
+
+    // 엣지: id로 먼저 매칭하고, id가 없으면 같은 연결의 기존 엣지를 재사용
+    let mut remaining: Vec<Edge> = current
+        .edges
+        .into_iter()
+        .filter(|edge| seen.contains(edge.source.as_str()) && seen.contains(edge.target.as_str()))
+        .collect();
+    let mut pending = Vec::new();
+    for input in edges {
+        let by_id = input.id.and_then(|id| remaining.iter().position(|edge| edge.id == id));
+        match by_id {
+            Some(index) => {
+                let edge = remaining.remove(index);
+                if !same_endpoints(&edge, input) {
+                    sqlx::query("UPDATE Edge SET source = ?, target = ?, sourceHandle = ?, targetHandle = ? WHERE id = ?")
+                        .bind(&input.source)
+                        .bind(&input.target)
+                        .bind(&input.source_handle)
+                        .bind(&input.target_handle)
+                        .bind(edge.id)
+                        .execute(&mut *conn)
+                        .await
+                        .map_err(map_err)?;
+                    changes.edges_updated += 1;
+                }
+            }
+            None => pending.push(input),
+        }
+    }
+    for input in pending {
+        match remaining.iter().position(|edge| same_endpoints(edge, input)) {
+            Some(index) => {
+                remaining.remove(index);
+            }
+            None => {
+                sqlx::query("INSERT INTO Edge (fk_page_id, source, target, sourceHandle, targetHandle) VALUES (?, ?, ?, ?, ?)")
+                    .bind(page_id)
+                    .bind(&input.source)
+                    .bind(&input.target)
+                    .bind(&input.source_handle)
+                    .bind(&input.target_handle)
+                    .execute(&mut *conn)
+                    .await
+                    .map_err(|e| format!("Failed to save edge {} -> {}: {}", input.source, input.target, e))?;
+                changes.edges_created += 1;
+            }
+        }
+    }
+    for edge in remaining {
+        sqlx::query("DELETE FROM Edge WHERE id = ?")
+            .bind(edge.id)
+            .execute(&mut *conn)
+            .await
+            .map_err(map_err)?;
+        changes.edges_deleted += 1;
+    }
+
+    Ok(changes)
+}
+
+pub async fn save_page_graph_with(
+    pool: &Pool<Sqlite>,
+    page_id: i32,
+    flow_data: Option<String>,
+    nodes: Vec<GraphNodeInput>,
+    edges: Vec<GraphEdgeInput>,
+) -> Result<PageGraphSaveResult, String> {
+    let map_err = |e: sqlx::Error| format!("Failed to save page {}: {}", page_id, e);
+    let mut tx = pool.begin().await.map_err(map_err)?;
+    let selection = Selection::PageGraph(page_id);
+    let before = journal::capture(&mut tx, &selection).await.map_err(map_err)?;
+    let changes = apply_page_graph(&mut tx, page_id, flow_data, &nodes, &edges).await?;
+    // 그래프 저장 전체를 하나의 되돌리기 단위로 기록
+    journal::record(&mut tx, JournalScope::Page(page_id), "save_page_graph", &selection, before)
+        .await
+        .map_err(map_err)?;
+    history::record_version(&mut tx, page_id, None, None, false)
+        .await
+        .map_err(map_err)?;
+    let graph = load_page_graph(&mut tx, page_id).await.map_err(map_err)?;
+    tx.commit().await.map_err(map_err)?;
+    Ok(PageGraphSaveResult { graph, changes })
+}
+
+pub async fn save_page_graph(
+    page_id: i32,
+    flow_data: Option<String>,
+    nodes: Vec<GraphNodeInput>,
+    edges: Vec<GraphEdgeInput>,
+) -> Result<PageGraphSaveResult, String> {
+    let pool = get_db_pool().await;
+    save_page_graph_with(&pool, page_id, flow_data, nodes, edges).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::database::migration;
+
+    fn node(id: &str, x: i32) -> GraphNodeInput {
+        GraphNodeInput {
+            id: id.to_string(),
+            data: "{}".to_string(),
+            node_type: "apiNode".to_string(),
+            position_x: x,
+            position_y: 0,
+        }
+    }
+
+    fn edge(id: Option<i32>, source: &str, target: &str) -> GraphEdgeInput {
+        GraphEdgeInput {
+            id,
+            source: source.to_string(),
+            target: target.to_string(),
+            source_handle: None,
+            target_handle: None,
+        }
+    }
+
+    #[test]
+    fn test_save_page_graph_diff_and_rollback() {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let pool = migration::test_pool().await;
+                let saved = save_page_graph_with(
+                    &pool,
+                    10,
+                    Some("{\"v\":1}".to_string()),
+                    vec![node("a", 0), node("b", 100), node("c", 200)],
+                    vec![edge(None, "a", "b"), edge(None, "b", "c")],
+                )
+                .await
+                .unwrap();
+                assert_eq!(saved.changes.nodes_created, 3);
+                assert_eq!(saved.changes.edges_created, 2);
+                let ab = saved.graph.edges[0].id;
+
+                // b 이동, c 삭제(b→c 엣지도 삭제), a→b 유지, 새 엣지 추가
+                let saved = save_page_graph_with(
+                    &pool,
+                    10,
+                    Some("{\"v\":2}".to_string()),
+                    vec![node("a", 0), node("b", 150), node("d", 300)],
+                    vec![edge(Some(ab), "a", "b"), edge(None, "b", "d")],
+                )
+                .await
+                .unwrap();
+                assert_eq!(saved.changes.nodes_created, 1);
+                assert_eq!(saved.changes.nodes_updated, 1);
+                assert_eq!(saved.changes.nodes_deleted, 1);
+                assert_eq!(saved.changes.edges_created, 1);
+                assert_eq!(saved.changes.edges_deleted, 0);
+                assert_eq!(saved.graph.edges.len(), 2);
+                assert_eq!(saved.graph.edges[0].id, ab);
+                assert_eq!(saved.graph.flow_data.as_deref(), Some("{\"v\":2}"));
+
+                // 존재하지 않는 노드를 가리키는 엣지 → 전체 롤백
+                let failed = save_page_graph_with(
+                    &pool,
+                    10,
+                    None,
+                    vec![node("a", 0)],
+                    vec![edge(None, "a", "missing")],
+                )
+                .await;
+                assert!(failed.is_err());
+                let mut conn = pool.acquire().await.unwrap();
+                let graph = load_page_graph(&mut conn, 10).await.unwrap();
+                assert_eq!(graph.nodes.len(), 3);
+                assert_eq!(graph.flow_data.as_deref(), Some("{\"v\":2}"));
+            });
+    }
+}
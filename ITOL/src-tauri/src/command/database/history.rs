@@ -0,0 +1,538 @@
+use super::get_db_pool;
+use super::graph::{self, GraphChanges, GraphEdgeInput, GraphNodeInput, PageGraphSaveResult};
+use super::journal::{self, JournalScope, Selection};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::{Pool, Row, Sqlite, SqliteConnection};
+use std::collections::{BTreeSet, HashMap};
+
+/// 페이지별로 보관하는 최대 버전 수
+const MAX_VERSIONS_PER_PAGE: i64 = 100;
+/// 마지막 자동 저장 버전이 이보다 최근이면 새 버전을 만들지 않고 그 버전에 합침
+const AUTOSAVE_MERGE_MINUTES: i64 = 10;
+
+/// 한 시점의 페이지 그래프 (저장 후 변경되지 않음)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PageSnapshot {
+    pub title: String,
+    pub flow_data: Option<String>,
+    pub nodes: Vec<GraphNodeInput>,
+    pub edges: Vec<GraphEdgeInput>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PageVersionInfo {
+    pub id: i32,
+    pub page_id: i32,
+    pub version: i64,
+    pub author: Option<String>,
+    pub message: Option<String>,
+    pub created_at: String,
+    pub node_count: i64,
+    pub edge_count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PageVersion {
+    pub info: PageVersionInfo,
+    pub snapshot: PageSnapshot,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeChange {
+    pub id: String,
+    /// 바뀐 항목: "type", "position", "data.<key>"
+    pub fields: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct EdgeRef {
+    pub source: String,
+    pub target: String,
+    pub source_handle: Option<String>,
+    pub target_handle: Option<String>,
+}
+
+/// 두 버전 사이의 구조적 차이
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PageDiff {
+    pub title_changed: bool,
+    pub flow_data_changed: bool,
+    pub nodes_added: Vec<String>,
+    pub nodes_removed: Vec<String>,
+    pub nodes_changed: Vec<NodeChange>,
+    pub edges_added: Vec<EdgeRef>,
+    pub edges_removed: Vec<EdgeRef>,
+}
+
+impl PageDiff {
+    pub fn is_empty(&self) -> bool {
+        !self.title_changed
+            && !self.flow_data_changed
+            && self.nodes_added.is_empty()
+            && self.nodes_removed.is_empty()
+            && self.nodes_changed.is_empty()
+            && self.edges_added.is_empty()
+            && self.edges_removed.is_empty()
+    }
+}
+
+/// 현재 OS 사용자 이름 (작성자를 지정하지 않은 경우)
+pub fn default_author() -> Option<String> {
+    std::env::var("USER")
+        .or_else(|_| std::env::var("USERNAME"))
+        .ok()
+        .filter(|name| !name.is_empty())
+}
+
+pub(crate) async fn current_snapshot(conn: &mut SqliteConnection, page_id: i32) -> Result<PageSnapshot, sqlx::Error> {
+    let title: String = sqlx::query_scalar("SELECT title FROM Page WHERE id = ?")
+        .bind(page_id)
+        .fetch_one(&mut *conn)
+        .await?;
+    let graph = graph::load_page_graph(conn, page_id).await?;
+    Ok(PageSnapshot {
+        title,
+        flow_data: graph.flow_data,
+        nodes: graph
+            .nodes
+            .into_iter()
+            .map(|node| GraphNodeInput {
+                id: node.id,
+                data: node.data,
+                node_type: node.node_type,
+                position_x: node.position_x,
+                position_y: node.position_y,
+            })
+            .collect(),
+        edges: graph
+            .edges
+            .into_iter()
+            .map(|edge| GraphEdgeInput {
+                id: Some(edge.id),
+                source: edge.source,
+                target: edge.target,
+                source_handle: edge.source_handle,
+                target_handle: edge.target_handle,
+            })
+            .collect(),
+    })
+}
+
+const VERSION_COLUMNS: &str = "id, fk_page_id, version, author, message, created_at,
+    json_array_length(snapshot, '$.nodes'), json_array_length(snapshot, '$.edges')";
+
+fn map_info(row: &sqlx::sqlite::SqliteRow) -> PageVersionInfo {
+    PageVersionInfo {
+        id: row.get(0),
+        page_id: row.get(1),
+        version: row.get(2),
+        author: row.get(3),
+        message: row.get(4),
+        created_at: row.get(5),
+        node_count: row.get(6),
+        edge_count: row.get(7),
+    }
+}
+
+async fn load_version(conn: &mut SqliteConnection, page_id: i32, version: i64) -> Result<PageVersion, sqlx::Error> {
+    let row = sqlx::query(&format!(
+        "SELECT {}, snapshot FROM PageVersion WHERE fk_page_id = ? AND version = ?",
+        VERSION_COLUMNS
+    ))
+    .bind(page_id)
+    .bind(version)
+    .fetch_one(&mut *conn)
+    .await?;
+    let snapshot: String = row.get(8);
+    Ok(PageVersion {
+        info: map_info(&row),
+        snapshot: serde_json::from_str(&snapshot).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+    })
+}
+
+/// 현재 페이지 상태를 새 버전으로 저장 (페이지를 저장할 때마다 호출)
+/// force가 false이면 자동 저장 - 마지막 버전과 같으면 저장하지 않고,
+/// 마지막 버전이 최근 자동 저장이면 새 버전 대신 그 버전을 현재 상태로 갱신
+/// 보관 개수를 넘는 오래된 버전은 같은 트랜잭션에서 삭제
+pub(crate) async fn record_version(
+    conn: &mut SqliteConnection,
+    page_id: i32,
+    author: Option<String>,
+    message: Option<String>,
+    force: bool,
+) -> Result<Option<PageVersionInfo>, sqlx::Error> {
+    let snapshot = current_snapshot(conn, page_id).await?;
+    let latest: Option<(i64, i64, String, String, bool)> = sqlx::query_as(
+        "SELECT id, version, snapshot, created_at, auto_saved FROM PageVersion WHERE fk_page_id = ? ORDER BY version DESC LIMIT 1",
+    )
+    .bind(page_id)
+    .fetch_optional(&mut *conn)
+    .await?;
+
+    let now = chrono::Utc::now();
+    let mut merge_into = None;
+    if !force {
+        if let Some((id, _, previous, created_at, auto_saved)) = &latest {
+            if serde_json::from_str::<PageSnapshot>(previous).is_ok_and(|previous| previous == snapshot) {
+                return Ok(None);
+            }
+            let recent = chrono::DateTime::parse_from_rfc3339(created_at)
+                .is_ok_and(|created_at| now.signed_duration_since(created_at) < chrono::Duration::minutes(AUTOSAVE_MERGE_MINUTES));
+            if *auto_saved && recent {
+                merge_into = Some(*id);
+            }
+        }
+    }
+
+    let snapshot = serde_json::to_string(&snapshot).map_err(|e| sqlx::Error::Encode(Box::new(e)))?;
+    let author = author.or_else(default_author);
+    // created_at은 합친 첫 자동 저장 시각을 유지해야 간격마다 새 버전이 생김
+    let id = match merge_into {
+        Some(id) => {
+            sqlx::query("UPDATE PageVersion SET author = ?, snapshot = ? WHERE id = ?")
+                .bind(author)
+                .bind(snapshot)
+                .bind(id)
+                .execute(&mut *conn)
+                .await?;
+            id
+        }
+        None => {
+            let version = latest.map(|(_, version, ..)| version).unwrap_or(0) + 1;
+            sqlx::query(
+                "INSERT INTO PageVersion (fk_page_id, version, author, message, created_at, snapshot, auto_saved)
+                 VALUES (?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(page_id)
+            .bind(version)
+            .bind(author)
+            .bind(message)
+            .bind(now.to_rfc3339())
+            .bind(snapshot)
+            .bind(!force)
+            .execute(&mut *conn)
+            .await?
+            .last_insert_rowid()
+        }
+    };
+    sqlx::query(
+        "DELETE FROM PageVersion WHERE fk_page_id = ? AND id NOT IN (
+            SELECT id FROM PageVersion WHERE fk_page_id = ? ORDER BY version DESC LIMIT ?
+        )",
+    )
+    .bind(page_id)
+    .bind(page_id)
+    .bind(MAX_VERSIONS_PER_PAGE)
+    .execute(&mut *conn)
+    .await?;
+
+    let row = sqlx::query(&format!("SELECT {} FROM PageVersion WHERE id = ?", VERSION_COLUMNS))
+        .bind(id)
+        .fetch_one(&mut *conn)
+        .await?;
+    Ok(Some(map_info(&row)))
+}
+
+fn changed_fields(old: &GraphNodeInput, new: &GraphNodeInput) -> Vec<String> {
+    let mut fields = Vec::new();
+    if old.node_type != new.node_type {
+        fields.push("type".to_string());
+    }
+    if (old.position_x, old.position_y) != (new.position_x, new.position_y) {
+        fields.push("position".to_string());
+    }
+    if old.data != new.data {
+        // JSON 객체이면 바뀐 최상위 키까지 표시
+        match (
+            serde_json::from_str::<Value>(&old.data),
+            serde_json::from_str::<Value>(&new.data),
+        ) {
+            (Ok(Value::Object(old)), Ok(Value::Object(new))) => {
+                let keys: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+                fields.extend(
+                    keys.into_iter()
+                        .filter(|key| old.get(*key) != new.get(*key))
+                        .map(|key| format!("data.{}", key)),
+                );
+            }
+            _ => fields.push("data".to_string()),
+        }
+    }
+    fields
+}
+
+fn edge_refs(snapshot: &PageSnapshot) -> BTreeSet<EdgeRef> {
+    snapshot
+        .edges
+        .iter()
+        .map(|edge| EdgeRef {
+            source: edge.source.clone(),
+            target: edge.target.clone(),
+            source_handle: edge.source_handle.clone(),
+            target_handle: edge.target_handle.clone(),
+        })
+        .collect()
+}
+
+pub fn diff_snapshots(from: &PageSnapshot, to: &PageSnapshot) -> PageDiff {
+    let old: HashMap<&str, &GraphNodeInput> = from.nodes.iter().map(|node| (node.id.as_str(), node)).collect();
+    let new: HashMap<&str, &GraphNodeInput> = to.nodes.iter().map(|node| (node.id.as_str(), node)).collect();
+
+    let mut diff = PageDiff {
+        title_changed: from.title != to.title,
+        flow_data_changed: from.flow_data != to.flow_data,
+        ..Default::default()
+    };
+    for node in &to.nodes {
+        match old.get(node.id.as_str()) {
+            None => diff.nodes_added.push(node.id.clone()),
+            Some(previous) => {
+                let fields = changed_fields(previous, node);
+                if !fields.is_empty() {
+                    diff.nodes_changed.push(NodeChange { id: node.id.clone(), fields });
+                }
+            }
+        }
+    }
+    diff.nodes_removed = from
+        .nodes
+        .iter()
+        .filter(|node| !new.contains_key(node.id.as_str()))
+        .map(|node| node.id.clone())
+        .collect();
+
+    let (old_edges, new_edges) = (edge_refs(from), edge_refs(to));
+    diff.edges_added = new_edges.difference(&old_edges).cloned().collect();
+    diff.edges_removed = old_edges.difference(&new_edges).cloned().collect();
+    diff
+}
+
+pub async fn list_versions(page_id: i32) -> Result<Vec<PageVersionInfo>, sqlx::Error> {
+    let pool = get_db_pool().await;
+
+    let rows = sqlx::query(&format!(
+        "SELECT {} FROM PageVersion WHERE fk_page_id = ? ORDER BY version DESC",
+        VERSION_COLUMNS
+    ))
+    .bind(page_id)
+    .fetch_all(&*pool)
+    .await?;
+    Ok(rows.iter().map(map_info).collect())
+}
+
+pub async fn get_version(page_id: i32, version: i64) -> Result<PageVersion, sqlx::Error> {
+    let pool = get_db_pool().await;
+    let mut conn = pool.acquire().await?;
+    load_version(&mut conn, page_id, version).await
+}
+
+/// 변경이 없어도 항상 새 버전을 만드는 명시적 체크포인트
+pub async fn create_checkpoint(
+    page_id: i32,
+    author: Option<String>,
+    message: Option<String>,
+) -> Result<PageVersionInfo, sqlx::Error> {
+    let pool = get_db_pool().await;
+    create_checkpoint_with(&pool, page_id, author, message).await
+}
+
+pub async fn create_checkpoint_with(
+    pool: &Pool<Sqlite>,
+    page_id: i32,
+    author: Option<String>,
+    message: Option<String>,
+) -> Result<PageVersionInfo, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    let info = record_version(&mut tx, page_id, author, message, true)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)?;
+    tx.commit().await?;
+    Ok(info)
+}
+
+/// to가 None이면 현재 페이지 상태와 비교
+pub async fn diff_versions_with(
+    pool: &Pool<Sqlite>,
+    page_id: i32,
+    from: i64,
+    to: Option<i64>,
+) -> Result<PageDiff, sqlx::Error> {
+    let mut conn = pool.acquire().await?;
+    let from = load_version(&mut conn, page_id, from).await?.snapshot;
+    let to = match to {
+        Some(version) => load_version(&mut conn, page_id, version).await?.snapshot,
+        None => current_snapshot(&mut conn, page_id).await?,
+    };
+    Ok(diff_snapshots(&from, &to))
+}
+
+pub async fn diff_versions(page_id: i32, from: i64, to: Option<i64>) -> Result<PageDiff, sqlx::Error> {
+    let pool = get_db_pool().await;
+    diff_versions_with(&pool, page_id, from, to).await
+}
+
+/// 페이지를 지정한 버전 상태로 되돌리고, 되돌린 상태를 새 버전으로 기록
+pub async fn restore_version_with(
+    pool: &Pool<Sqlite>,
+    page_id: i32,
+    version: i64,
+    author: Option<String>,
+) -> Result<PageGraphSaveResult, String> {
+    let map_err = |e: sqlx::Error| format!("Failed to restore version {} of page {}: {}", version, page_id, e);
+    let mut tx = pool.begin().await.map_err(map_err)?;
+    let snapshot = load_version(&mut tx, page_id, version).await.map_err(map_err)?.snapshot;
+    let selection = Selection::PageGraph(page_id);
+    let before = journal::capture(&mut tx, &selection).await.map_err(map_err)?;
+
+    sqlx::query("UPDATE Page SET title = ? WHERE id = ?")
+        .bind(&snapshot.title)
+        .bind(page_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(map_err)?;
+    let changes: GraphChanges =
+        graph::apply_page_graph(&mut tx, page_id, snapshot.flow_data, &snapshot.nodes, &snapshot.edges).await?;
+    journal::record(&mut tx, JournalScope::Page(page_id), "restore_page_version", &selection, before)
+        .await
+        .map_err(map_err)?;
+    record_version(&mut tx, page_id, author, Some(format!("Restored version {}", version)), true)
+        .await
+        .map_err(map_err)?;
+
+    let graph = graph::load_page_graph(&mut tx, page_id).await.map_err(map_err)?;
+    tx.commit().await.map_err(map_err)?;
+    Ok(PageGraphSaveResult { graph, changes })
+}
+
+pub async fn restore_version(page_id: i32, version: i64, author: Option<String>) -> Result<PageGraphSaveResult, String> {
+    let pool = get_db_pool().await;
+    restore_version_with(&pool, page_id, version, author).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::database::migration;
+
+    fn node(id: &str, data: &str) -> GraphNodeInput {
+        GraphNodeInput {
+            id: id.to_string(),
+            data: data.to_string(),
+            node_type: "apiNode".to_string(),
+            position_x: 0,
+            position_y: 0,
+        }
+    }
+
+    fn edge(source: &str, target: &str) -> GraphEdgeInput {
+        GraphEdgeInput {
+            id: None,
+            source: source.to_string(),
+            target: target.to_string(),
+            source_handle: None,
+            target_handle: None,
+        }
+    }
+
+    #[test]
+    fn test_versions_diff_and_restore() {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let pool = migration::test_pool().await;
+                let v1 = vec![node("a", r#"{"url":"/a","method":"GET"}"#), node("b", "{}")];
+                graph::save_page_graph_with(&pool, 10, None, v1, vec![edge("a", "b")]).await.unwrap();
+                // 같은 내용으로 다시 저장하면 버전이 늘지 않음
+                let v1 = vec![node("a", r#"{"url":"/a","method":"GET"}"#), node("b", "{}")];
+                graph::save_page_graph_with(&pool, 10, None, v1, vec![edge("a", "b")]).await.unwrap();
+                // 자동 저장 합치기 간격이 지난 것으로 처리
+                sqlx::query("UPDATE PageVersion SET created_at = '2000-01-01T00:00:00+00:00'")
+                    .execute(&pool)
+                    .await
+                    .unwrap();
+                let v2 = vec![node("a", r#"{"url":"/a2","method":"GET"}"#), node("c", "{}")];
+                graph::save_page_graph_with(&pool, 10, None, v2, vec![edge("a", "c")]).await.unwrap();
+
+                let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM PageVersion").fetch_one(&pool).await.unwrap();
+                assert_eq!(count, 2);
+
+                let diff = diff_versions_with(&pool, 10, 1, Some(2)).await.unwrap();
+                assert_eq!(diff.nodes_added, ["c"]);
+                assert_eq!(diff.nodes_removed, ["b"]);
+                assert_eq!(diff.nodes_changed[0].fields, ["data.url"]);
+                assert_eq!(diff.edges_added[0].target, "c");
+                assert_eq!(diff.edges_removed[0].target, "b");
+
+                let restored = restore_version_with(&pool, 10, 1, Some("tester".to_string())).await.unwrap();
+                assert_eq!(restored.graph.nodes.len(), 2);
+                assert_eq!(restored.graph.edges[0].target, "b");
+                assert!(diff_versions_with(&pool, 10, 1, None).await.unwrap().is_empty());
+
+                let mut conn = pool.acquire().await.unwrap();
+                let latest = load_version(&mut conn, 10, 3).await.unwrap();
+                assert_eq!(latest.info.message.as_deref(), Some("Restored version 1"));
+                assert_eq!(latest.info.author.as_deref(), Some("tester"));
+                assert_eq!(latest.info.node_count, 2);
+            });
+    }
+
+    #[test]
+    fn test_autosaves_merge_into_recent_autosave_version() {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let pool = migration::test_pool().await;
+                let versions = |pool: Pool<Sqlite>| async move {
+                    sqlx::query_as::<_, (i64, bool)>("SELECT version, auto_saved FROM PageVersion WHERE fk_page_id = 10 ORDER BY version")
+                        .fetch_all(&pool)
+                        .await
+                        .unwrap()
+                };
+
+                // 노드를 여러 번 옮겨도 자동 저장 버전은 하나
+                for x in 0..5 {
+                    let mut moved = node("a", "{}");
+                    moved.position_x = x * 10;
+                    graph::save_page_graph_with(&pool, 10, None, vec![moved], Vec::new()).await.unwrap();
+                }
+                assert_eq!(versions(pool.clone()).await, vec![(1, true)]);
+                let mut conn = pool.acquire().await.unwrap();
+                assert_eq!(load_version(&mut conn, 10, 1).await.unwrap().snapshot.nodes[0].position_x, 40);
+                drop(conn);
+
+                // 체크포인트에는 합치지 않음
+                create_checkpoint_with(&pool, 10, None, Some("before refactor".to_string())).await.unwrap();
+                graph::save_page_graph_with(&pool, 10, None, vec![node("b", "{}")], Vec::new()).await.unwrap();
+                assert_eq!(versions(pool.clone()).await, vec![(1, true), (2, false), (3, true)]);
+            });
+    }
+
+    #[test]
+    fn test_record_version_keeps_latest_versions() {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let pool = migration::test_pool().await;
+                let mut tx = pool.begin().await.unwrap();
+                for _ in 0..MAX_VERSIONS_PER_PAGE + 5 {
+                    record_version(&mut tx, 10, None, None, true).await.unwrap();
+                }
+                tx.commit().await.unwrap();
+
+                let (count, oldest): (i64, i64) =
+                    sqlx::query_as("SELECT COUNT(*), MIN(version) FROM PageVersion WHERE fk_page_id = 10")
+                        .fetch_one(&pool)
+                        .await
+                        .unwrap();
+                assert_eq!(count, MAX_VERSIONS_PER_PAGE);
+                assert_eq!(oldest, 6);
+            });
+    }
+}
@@ -0,0 +1,196 @@
+use super::get_db_pool;
+use serde::{Deserialize, Serialize};
+use sqlx::{Pool, Row, Sqlite};
+
+/// 고아 행 검사 항목 (table에서 condition에 해당하는 행을 삭제)
+struct OrphanCheck {
+    label: &'static str,
+    table: &'static str,
+    condition: &'static str,
+}
+
+/// 부모가 없는 북과 그 하위 북 전체
+macro_rules! orphan_book_ids {
+    () => {
+        "WITH RECURSIVE orphan(id) AS (
+            SELECT id FROM Book WHERE parent_id IS NOT NULL AND parent_id NOT IN (SELECT id FROM Book)
+            UNION
+            SELECT b.id FROM Book b JOIN orphan o ON b.parent_id = o.id
+        )
+        SELECT id FROM orphan"
+    };
+}
+
+/// 북이 없거나 고아 북에 속한 페이지
+macro_rules! orphan_page_ids {
+    () => {
+        concat!(
+            "SELECT id FROM Page WHERE fk_book_id NOT IN (SELECT id FROM Book) OR fk_book_id IN (",
+            orphan_book_ids!(),
+            ")"
+        )
+    };
+}
+
+/// 각 조건은 상위 고아 행에 딸린 행까지 포함한다.
+/// 삭제 시 CASCADE로 함께 지워진 행은 rows_affected에 잡히지 않으므로 개수는 삭제 전에 센다.
+const ORPHAN_CHECKS: [OrphanCheck; 4] = [
+    OrphanCheck {
+        label: "books",
+        table: "Book",
+        condition: concat!("id IN (", orphan_book_ids!(), ")"),
+    },
+    OrphanCheck {
+        label: "pages",
+        table: "Page",
+        condition: concat!("id IN (", orphan_page_ids!(), ")"),
+    },
+    OrphanCheck {
+        label: "nodes",
+        table: "Node",
+        condition: concat!("fk_page_id NOT IN (SELECT id FROM Page) OR fk_page_id IN (", orphan_page_ids!(), ")"),
+    },
+    OrphanCheck {
+        label: "edges",
+        table: "Edge",
+        condition: concat!(
+            "NOT EXISTS (SELECT 1 FROM Node n WHERE n.fk_page_id = Edge.fk_page_id AND n.id = Edge.source)
+            OR NOT EXISTS (SELECT 1 FROM Node n WHERE n.fk_page_id = Edge.fk_page_id AND n.id = Edge.target)
+            OR fk_page_id IN (",
+            orphan_page_ids!(),
+            ")"
+        ),
+    },
+];
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IntegrityReport {
+    /// PRAGMA integrity_check 결과가 "ok"인지
+    pub integrity_ok: bool,
+    /// integrity_check가 "ok"가 아닐 때의 메시지
+    pub messages: Vec<String>,
+    pub orphan_books: u64,
+    pub orphan_pages: u64,
+    pub orphan_nodes: u64,
+    pub orphan_edges: u64,
+    /// 정리 후에도 남아 있는 외래 키 위반 수 (PRAGMA foreign_key_check)
+    pub foreign_key_violations: u64,
+    pub repaired: bool,
+}
+
+impl IntegrityReport {
+    pub fn orphans(&self) -> u64 {
+        self.orphan_books + self.orphan_pages + self.orphan_nodes + self.orphan_edges
+    }
+}
+
+/// 고아 행을 찾고 repair가 true이면 삭제 (false이면 트랜잭션을 롤백해 개수만 보고)
+pub async fn check_integrity_with(pool: &Pool<Sqlite>, repair: bool) -> Result<IntegrityReport, sqlx::Error> {
+    let mut report = IntegrityReport::default();
+
+    let messages: Vec<String> = sqlx::query("PRAGMA integrity_check")
+        .fetch_all(pool)
+        .await?
+        .iter()
+        .map(|row| row.get::<String, _>(0))
+        .collect();
+    report.integrity_ok = messages.len() == 1 && messages[0] == "ok";
+    if !report.integrity_ok {
+        report.messages = messages;
+    }
+
+    let mut tx = pool.begin().await?;
+    for check in &ORPHAN_CHECKS {
+        let count: i64 = sqlx::query_scalar(&format!("SELECT COUNT(*) FROM {} WHERE {}", check.table, check.condition))
+            .fetch_one(&mut *tx)
+            .await?;
+        let count = count as u64;
+        match check.label {
+            "books" => report.orphan_books = count,
+            "pages" => report.orphan_pages = count,
+            "nodes" => report.orphan_nodes = count,
+            _ => report.orphan_edges = count,
+        }
+    }
+    // 자식 → 부모 순서로 삭제
+    for check in ORPHAN_CHECKS.iter().rev() {
+        sqlx::query(&format!("DELETE FROM {} WHERE {}", check.table, check.condition))
+            .execute(&mut *tx)
+            .await?;
+    }
+    report.foreign_key_violations = sqlx::query("PRAGMA foreign_key_check")
+        .fetch_all(&mut *tx)
+        .await?
+        .len() as u64;
+
+    if repair {
+        tx.commit().await?;
+        report.repaired = true;
+    } else {
+        tx.rollback().await?;
+    }
+    Ok(report)
+}
+
+pub async fn check_integrity(repair: bool) -> Result<IntegrityReport, sqlx::Error> {
+    let pool = get_db_pool().await;
+    check_integrity_with(&pool, repair).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::database::migration;
+
+    #[test]
+    fn test_cascades_and_orphan_repair() {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let pool = migration::test_pool().await;
+                sqlx::query("INSERT INTO Node VALUES ('a', 10, '{}', 'apiNode', 0, 0), ('b', 10, '{}', 'apiNode', 0, 0)")
+                    .execute(&pool)
+                    .await
+                    .unwrap();
+                sqlx::query("INSERT INTO Edge (fk_page_id, source, target) VALUES (10, 'a', 'b')")
+                    .execute(&pool)
+                    .await
+                    .unwrap();
+                // 존재하지 않는 노드를 가리키는 엣지는 거부
+                assert!(sqlx::query("INSERT INTO Edge (fk_page_id, source, target) VALUES (10, 'a', 'missing')")
+                    .execute(&pool)
+                    .await
+                    .is_err());
+
+                // 노드 삭제 → 엣지 삭제
+                sqlx::query("DELETE FROM Node WHERE id = 'b'").execute(&pool).await.unwrap();
+                let edges: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM Edge").fetch_one(&pool).await.unwrap();
+                assert_eq!(edges, 0);
+
+                // 외래 키 검사 없이 만들어진 고아 행 정리
+                sqlx::query("PRAGMA foreign_keys = OFF").execute(&pool).await.unwrap();
+                sqlx::query("INSERT INTO Page (id, fk_book_id, title) VALUES (20, 99, 'orphan')").execute(&pool).await.unwrap();
+                sqlx::query("INSERT INTO Node VALUES ('x', 20, '{}', 'apiNode', 0, 0)").execute(&pool).await.unwrap();
+                sqlx::query("PRAGMA foreign_keys = ON").execute(&pool).await.unwrap();
+
+                let report = check_integrity_with(&pool, false).await.unwrap();
+                assert!(report.integrity_ok);
+                assert_eq!(report.orphan_pages, 1);
+                // 고아 페이지의 노드도 함께 집계
+                assert_eq!(report.orphan_nodes, 1);
+                assert!(!report.repaired);
+                let report = check_integrity_with(&pool, true).await.unwrap();
+                assert!(report.repaired);
+                assert_eq!((report.orphan_pages, report.orphan_nodes), (1, 1));
+                assert_eq!(report.foreign_key_violations, 0);
+                assert_eq!(check_integrity_with(&pool, false).await.unwrap().orphans(), 0);
+
+                // 북 삭제 → 페이지/노드 삭제
+                sqlx::query("DELETE FROM Book WHERE id = 1").execute(&pool).await.unwrap();
+                let nodes: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM Node").fetch_one(&pool).await.unwrap();
+                assert_eq!(nodes, 0);
+            });
+    }
+}
@@ -0,0 +1,652 @@
+use super::book::BOOK_TREE;
+use super::get_db_pool;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::{Pool, Row, Sqlite, SqliteConnection};
+
+/// 범위별로 보관하는 최대 작업 수
+const MAX_ENTRIES_PER_SCOPE: i64 = 100;
+
+/// 저널에 기록되는 테이블 (부모 → 자식 순서)
+pub(crate) struct TableSpec {
+    name: &'static str,
+    columns: &'static [&'static str],
+    key: &'static [&'static str],
+}
+
+const BOOK: TableSpec = TableSpec {
+    name: "Book",
+    columns: &["id", "title", "parent_id", "deleted_at"],
+    key: &["id"],
+};
+const PAGE: TableSpec = TableSpec {
+    name: "Page",
+    columns: &["id", "fk_book_id", "title", "flow_data", "display_order", "deleted_at"],
+    key: &["id"],
+};
+const NODE: TableSpec = TableSpec {
+    name: "Node",
+    columns: &["id", "fk_page_id", "data", "type", "position_x", "position_y"],
+    key: &["fk_page_id", "id"],
+};
+const EDGE: TableSpec = TableSpec {
+    name: "Edge",
+    columns: &["id", "fk_page_id", "source", "target", "sourceHandle", "targetHandle"],
+    key: &["id"],
+};
+const PAGE_VERSION: TableSpec = TableSpec {
+    name: "PageVersion",
+    columns: &["id", "fk_page_id", "version", "author", "message", "created_at", "snapshot", "auto_saved"],
+    key: &["id"],
+};
+const TABLES: [&TableSpec; 5] = [&BOOK, &PAGE, &NODE, &EDGE, &PAGE_VERSION];
+
+fn table_spec(name: &str) -> Option<&'static TableSpec> {
+    TABLES.iter().copied().find(|table| table.name == name)
+}
+
+/// 되돌리기 범위 - 노드/엣지/페이지 작업은 페이지, 북 작업과 페이지 순서 변경은 북
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "id", rename_all = "lowercase")]
+pub enum JournalScope {
+    Page(i32),
+    Book(i32),
+}
+
+impl JournalScope {
+    pub(crate) fn key(&self) -> String {
+        match self {
+            JournalScope::Page(id) => format!("page:{}", id),
+            JournalScope::Book(id) => format!("book:{}", id),
+        }
+    }
+}
+
+/// 작업 전후로 캡처할 행 범위
+pub(crate) enum Selection {
+    /// 북 한 행
+    Book(i32),
+    /// 북과 하위 북/페이지/노드/엣지/버전 전체 (삭제용)
+    BookTree(i32),
+    /// 페이지 한 행
+    PageRow(i32),
+    /// 북의 모든 페이지 행 (순서 변경용)
+    BookPages(i32),
+    /// 페이지와 노드/엣지
+    PageGraph(i32),
+    /// 페이지와 노드/엣지/버전 전체 (삭제용)
+    Page(i32),
+    /// 노드와 연결된 엣지
+    Node(i32, String),
+    Edge(i32),
+}
+
+enum Bind {
+    Int(i32),
+    Text(String),
+}
+
+/// 한 행의 전체 값 (컬럼명 → 값)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct RowImage {
+    table: String,
+    row: Value,
+}
+
+impl RowImage {
+    fn same_row(&self, other: &RowImage) -> bool {
+        self.table == other.table
+            && table_spec(&self.table)
+                .is_some_and(|spec| spec.key.iter().all(|key| self.row.get(key) == other.row.get(key)))
+    }
+}
+
+/// 한 행의 변경 (before가 없으면 생성, after가 없으면 삭제)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RowChange {
+    table: String,
+    before: Option<Value>,
+    after: Option<Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournalEntry {
+    pub id: i64,
+    pub scope: JournalScope,
+    pub label: String,
+    pub created_at: String,
+    pub changes: i64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UndoState {
+    pub undo: Option<JournalEntry>,
+    pub redo: Option<JournalEntry>,
+}
+
+impl Selection {
+    /// (테이블, SELECT 앞 CTE, FROM 뒤 조건, 바인딩)
+    fn queries(&self) -> Vec<(&'static TableSpec, &'static str, &'static str, Vec<Bind>)> {
+        match self {
+            Selection::Book(id) => vec![(&BOOK, "", "WHERE Book.id = ?", vec![Bind::Int(*id)])],
+            Selection::BookTree(id) => {
+                let pages = "WHERE fk_page_id IN (SELECT Page.id FROM Page JOIN tree ON Page.fk_book_id = tree.id)";
+                vec![
+                    (&BOOK, BOOK_TREE, "JOIN tree ON Book.id = tree.id ORDER BY tree.depth", vec![Bind::Int(*id)]),
+                    (&PAGE, BOOK_TREE, "WHERE fk_book_id IN (SELECT id FROM tree)", vec![Bind::Int(*id)]),
+                    (&NODE, BOOK_TREE, pages, vec![Bind::Int(*id)]),
+                    (&EDGE, BOOK_TREE, pages, vec![Bind::Int(*id)]),
+                    (&PAGE_VERSION, BOOK_TREE, pages, vec![Bind::Int(*id)]),
+                ]
+            }
+            Selection::PageRow(id) => vec![(&PAGE, "", "WHERE id = ?", vec![Bind::Int(*id)])],
+            Selection::BookPages(book_id) => {
+                vec![(&PAGE, "", "WHERE fk_book_id = ? ORDER BY id", vec![Bind::Int(*book_id)])]
+            }
+            Selection::PageGraph(id) | Selection::Page(id) => {
+                let mut queries = vec![
+                    (&PAGE, "", "WHERE id = ?", vec![Bind::Int(*id)]),
+                    (&NODE, "", "WHERE fk_page_id = ? ORDER BY rowid", vec![Bind::Int(*id)]),
+                    (&EDGE, "", "WHERE fk_page_id = ? ORDER BY id", vec![Bind::Int(*id)]),
+                ];
+                if matches!(self, Selection::Page(_)) {
+                    queries.push((&PAGE_VERSION, "", "WHERE fk_page_id = ? ORDER BY id", vec![Bind::Int(*id)]));
+                }
+                queries
+            }
+            Selection::Node(page_id, id) => vec![
+                (
+                    &NODE,
+                    "",
+                    "WHERE fk_page_id = ? AND id = ?",
+                    vec![Bind::Int(*page_id), Bind::Text(id.clone())],
+                ),
+                (
+                    &EDGE,
+                    "",
+                    "WHERE fk_page_id = ? AND (source = ? OR target = ?) ORDER BY id",
+                    vec![Bind::Int(*page_id), Bind::Text(id.clone()), Bind::Text(id.clone())],
+                ),
+            ],
+            Selection::Edge(id) => vec![(&EDGE, "", "WHERE id = ?", vec![Bind::Int(*id)])],
+        }
+    }
+}
+
+/// 행 전체를 JSON 객체로 읽는 SELECT 식
+fn row_object(table: &TableSpec) -> String {
+    let fields = table
+        .columns
+        .iter()
+        .map(|column| format!("'{}', {}.{}", column, table.name, column))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("json_object({})", fields)
+}
+
+/// 키 컬럼으로 행을 찾는 조건 (?1에 행 JSON을 바인딩)
+fn key_condition(table: &TableSpec) -> String {
+    table
+        .key
+        .iter()
+        .map(|key| format!("{} = json_extract(?1, '$.{}')", key, key))
+        .collect::<Vec<_>>()
+        .join(" AND ")
+}
+
+/// 선택 범위의 현재 행들을 캡처
+pub(crate) async fn capture(conn: &mut SqliteConnection, selection: &Selection) -> Result<Vec<RowImage>, sqlx::Error> {
+    let mut images = Vec::new();
+    for (table, prefix, condition, binds) in selection.queries() {
+        let sql = format!("{} SELECT {} FROM {} {}", prefix, row_object(table), table.name, condition);
+        let mut query = sqlx::query_scalar::<_, String>(&sql);
+        for bind in binds {
+            query = match bind {
+                Bind::Int(value) => query.bind(value),
+                Bind::Text(value) => query.bind(value),
+            };
+        }
+        for row in query.fetch_all(&mut *conn).await? {
+            images.push(RowImage {
+                table: table.name.to_string(),
+                row: serde_json::from_str(&row).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            });
+        }
+    }
+    Ok(images)
+}
+
+fn diff_images(before: Vec<RowImage>, after: Vec<RowImage>) -> Vec<RowChange> {
+    let mut changes = Vec::new();
+    for old in &before {
+        let new = after.iter().find(|image| image.same_row(old));
+        if new.map(|image| &image.row) != Some(&old.row) {
+            changes.push(RowChange {
+                table: old.table.clone(),
+                before: Some(old.row.clone()),
+                after: new.map(|image| image.row.clone()),
+            });
+        }
+    }
+    for new in after {
+        if !before.iter().any(|image| image.same_row(&new)) {
+            changes.push(RowChange { table: new.table, before: None, after: Some(new.row) });
+        }
+    }
+    changes
+}
+
+/// 작업 후 상태를 캡처해 before와 비교하고, 바뀐 행이 있으면 저널에 기록
+/// 새 작업이 기록되면 같은 범위의 다시 실행(redo) 목록은 비움
+pub(crate) async fn record(
+    conn: &mut SqliteConnection,
+    scope: JournalScope,
+    label: &str,
+    selection: &Selection,
+    before: Vec<RowImage>,
+) -> Result<(), sqlx::Error> {
+    let after = capture(conn, selection).await?;
+    let changes = diff_images(before, after);
+    if changes.is_empty() {
+        return Ok(());
+    }
+
+    let scope = scope.key();
+    sqlx::query("DELETE FROM OperationLog WHERE scope = ? AND undone = 1")
+        .bind(&scope)
+        .execute(&mut *conn)
+        .await?;
+    sqlx::query("INSERT INTO OperationLog (scope, label, changes, undone, created_at) VALUES (?, ?, ?, 0, ?)")
+        .bind(&scope)
+        .bind(label)
+        .bind(serde_json::to_string(&changes).map_err(|e| sqlx::Error::Encode(Box::new(e)))?)
+        .bind(chrono::Utc::now().to_rfc3339())
+        .execute(&mut *conn)
+        .await?;
+    sqlx::query(
+        "DELETE FROM OperationLog WHERE scope = ? AND id NOT IN (
+            SELECT id FROM OperationLog WHERE scope = ? ORDER BY id DESC LIMIT ?
+        )",
+    )
+    .bind(&scope)
+    .bind(&scope)
+    .bind(MAX_ENTRIES_PER_SCOPE)
+    .execute(&mut *conn)
+    .await?;
+    Ok(())
+}
+
+/// 행들을 target 상태로 되돌림 (target이 None이면 삭제)
+/// 외래 키 순서를 지키기 위해 추가/수정은 부모 → 자식, 삭제는 자식 → 부모 순서로 실행
+async fn apply_changes(conn: &mut SqliteConnection, changes: &[RowChange], undo: bool) -> Result<(), sqlx::Error> {
+    let target = |change: &RowChange| if undo { change.before.clone() } else { change.after.clone() };
+
+    for table in TABLES {
+        for change in changes.iter().filter(|change| change.table == table.name) {
+            let Some(row) = target(change) else { continue };
+            let columns = table.columns.join(", ");
+            let values = table
+                .columns
+                .iter()
+                .map(|column| format!("json_extract(?1, '$.{}')", column))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let updates = table
+                .columns
+                .iter()
+                .filter(|column| !table.key.contains(column))
+                .map(|column| format!("{} = excluded.{}", column, column))
+                .collect::<Vec<_>>()
+                .join(", ");
+            sqlx::query(&format!(
+                "INSERT INTO {} ({}) VALUES ({}) ON CONFLICT({}) DO UPDATE SET {}",
+                table.name,
+                columns,
+                values,
+                table.key.join(", "),
+                updates
+            ))
+            .bind(row.to_string())
+            .execute(&mut *conn)
+            .await?;
+        }
+    }
+
+    for table in TABLES.iter().rev() {
+        for change in changes.iter().filter(|change| change.table == table.name) {
+            if target(change).is_some() {
+                continue;
+            }
+            let Some(row) = change.current(undo) else { continue };
+            sqlx::query(&format!("DELETE FROM {} WHERE {}", table.name, key_condition(table)))
+                .bind(row.to_string())
+                .execute(&mut *conn)
+                .await?;
+        }
+    }
+    Ok(())
+}
+
+impl RowChange {
+    /// 작업 방향에서 적용 전(현재)이어야 할 값
+    fn current(&self, undo: bool) -> Option<&Value> {
+        if undo { self.after.as_ref() } else { self.before.as_ref() }
+    }
+
+    fn image(&self) -> Option<RowImage> {
+        let row = self.before.as_ref().or(self.after.as_ref())?;
+        Some(RowImage { table: self.table.clone(), row: row.clone() })
+    }
+}
+
+/// 키 값으로 표시한 행 이름 (예: "Node 10/a")
+fn describe_row(table: &TableSpec, row: &Value) -> String {
+    let key = table
+        .key
+        .iter()
+        .map(|key| match row.get(key) {
+            Some(Value::String(value)) => value.clone(),
+            Some(value) => value.to_string(),
+            None => String::new(),
+        })
+        .collect::<Vec<_>>()
+        .join("/");
+    format!("{} {}", table.name, key)
+}
+
+/// 행의 현재 값 (없으면 None)
+async fn load_row(conn: &mut SqliteConnection, table: &TableSpec, row: &Value) -> Result<Option<Value>, sqlx::Error> {
+    let sql = format!("SELECT {} FROM {} WHERE {}", row_object(table), table.name, key_condition(table));
+    let current: Option<String> = sqlx::query_scalar(&sql)
+        .bind(row.to_string())
+        .fetch_optional(&mut *conn)
+        .await?;
+    current
+        .map(|current| serde_json::from_str(&current).map_err(|e| sqlx::Error::Decode(Box::new(e))))
+        .transpose()
+}
+
+/// 저널 밖에서(번들 교체, 북 복제, 휴지통 비우기, 백업 복원 등) 바뀐 행이 있으면 그 행 이름을 반환
+async fn find_conflict(
+    conn: &mut SqliteConnection,
+    changes: &[RowChange],
+    undo: bool,
+) -> Result<Option<String>, sqlx::Error> {
+    for change in changes {
+        let Some(table) = table_spec(&change.table) else { continue };
+        let Some(key_row) = change.before.as_ref().or(change.after.as_ref()) else { continue };
+        let current = load_row(conn, table, key_row).await?;
+        if current.as_ref() != change.current(undo) {
+            return Ok(Some(describe_row(table, key_row)));
+        }
+    }
+    Ok(None)
+}
+
+/// 삭제할 행에 CASCADE로 함께 지워지는 하위 행 범위
+fn cascade_selection(table: &str, row: &Value) -> Option<Selection> {
+    let int = |key: &str| row.get(key).and_then(Value::as_i64).map(|value| value as i32);
+    match table {
+        "Book" => Some(Selection::BookTree(int("id")?)),
+        "Page" => Some(Selection::Page(int("id")?)),
+        "Node" => Some(Selection::Node(int("fk_page_id")?, row.get("id")?.as_str()?.to_string())),
+        _ => None,
+    }
+}
+
+/// 삭제로 함께 지워질 하위 행(작업 이후에 추가된 노드 등)을 같은 항목에 추가해
+/// 반대 방향으로 다시 적용할 때 복원되도록 함
+async fn add_cascaded_rows(conn: &mut SqliteConnection, changes: &mut Vec<RowChange>, undo: bool) -> Result<(), sqlx::Error> {
+    let mut selections = Vec::new();
+    for change in changes.iter() {
+        let target = if undo { &change.before } else { &change.after };
+        if target.is_some() {
+            continue;
+        }
+        if let Some(selection) = change.current(undo).and_then(|row| cascade_selection(&change.table, row)) {
+            selections.push(selection);
+        }
+    }
+
+    for selection in selections {
+        for image in capture(conn, &selection).await? {
+            if changes.iter().filter_map(RowChange::image).any(|known| known.same_row(&image)) {
+                continue;
+            }
+            let (before, after) = if undo { (None, Some(image.row)) } else { (Some(image.row), None) };
+            changes.push(RowChange { table: image.table, before, after });
+        }
+    }
+    Ok(())
+}
+
+fn map_entry(row: &sqlx::sqlite::SqliteRow) -> Option<JournalEntry> {
+    let scope: String = row.get(1);
+    let (kind, id) = scope.split_once(':')?;
+    let id = id.parse().ok()?;
+    Some(JournalEntry {
+        id: row.get(0),
+        scope: if kind == "book" { JournalScope::Book(id) } else { JournalScope::Page(id) },
+        label: row.get(2),
+        created_at: row.get(3),
+        changes: row.get(4),
+    })
+}
+
+const ENTRY_COLUMNS: &str = "id, scope, label, created_at, json_array_length(changes)";
+
+/// undo이면 마지막으로 실행한 작업, redo이면 가장 먼저 취소한 작업
+async fn next_entry(
+    conn: &mut SqliteConnection,
+    scope: JournalScope,
+    undo: bool,
+) -> Result<Option<(JournalEntry, String)>, sqlx::Error> {
+    let sql = if undo {
+        format!("SELECT {}, changes FROM OperationLog WHERE scope = ? AND undone = 0 ORDER BY id DESC LIMIT 1", ENTRY_COLUMNS)
+    } else {
+        format!("SELECT {}, changes FROM OperationLog WHERE scope = ? AND undone = 1 ORDER BY id ASC LIMIT 1", ENTRY_COLUMNS)
+    };
+    let row = sqlx::query(&sql).bind(scope.key()).fetch_optional(&mut *conn).await?;
+    Ok(row.and_then(|row| Some((map_entry(&row)?, row.get(5)))))
+}
+
+async fn step_with(pool: &Pool<Sqlite>, scope: JournalScope, undo: bool) -> Result<Option<JournalEntry>, String> {
+    let action = if undo { "undo" } else { "redo" };
+    let map_err = |e: sqlx::Error| format!("Failed to {}: {}", action, e);
+    let mut tx = pool.begin().await.map_err(map_err)?;
+    let Some((entry, changes)) = next_entry(&mut tx, scope, undo).await.map_err(map_err)? else {
+        return Ok(None);
+    };
+    let mut changes: Vec<RowChange> =
+        serde_json::from_str(&changes).map_err(|e| format!("Corrupted journal entry {}: {}", entry.id, e))?;
+
+    // 기록 이후 저널 밖에서 바뀐 행을 덮어쓰지 않도록 거부
+    if let Some(row) = find_conflict(&mut tx, &changes, undo).await.map_err(map_err)? {
+        return Err(format!(
+            "Cannot {} '{}': {} was changed outside the undo history",
+            action, entry.label, row
+        ));
+    }
+    add_cascaded_rows(&mut tx, &mut changes, undo).await.map_err(map_err)?;
+
+    apply_changes(&mut tx, &changes, undo)
+        .await
+        .map_err(|e| format!("Failed to {} '{}': {}", action, entry.label, e))?;
+    sqlx::query("UPDATE OperationLog SET undone = ?, changes = ? WHERE id = ?")
+        .bind(undo)
+        .bind(serde_json::to_string(&changes).map_err(|e| format!("Failed to {}: {}", action, e))?)
+        .bind(entry.id)
+        .execute(&mut *tx)
+        .await
+        .map_err(map_err)?;
+    tx.commit().await.map_err(map_err)?;
+    Ok(Some(entry))
+}
+
+/// 범위의 마지막 작업을 취소 (취소할 작업이 없으면 None)
+pub async fn undo_with(pool: &Pool<Sqlite>, scope: JournalScope) -> Result<Option<JournalEntry>, String> {
+    step_with(pool, scope, true).await
+}
+
+/// 마지막으로 취소한 작업을 다시 실행 (다시 실행할 작업이 없으면 None)
+pub async fn redo_with(pool: &Pool<Sqlite>, scope: JournalScope) -> Result<Option<JournalEntry>, String> {
+    step_with(pool, scope, false).await
+}
+
+pub async fn undo(scope: JournalScope) -> Result<Option<JournalEntry>, String> {
+    let pool = get_db_pool().await;
+    undo_with(&pool, scope).await
+}
+
+pub async fn redo(scope: JournalScope) -> Result<Option<JournalEntry>, String> {
+    let pool = get_db_pool().await;
+    redo_with(&pool, scope).await
+}
+
+pub async fn get_undo_state(scope: JournalScope) -> Result<UndoState, sqlx::Error> {
+    let pool = get_db_pool().await;
+    let mut conn = pool.acquire().await?;
+    Ok(UndoState {
+        undo: next_entry(&mut conn, scope, true).await?.map(|(entry, _)| entry),
+        redo: next_entry(&mut conn, scope, false).await?.map(|(entry, _)| entry),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::database::migration;
+
+    async fn count(pool: &Pool<Sqlite>, table: &str) -> i64 {
+        sqlx::query_scalar(&format!("SELECT COUNT(*) FROM {}", table))
+            .fetch_one(pool)
+            .await
+            .unwrap()
+    }
+
+    #[test]
+    fn test_undo_redo_restores_cascaded_rows() {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let pool = migration::test_pool().await;
+                sqlx::query("INSERT INTO Node VALUES ('a', 10, '{}', 'apiNode', 0, 0), ('b', 10, '{}', 'apiNode', 5, 5)")
+                    .execute(&pool)
+                    .await
+                    .unwrap();
+                sqlx::query("INSERT INTO Edge (id, fk_page_id, source, target) VALUES (7, 10, 'a', 'b')")
+                    .execute(&pool)
+                    .await
+                    .unwrap();
+                let scope = JournalScope::Page(10);
+
+                // 노드 이동
+                let mut conn = pool.acquire().await.unwrap();
+                let selection = Selection::Node(10, "a".to_string());
+                let before = capture(&mut conn, &selection).await.unwrap();
+                sqlx::query("UPDATE Node SET position_x = 99 WHERE id = 'a'").execute(&mut *conn).await.unwrap();
+                record(&mut conn, scope, "update_node", &selection, before).await.unwrap();
+
+                // 노드 삭제 (엣지는 CASCADE)
+                let selection = Selection::Node(10, "b".to_string());
+                let before = capture(&mut conn, &selection).await.unwrap();
+                sqlx::query("DELETE FROM Node WHERE id = 'b'").execute(&mut *conn).await.unwrap();
+                record(&mut conn, scope, "delete_node", &selection, before).await.unwrap();
+                drop(conn);
+                assert_eq!(count(&pool, "Edge").await, 0);
+
+                let undone = undo_with(&pool, scope).await.unwrap().unwrap();
+                assert_eq!(undone.label, "delete_node");
+                assert_eq!(count(&pool, "Node").await, 2);
+                let edge_id: i32 = sqlx::query_scalar("SELECT id FROM Edge").fetch_one(&pool).await.unwrap();
+                assert_eq!(edge_id, 7);
+
+                undo_with(&pool, scope).await.unwrap().unwrap();
+                let x: i32 = sqlx::query_scalar("SELECT position_x FROM Node WHERE id = 'a'").fetch_one(&pool).await.unwrap();
+                assert_eq!(x, 0);
+                assert!(undo_with(&pool, scope).await.unwrap().is_none());
+
+                let redone = redo_with(&pool, scope).await.unwrap().unwrap();
+                assert_eq!(redone.label, "update_node");
+                redo_with(&pool, scope).await.unwrap().unwrap();
+                assert_eq!(count(&pool, "Node").await, 1);
+                assert_eq!(count(&pool, "Edge").await, 0);
+                assert!(redo_with(&pool, scope).await.unwrap().is_none());
+                // 다른 범위에는 영향 없음
+                assert!(undo_with(&pool, JournalScope::Page(11)).await.unwrap().is_none());
+            });
+    }
+
+    #[test]
+    fn test_undo_refuses_rows_changed_outside_journal() {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let pool = migration::test_pool().await;
+                sqlx::query("INSERT INTO Node VALUES ('a', 10, '{}', 'apiNode', 0, 0)").execute(&pool).await.unwrap();
+                let scope = JournalScope::Page(10);
+
+                let mut conn = pool.acquire().await.unwrap();
+                let selection = Selection::Node(10, "a".to_string());
+                let before = capture(&mut conn, &selection).await.unwrap();
+                sqlx::query("UPDATE Node SET position_x = 99 WHERE id = 'a'").execute(&mut *conn).await.unwrap();
+                record(&mut conn, scope, "update_node", &selection, before).await.unwrap();
+                // 저널 없이 바뀐 행 (번들 교체, 백업 복원 등)
+                sqlx::query("UPDATE Node SET data = '{\"x\":1}' WHERE id = 'a'").execute(&mut *conn).await.unwrap();
+                drop(conn);
+
+                let err = undo_with(&pool, scope).await.unwrap_err();
+                assert!(err.contains("Node 10/a was changed outside"), "{}", err);
+                let x: i32 = sqlx::query_scalar("SELECT position_x FROM Node WHERE id = 'a'").fetch_one(&pool).await.unwrap();
+                assert_eq!(x, 99);
+                // 거부된 작업은 그대로 남음
+                let mut conn = pool.acquire().await.unwrap();
+                let (entry, _) = next_entry(&mut conn, scope, true).await.unwrap().unwrap();
+                assert_eq!(entry.label, "update_node");
+            });
+    }
+
+    #[test]
+    fn test_undo_create_keeps_cascaded_children_for_redo() {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let pool = migration::test_pool().await;
+                let scope = JournalScope::Book(1);
+
+                let mut conn = pool.acquire().await.unwrap();
+                let selection = Selection::PageRow(20);
+                let before = capture(&mut conn, &selection).await.unwrap();
+                sqlx::query("INSERT INTO Page (id, fk_book_id, title) VALUES (20, 1, 'new')").execute(&mut *conn).await.unwrap();
+                record(&mut conn, scope, "create_page", &selection, before).await.unwrap();
+                // 페이지를 만든 뒤 추가된 노드/엣지
+                sqlx::query("INSERT INTO Node VALUES ('a', 20, '{}', 'apiNode', 0, 0), ('b', 20, '{}', 'apiNode', 0, 0)")
+                    .execute(&mut *conn)
+                    .await
+                    .unwrap();
+                sqlx::query("INSERT INTO Edge (id, fk_page_id, source, target) VALUES (3, 20, 'a', 'b')")
+                    .execute(&mut *conn)
+                    .await
+                    .unwrap();
+                drop(conn);
+
+                undo_with(&pool, scope).await.unwrap().unwrap();
+                assert_eq!(count(&pool, "Page").await, 1);
+                assert_eq!(count(&pool, "Node").await, 0);
+
+                let redone = redo_with(&pool, scope).await.unwrap().unwrap();
+                assert_eq!(redone.changes, 4);
+                assert_eq!(count(&pool, "Node").await, 2);
+                assert_eq!(count(&pool, "Edge").await, 1);
+                // 다시 취소해도 충돌 없이 전체가 지워짐
+                undo_with(&pool, scope).await.unwrap().unwrap();
+                assert_eq!(count(&pool, "Node").await, 0);
+            });
+    }
+}
@@ -0,0 +1,577 @@
+use serde::{Deserialize, Serialize};
+use sqlx::{Pool, Row, Sqlite, SqliteConnection};
+use std::path::{Path, PathBuf};
+
+/// 마이그레이션 한 단계
+pub enum Step {
+    /// 그대로 실행할 SQL
+    Sql(&'static str),
+    /// 컬럼이 없을 때만 추가 (버전 관리 이전에 만들어진 DB 호환)
+    AddColumn {
+        table: &'static str,
+        column: &'static str,
+        definition: &'static str,
+    },
+}
+
+pub struct Migration {
+    pub version: i64,
+    pub description: &'static str,
+    pub steps: &'static [Step],
+}
+
+/// 순서대로 적용되는 스키마 변경 목록 - 이미 배포된 항목은 수정하지 말고 새 버전을 추가할 것
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "initial schema",
+        steps: &[
+            Step::Sql(
+                "CREATE TABLE IF NOT EXISTS Book (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    title TEXT NOT NULL,
+                    parent_id INTEGER,
+                    FOREIGN KEY (parent_id) REFERENCES Book(id) ON DELETE CASCADE
+                )",
+            ),
+            Step::Sql(
+                "CREATE TABLE IF NOT EXISTS Page (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    fk_book_id INTEGER NOT NULL,
+                    title TEXT NOT NULL,
+                    flow_data TEXT,
+                    FOREIGN KEY (fk_book_id) REFERENCES Book(id) ON DELETE CASCADE
+                )",
+            ),
+            Step::Sql(
+                "CREATE TABLE IF NOT EXISTS Node (
+                    id STRING,
+                    fk_page_id INTEGER NOT NULL,
+                    data TEXT NOT NULL,
+                    type STRING NOT NULL,
+                    position_x INTEGER NOT NULL,
+                    position_y INTEGER NOT NULL,
+                    FOREIGN KEY (fk_page_id) REFERENCES Page(id) ON DELETE CASCADE
+                    UNIQUE (id, fk_page_id)
+                )",
+            ),
+            Step::Sql(
+                "CREATE TABLE IF NOT EXISTS Edge (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    fk_page_id INTEGER NOT NULL,
+                    source TEXT NOT NULL,
+                    target TEXT NOT NULL,
+                    sourceHandle TEXT,
+                    targetHandle TEXT,
+                    FOREIGN KEY (fk_page_id) REFERENCES Page(id) ON DELETE CASCADE
+                )",
+            ),
+        ],
+    },
+    Migration {
+        version: 2,
+        description: "page display order",
+        steps: &[Step::AddColumn {
+            table: "Page",
+            column: "display_order",
+            definition: "INTEGER NOT NULL DEFAULT 0",
+        }],
+    },
+    Migration {
+        version: 3,
+        description: "connection profiles and environments",
+        steps: &[
+            // connection은 DatabaseConnection JSON
+            Step::Sql(
+                "CREATE TABLE IF NOT EXISTS ConnectionProfile (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    name TEXT NOT NULL,
+                    connection TEXT NOT NULL
+                )",
+            ),
+            // variables는 {"key": "value"} JSON
+            Step::Sql(
+                "CREATE TABLE IF NOT EXISTS Environment (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    name TEXT NOT NULL UNIQUE,
+                    variables TEXT NOT NULL
+                )",
+            ),
+        ],
+    },
+    Migration {
+        version: 4,
+        description: "node primary key and edge references",
+        steps: &[
+            // 고아 행 정리 (부모가 없는 북은 하위 북까지 함께 삭제)
+            Step::Sql(
+                "DELETE FROM Book WHERE id IN (
+                    WITH RECURSIVE orphan(id) AS (
+                        SELECT id FROM Book WHERE parent_id IS NOT NULL AND parent_id NOT IN (SELECT id FROM Book)
+                        UNION
+                        SELECT b.id FROM Book b JOIN orphan o ON b.parent_id = o.id
+                    )
+                    SELECT id FROM orphan
+                )",
+            ),
+            Step::Sql("DELETE FROM Page WHERE fk_book_id NOT IN (SELECT id FROM Book)"),
+            Step::Sql("DELETE FROM Node WHERE id IS NULL OR fk_page_id NOT IN (SELECT id FROM Page)"),
+            // Node: (fk_page_id, id) 기본 키
+            Step::Sql(
+                "CREATE TABLE Node_new (
+                    id TEXT NOT NULL,
+                    fk_page_id INTEGER NOT NULL,
+                    data TEXT NOT NULL,
+                    type TEXT NOT NULL,
+                    position_x INTEGER NOT NULL,
+                    position_y INTEGER NOT NULL,
+                    PRIMARY KEY (fk_page_id, id),
+                    FOREIGN KEY (fk_page_id) REFERENCES Page(id) ON DELETE CASCADE
+                )",
+            ),
+            Step::Sql(
+                "INSERT INTO Node_new (id, fk_page_id, data, type, position_x, position_y)
+                 SELECT id, fk_page_id, data, type, position_x, position_y FROM Node",
+            ),
+            Step::Sql("DROP TABLE Node"),
+            Step::Sql("ALTER TABLE Node_new RENAME TO Node"),
+            // Edge: source/target이 같은 페이지의 노드를 참조 (노드 삭제 시 엣지도 삭제)
+            Step::Sql(
+                "CREATE TABLE Edge_new (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    fk_page_id INTEGER NOT NULL,
+                    source TEXT NOT NULL,
+                    target TEXT NOT NULL,
+                    sourceHandle TEXT,
+                    targetHandle TEXT,
+                    FOREIGN KEY (fk_page_id) REFERENCES Page(id) ON DELETE CASCADE,
+                    FOREIGN KEY (fk_page_id, source) REFERENCES Node(fk_page_id, id) ON DELETE CASCADE,
+                    FOREIGN KEY (fk_page_id, target) REFERENCES Node(fk_page_id, id) ON DELETE CASCADE
+                )",
+            ),
+            Step::Sql(
+                "INSERT INTO Edge_new (id, fk_page_id, source, target, sourceHandle, targetHandle)
+                 SELECT e.id, e.fk_page_id, e.source, e.target, e.sourceHandle, e.targetHandle FROM Edge e
+                 WHERE EXISTS (SELECT 1 FROM Node n WHERE n.fk_page_id = e.fk_page_id AND n.id = e.source)
+                   AND EXISTS (SELECT 1 FROM Node n WHERE n.fk_page_id = e.fk_page_id AND n.id = e.target)",
+            ),
+            Step::Sql("DROP TABLE Edge"),
+            Step::Sql("ALTER TABLE Edge_new RENAME TO Edge"),
+        ],
+    },
+    Migration {
+        version: 5,
+        description: "page version history",
+        steps: &[
+            // snapshot은 PageSnapshot JSON (title, flow_data, nodes, edges)
+            Step::Sql(
+                "CREATE TABLE PageVersion (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    fk_page_id INTEGER NOT NULL,
+                    version INTEGER NOT NULL,
+                    author TEXT,
+                    message TEXT,
+                    created_at TEXT NOT NULL,
+                    snapshot TEXT NOT NULL,
+                    FOREIGN KEY (fk_page_id) REFERENCES Page(id) ON DELETE CASCADE,
+                    UNIQUE (fk_page_id, version)
+                )",
+            ),
+        ],
+    },
+    Migration {
+        version: 6,
+        description: "undo journal",
+        steps: &[
+            // scope는 "page:<id>" / "book:<id>", changes는 행 변경 전후 JSON 목록
+            Step::Sql(
+                "CREATE TABLE OperationLog (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    scope TEXT NOT NULL,
+                    label TEXT NOT NULL,
+                    changes TEXT NOT NULL,
+                    undone INTEGER NOT NULL DEFAULT 0,
+                    created_at TEXT NOT NULL
+                )",
+            ),
+            Step::Sql("CREATE INDEX idx_operation_log_scope ON OperationLog (scope, id)"),
+        ],
+    },
+    Migration {
+        version: 7,
+        description: "full-text search index",
+        steps: &[
+            // title은 북/페이지 제목 또는 노드 이름, content는 노드 data의 문자열 값들
+            Step::Sql(
+                "CREATE VIRTUAL TABLE SearchIndex USING fts5(
+                    kind UNINDEXED,
+                    book_id UNINDEXED,
+                    page_id UNINDEXED,
+                    node_id UNINDEXED,
+                    node_type UNINDEXED,
+                    title,
+                    content,
+                    tokenize = 'unicode61 remove_diacritics 2'
+                )",
+            ),
+            Step::Sql(
+                "CREATE TRIGGER search_book_insert AFTER INSERT ON Book BEGIN
+                    INSERT INTO SearchIndex (kind, book_id, title, content) VALUES ('book', NEW.id, NEW.title, '');
+                END",
+            ),
+            Step::Sql(
+                "CREATE TRIGGER search_book_update AFTER UPDATE OF title ON Book BEGIN
+                    DELETE FROM SearchIndex WHERE kind = 'book' AND book_id = OLD.id;
+                    INSERT INTO SearchIndex (kind, book_id, title, content) VALUES ('book', NEW.id, NEW.title, '');
+                END",
+            ),
+            Step::Sql(
+                "CREATE TRIGGER search_book_delete AFTER DELETE ON Book BEGIN
+                    DELETE FROM SearchIndex WHERE kind = 'book' AND book_id = OLD.id;
+                END",
+            ),
+            Step::Sql(
+                "CREATE TRIGGER search_page_insert AFTER INSERT ON Page BEGIN
+                    INSERT INTO SearchIndex (kind, page_id, title, content) VALUES ('page', NEW.id, NEW.title, '');
+                END",
+            ),
+            Step::Sql(
+                "CREATE TRIGGER search_page_update AFTER UPDATE OF title ON Page BEGIN
+                    DELETE FROM SearchIndex WHERE kind = 'page' AND page_id = OLD.id;
+                    INSERT INTO SearchIndex (kind, page_id, title, content) VALUES ('page', NEW.id, NEW.title, '');
+                END",
+            ),
+            Step::Sql(
+                "CREATE TRIGGER search_page_delete AFTER DELETE ON Page BEGIN
+                    DELETE FROM SearchIndex WHERE kind = 'page' AND page_id = OLD.id;
+                END",
+            ),
+            // data가 JSON이 아니면 원문 그대로 색인
+            Step::Sql(
+                "CREATE TRIGGER search_node_insert AFTER INSERT ON Node BEGIN
+                    INSERT INTO SearchIndex (kind, page_id, node_id, node_type, title, content) VALUES (
+                        'node', NEW.fk_page_id, NEW.id, NEW.type,
+                        CASE WHEN json_valid(NEW.data) THEN COALESCE(json_extract(NEW.data, '$.name'), json_extract(NEW.data, '$.label'), NEW.id) ELSE NEW.id END,
+                        CASE WHEN json_valid(NEW.data) THEN (SELECT group_concat(value, ' ') FROM json_tree(NEW.data) WHERE type = 'text') ELSE NEW.data END
+                    );
+                END",
+            ),
+            Step::Sql(
+                "CREATE TRIGGER search_node_update AFTER UPDATE ON Node BEGIN
+                    DELETE FROM SearchIndex WHERE kind = 'node' AND page_id = OLD.fk_page_id AND node_id = OLD.id;
+                    INSERT INTO SearchIndex (kind, page_id, node_id, node_type, title, content) VALUES (
+                        'node', NEW.fk_page_id, NEW.id, NEW.type,
+                        CASE WHEN json_valid(NEW.data) THEN COALESCE(json_extract(NEW.data, '$.name'), json_extract(NEW.data, '$.label'), NEW.id) ELSE NEW.id END,
+                        CASE WHEN json_valid(NEW.data) THEN (SELECT group_concat(value, ' ') FROM json_tree(NEW.data) WHERE type = 'text') ELSE NEW.data END
+                    );
+                END",
+            ),
+            Step::Sql(
+                "CREATE TRIGGER search_node_delete AFTER DELETE ON Node BEGIN
+                    DELETE FROM SearchIndex WHERE kind = 'node' AND page_id = OLD.fk_page_id AND node_id = OLD.id;
+                END",
+            ),
+            // 기존 데이터 색인
+            Step::Sql("INSERT INTO SearchIndex (kind, book_id, title, content) SELECT 'book', id, title, '' FROM Book"),
+            Step::Sql("INSERT INTO SearchIndex (kind, page_id, title, content) SELECT 'page', id, title, '' FROM Page"),
+            Step::Sql(
+                "INSERT INTO SearchIndex (kind, page_id, node_id, node_type, title, content)
+                 SELECT 'node', fk_page_id, id, type,
+                    CASE WHEN json_valid(data) THEN COALESCE(json_extract(data, '$.name'), json_extract(data, '$.label'), id) ELSE id END,
+                    CASE WHEN json_valid(data) THEN (SELECT group_concat(value, ' ') FROM json_tree(Node.data) WHERE type = 'text') ELSE data END
+                 FROM Node",
+            ),
+        ],
+    },
+    Migration {
+        version: 8,
+        description: "trash (soft delete) for books and pages",
+        steps: &[
+            Step::AddColumn { table: "Book", column: "deleted_at", definition: "TEXT" },
+            Step::AddColumn { table: "Page", column: "deleted_at", definition: "TEXT" },
+        ],
+    },
+    Migration {
+        version: 9,
+        description: "autosaved page versions",
+        steps: &[
+            // 자동 저장으로 만든 버전 (짧은 간격의 자동 저장은 이 버전에 합침)
+            Step::AddColumn { table: "PageVersion", column: "auto_saved", definition: "INTEGER NOT NULL DEFAULT 0" },
+        ],
+    },
+];
+
+/// 마이그레이션 실행 결과
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MigrationReport {
+    pub from_version: i64,
+    pub to_version: i64,
+    pub applied: Vec<i64>,
+    /// 적용 전에 만든 백업 파일
+    pub backup_path: Option<String>,
+}
+
+pub fn latest_version() -> i64 {
+    MIGRATIONS.last().map(|m| m.version).unwrap_or(0)
+}
+
+async fn ensure_version_table(pool: &Pool<Sqlite>) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS schema_version (
+            version INTEGER PRIMARY KEY,
+            description TEXT NOT NULL,
+            applied_at TEXT NOT NULL
+        )",
+    )
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+pub async fn current_version(pool: &Pool<Sqlite>) -> Result<i64, sqlx::Error> {
+    ensure_version_table(pool).await?;
+    sqlx::query_scalar("SELECT COALESCE(MAX(version), 0) FROM schema_version")
+        .fetch_one(pool)
+        .await
+}
+
+async fn has_column(conn: &mut SqliteConnection, table: &str, column: &str) -> Result<bool, sqlx::Error> {
+    let rows = sqlx::query(&format!("PRAGMA table_info({})", table))
+        .fetch_all(&mut *conn)
+        .await?;
+    Ok(rows.iter().any(|row| row.get::<String, _>("name") == column))
+}
+
+async fn apply(pool: &Pool<Sqlite>, migration: &Migration) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    for step in migration.steps {
+        match step {
+            Step::Sql(sql) => {
+                sqlx::query(sql).execute(&mut *tx).await?;
+            }
+            Step::AddColumn { table, column, definition } => {
+                if !has_column(&mut tx, table, column).await? {
+                    sqlx::query(&format!("ALTER TABLE {} ADD COLUMN {} {}", table, column, definition))
+                        .execute(&mut *tx)
+                        .await?;
+                }
+            }
+        }
+    }
+    sqlx::query("INSERT INTO schema_version (version, description, applied_at) VALUES (?, ?, ?)")
+        .bind(migration.version)
+        .bind(migration.description)
+        .bind(chrono::Utc::now().to_rfc3339())
+        .execute(&mut *tx)
+        .await?;
+    tx.commit().await
+}
+
+/// 사용자 테이블이 하나라도 있으면 백업 대상
+async fn has_user_tables(pool: &Pool<Sqlite>) -> Result<bool, sqlx::Error> {
+    let count: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%' AND name != 'schema_version'",
+    )
+    .fetch_one(pool)
+    .await?;
+    Ok(count > 0)
+}
+
+/// ttol.db → ttol.db.v<버전>-<시각>.bak (VACUUM INTO는 열린 DB에서도 일관된 사본을 만듦)
+async fn backup(pool: &Pool<Sqlite>, db_path: &Path, version: i64) -> Result<PathBuf, String> {
+    let file_name = db_path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| "ttol.db".to_string());
+    let backup_path = db_path.with_file_name(format!(
+        "{}.v{}-{}.bak",
+        file_name,
+        version,
+        chrono::Local::now().format("%Y%m%d%H%M%S")
+    ));
+    sqlx::query("VACUUM INTO ?")
+        .bind(backup_path.to_string_lossy().to_string())
+        .execute(pool)
+        .await
+        .map_err(|e| format!("Failed to back up database to {}: {}", backup_path.display(), e))?;
+    Ok(backup_path)
+}
+
+/// 미적용 마이그레이션을 버전 순서대로 각각 트랜잭션으로 적용 (적용 전 db_path 백업)
+pub async fn migrate(pool: &Pool<Sqlite>, db_path: Option<&Path>) -> Result<MigrationReport, String> {
+    let from_version = current_version(pool)
+        .await
+        .map_err(|e| format!("Failed to read schema version: {}", e))?;
+    if from_version > latest_version() {
+        return Err(format!(
+            "Database schema version {} is newer than this ITOL build supports ({})",
+            from_version,
+            latest_version()
+        ));
+    }
+
+    let pending: Vec<&Migration> = MIGRATIONS.iter().filter(|m| m.version > from_version).collect();
+    let mut report = MigrationReport {
+        from_version,
+        to_version: from_version,
+        ..Default::default()
+    };
+    if pending.is_empty() {
+        return Ok(report);
+    }
+
+    if let Some(db_path) = db_path {
+        let has_data = has_user_tables(pool)
+            .await
+            .map_err(|e| format!("Failed to inspect database: {}", e))?;
+        if has_data {
+            let backup_path = backup(pool, db_path, from_version).await?;
+            log::info!("Database backed up to {}", backup_path.display());
+            report.backup_path = Some(backup_path.to_string_lossy().to_string());
+        }
+    }
+
+    for migration in pending {
+        apply(pool, migration).await.map_err(|e| {
+            format!(
+                "Migration {} ({}) failed: {}",
+                migration.version, migration.description, e
+            )
+        })?;
+        log::info!("Applied migration {} ({})", migration.version, migration.description);
+        report.applied.push(migration.version);
+        report.to_version = migration.version;
+    }
+    Ok(report)
+}
+
+/// 테스트용 메모리 DB (외래 키 활성화, 최신 스키마)
+#[cfg(test)]
+pub(crate) async fn empty_test_pool() -> Pool<Sqlite> {
+    let options = sqlx::sqlite::SqliteConnectOptions::new().in_memory(true).foreign_keys(true);
+    let pool = sqlx::sqlite::SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect_with(options)
+        .await
+        .unwrap();
+    migrate(&pool, None).await.unwrap();
+    pool
+}
+
+/// 테스트용 메모리 DB에 Book 1 / Page 10 을 만들어 둠
+#[cfg(test)]
+pub(crate) async fn test_pool() -> Pool<Sqlite> {
+    let pool = empty_test_pool().await;
+    sqlx::query("INSERT INTO Book (id, title) VALUES (1, 'b')").execute(&pool).await.unwrap();
+    sqlx::query("INSERT INTO Page (id, fk_book_id, title) VALUES (10, 1, 'p')").execute(&pool).await.unwrap();
+    pool
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn memory_pool() -> Pool<Sqlite> {
+        sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap()
+    }
+
+    #[test]
+    fn test_migrate_fresh_and_legacy_databases() {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                // 새 DB: 모든 마이그레이션 적용, 두 번째 실행은 변경 없음
+                let pool = memory_pool().await;
+                let report = migrate(&pool, None).await.unwrap();
+                assert_eq!(report.applied.len(), MIGRATIONS.len());
+                assert_eq!(current_version(&pool).await.unwrap(), latest_version());
+                assert!(migrate(&pool, None).await.unwrap().applied.is_empty());
+
+                // 버전 관리 이전 DB: display_order가 이미 있어도 실패하지 않음
+                let pool = memory_pool().await;
+                sqlx::query("CREATE TABLE Book (id INTEGER PRIMARY KEY AUTOINCREMENT, title TEXT NOT NULL, parent_id INTEGER)")
+                    .execute(&pool)
+                    .await
+                    .unwrap();
+                sqlx::query("INSERT INTO Book (id, title) VALUES (1, 'b')")
+                    .execute(&pool)
+                    .await
+                    .unwrap();
+                sqlx::query("CREATE TABLE Page (id INTEGER PRIMARY KEY AUTOINCREMENT, fk_book_id INTEGER NOT NULL, title TEXT NOT NULL, flow_data TEXT, display_order INTEGER NOT NULL DEFAULT 0)")
+                    .execute(&pool)
+                    .await
+                    .unwrap();
+                sqlx::query("INSERT INTO Page (fk_book_id, title, display_order) VALUES (1, 'kept', 3)")
+                    .execute(&pool)
+                    .await
+                    .unwrap();
+                // 외래 키 없이 만들어진 Node/Edge: 고아 행과 없는 노드를 가리키는 엣지 포함
+                for sql in [
+                    "CREATE TABLE Node (id STRING, fk_page_id INTEGER NOT NULL, data TEXT NOT NULL, type STRING NOT NULL, position_x INTEGER NOT NULL, position_y INTEGER NOT NULL, UNIQUE (id, fk_page_id))",
+                    "CREATE TABLE Edge (id INTEGER PRIMARY KEY AUTOINCREMENT, fk_page_id INTEGER NOT NULL, source TEXT NOT NULL, target TEXT NOT NULL, sourceHandle TEXT, targetHandle TEXT)",
+                    "INSERT INTO Node VALUES ('a', 1, '{}', 'apiNode', 0, 0), ('b', 1, '{}', 'dbNode', 0, 0), (NULL, 1, '{}', 'dbNode', 0, 0), ('x', 99, '{}', 'apiNode', 0, 0)",
+                    "INSERT INTO Edge (fk_page_id, source, target) VALUES (1, 'a', 'b'), (1, 'a', 'missing'), (99, 'x', 'a')",
+                ] {
+                    sqlx::query(sql).execute(&pool).await.unwrap();
+                }
+                let report = migrate(&pool, None).await.unwrap();
+                assert_eq!(report.from_version, 0);
+                assert_eq!(report.to_version, latest_version());
+                let order: i64 = sqlx::query_scalar("SELECT display_order FROM Page WHERE title = 'kept'")
+                    .fetch_one(&pool)
+                    .await
+                    .unwrap();
+                assert_eq!(order, 3);
+
+                // v4: 고아 노드/엣지는 정리되고 나머지는 유지
+                let nodes: Vec<String> = sqlx::query_scalar("SELECT id FROM Node ORDER BY id")
+                    .fetch_all(&pool)
+                    .await
+                    .unwrap();
+                assert_eq!(nodes, vec!["a".to_string(), "b".to_string()]);
+                let edges: Vec<(String, String)> = sqlx::query_as("SELECT source, target FROM Edge")
+                    .fetch_all(&pool)
+                    .await
+                    .unwrap();
+                assert_eq!(edges, vec![("a".to_string(), "b".to_string())]);
+
+                // v4: 엣지는 (fk_page_id, source/target)으로 노드를 참조하고 노드 삭제 시 함께 삭제
+                let node_refs: i64 = sqlx::query_scalar("SELECT COUNT(DISTINCT id) FROM pragma_foreign_key_list('Edge') WHERE \"table\" = 'Node'")
+                    .fetch_one(&pool)
+                    .await
+                    .unwrap();
+                assert_eq!(node_refs, 2);
+                sqlx::query("PRAGMA foreign_keys = ON").execute(&pool).await.unwrap();
+                assert!(sqlx::query("INSERT INTO Node (id, fk_page_id, data, type, position_x, position_y) VALUES ('a', 1, '{}', 'apiNode', 0, 0)")
+                    .execute(&pool)
+                    .await
+                    .is_err());
+                sqlx::query("DELETE FROM Node WHERE id = 'b'").execute(&pool).await.unwrap();
+                let edges: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM Edge").fetch_one(&pool).await.unwrap();
+                assert_eq!(edges, 0);
+            });
+    }
+
+    #[test]
+    fn test_migrate_rejects_newer_schema() {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let pool = memory_pool().await;
+                ensure_version_table(&pool).await.unwrap();
+                sqlx::query("INSERT INTO schema_version (version, description, applied_at) VALUES (999, 'future', '')")
+                    .execute(&pool)
+                    .await
+                    .unwrap();
+                assert!(migrate(&pool, None).await.is_err());
+            });
+    }
+}
@@ -1,4 +1,5 @@
 use super::get_db_pool;
+use super::journal::{self, JournalScope, Selection};
 use sqlx::Row;
 use serde::{Deserialize, Serialize};
 
@@ -44,6 +45,7 @@ pub async fn create_node(
 
     println!("[create_node] Creating node: id={}, fk_page_id={}, type={}", id, fk_page_id, node_type);
 
+    let mut tx = pool.begin().await?;
     sqlx::query("INSERT INTO Node (id, fk_page_id, data, type, position_x, position_y) VALUES (?, ?, ?, ?, ?, ?)")
         .bind(&id)
         .bind(fk_page_id)
@@ -51,8 +53,11 @@ pub async fn create_node(
         .bind(&node_type)
         .bind(position_x)
         .bind(position_y)
-        .execute(&*pool)
+        .execute(&mut *tx)
         .await?;
+    let selection = Selection::Node(fk_page_id, id.clone());
+    journal::record(&mut tx, JournalScope::Page(fk_page_id), "create_node", &selection, Vec::new()).await?;
+    tx.commit().await?;
     
     println!("[create_node] Successfully created node: {}", id);
     Ok(())
@@ -68,6 +73,9 @@ pub async fn update_node(
 ) -> Result<(), sqlx::Error> {
     let pool = get_db_pool().await;
 
+    let mut tx = pool.begin().await?;
+    let selection = Selection::Node(fk_page_id, id.clone());
+    let before = journal::capture(&mut tx, &selection).await?;
     sqlx::query("UPDATE Node SET data = ?, type = ?, position_x = ?, position_y = ? WHERE id = ? AND fk_page_id = ?")
         .bind(data)
         .bind(node_type)
@@ -75,8 +83,11 @@ pub async fn update_node(
         .bind(position_y)
         .bind(id)
         .bind(fk_page_id)
-        .execute(&*pool)
+        .execute(&mut *tx)
         .await?;
+    // 값이 바뀌지 않은 저장은 기록되지 않음
+    journal::record(&mut tx, JournalScope::Page(fk_page_id), "update_node", &selection, before).await?;
+    tx.commit().await?;
     
     Ok(())
 }
@@ -84,11 +95,17 @@ pub async fn update_node(
 pub async fn delete_node(id: String, fk_page_id: i32) -> Result<(), sqlx::Error> {
     let pool = get_db_pool().await;
 
+    // 연결된 엣지도 CASCADE로 삭제되므로 함께 기록
+    let mut tx = pool.begin().await?;
+    let selection = Selection::Node(fk_page_id, id.clone());
+    let before = journal::capture(&mut tx, &selection).await?;
     sqlx::query("DELETE FROM Node WHERE id = ? AND fk_page_id = ?")
         .bind(id)
         .bind(fk_page_id)
-        .execute(&*pool)
+        .execute(&mut *tx)
         .await?;
+    journal::record(&mut tx, JournalScope::Page(fk_page_id), "delete_node", &selection, before).await?;
+    tx.commit().await?;
     
     Ok(())
 }
@@ -96,10 +113,15 @@ pub async fn delete_node(id: String, fk_page_id: i32) -> Result<(), sqlx::Error>
 pub async fn delete_nodes_by_page_id(page_id: i32) -> Result<(), sqlx::Error> {
     let pool = get_db_pool().await;
 
+    let mut tx = pool.begin().await?;
+    let selection = Selection::PageGraph(page_id);
+    let before = journal::capture(&mut tx, &selection).await?;
     sqlx::query("DELETE FROM Node WHERE fk_page_id = ?")
         .bind(page_id)
-        .execute(&*pool)
+        .execute(&mut *tx)
         .await?;
+    journal::record(&mut tx, JournalScope::Page(page_id), "delete_nodes", &selection, before).await?;
+    tx.commit().await?;
     
     Ok(())
 }
@@ -1,4 +1,7 @@
 use super::get_db_pool;
+use super::history;
+use super::journal::{self, JournalScope, Selection};
+use super::trash;
 use sqlx::Row;
 use serde::{Deserialize, Serialize};
 
@@ -14,7 +17,7 @@ pub struct Page {
 pub async fn get_page_by_id(id: i32) -> Result<Page, sqlx::Error> {
     let pool = get_db_pool().await;
 
-    let page = sqlx::query("SELECT id, fk_book_id, title, flow_data, display_order FROM Page WHERE id = ?")
+    let page = sqlx::query("SELECT id, fk_book_id, title, flow_data, display_order FROM Page WHERE id = ? AND deleted_at IS NULL")
         .bind(id)
         .map(|row: sqlx::sqlite::SqliteRow| {
             Page {
@@ -33,7 +36,7 @@ pub async fn get_page_by_id(id: i32) -> Result<Page, sqlx::Error> {
 pub async fn get_pages_by_book_id(book_id: i32) -> Result<Vec<Page>, sqlx::Error> {
     let pool = get_db_pool().await;
 
-    let pages = sqlx::query("SELECT id, fk_book_id, title, flow_data, display_order FROM Page WHERE fk_book_id = ? ORDER BY display_order, id")
+    let pages = sqlx::query("SELECT id, fk_book_id, title, flow_data, display_order FROM Page WHERE fk_book_id = ? AND deleted_at IS NULL ORDER BY display_order, id")
         .bind(book_id)
         .map(|row: sqlx::sqlite::SqliteRow| {
             Page {
@@ -60,27 +63,37 @@ pub async fn create_page(fk_book_id: i32, title: String, flow_data: Option<Strin
     
     let next_order = max_order.unwrap_or(-1) + 1;
 
+    let mut tx = pool.begin().await?;
     let result = sqlx::query("INSERT INTO Page (fk_book_id, title, flow_data, display_order) VALUES (?, ?, ?, ?)")
         .bind(fk_book_id)
         .bind(title)
         .bind(flow_data)
         .bind(next_order)
-        .execute(&*pool)
+        .execute(&mut *tx)
         .await?;
+    let id = result.last_insert_rowid() as i32;
+    journal::record(&mut tx, JournalScope::Page(id), "create_page", &Selection::PageRow(id), Vec::new()).await?;
+    tx.commit().await?;
     
-    Ok(result.last_insert_rowid() as i32)
+    Ok(id)
 }
 
 pub async fn update_page(id: i32, fk_book_id: i32, title: String, flow_data: Option<String>) -> Result<(), sqlx::Error> {
     let pool = get_db_pool().await;
 
+    let mut tx = pool.begin().await?;
+    let selection = Selection::PageRow(id);
+    let before = journal::capture(&mut tx, &selection).await?;
     sqlx::query("UPDATE Page SET fk_book_id = ?, title = ?, flow_data = ? WHERE id = ?")
         .bind(fk_book_id)
         .bind(title)
         .bind(flow_data)
         .bind(id)
-        .execute(&*pool)
+        .execute(&mut *tx)
         .await?;
+    journal::record(&mut tx, JournalScope::Page(id), "update_page", &selection, before).await?;
+    history::record_version(&mut tx, id, None, None, false).await?;
+    tx.commit().await?;
     
     Ok(())
 }
@@ -88,11 +101,21 @@ pub async fn update_page(id: i32, fk_book_id: i32, title: String, flow_data: Opt
 pub async fn update_page_order(id: i32, new_order: i32) -> Result<(), sqlx::Error> {
     let pool = get_db_pool().await;
 
+    // 페이지 순서 변경은 reorder_pages와 같이 북 범위로 기록
+    let mut tx = pool.begin().await?;
+    let book_id: i32 = sqlx::query_scalar("SELECT fk_book_id FROM Page WHERE id = ?")
+        .bind(id)
+        .fetch_one(&mut *tx)
+        .await?;
+    let selection = Selection::PageRow(id);
+    let before = journal::capture(&mut tx, &selection).await?;
     sqlx::query("UPDATE Page SET display_order = ? WHERE id = ?")
         .bind(new_order)
         .bind(id)
-        .execute(&*pool)
+        .execute(&mut *tx)
         .await?;
+    journal::record(&mut tx, JournalScope::Book(book_id), "update_page_order", &selection, before).await?;
+    tx.commit().await?;
     
     Ok(())
 }
@@ -100,14 +123,20 @@ pub async fn update_page_order(id: i32, new_order: i32) -> Result<(), sqlx::Erro
 pub async fn reorder_pages(book_id: i32, page_ids_in_order: Vec<i32>) -> Result<(), sqlx::Error> {
     let pool = get_db_pool().await;
     
+    // 여러 페이지의 순서 변경을 하나의 작업으로 기록 (북 범위)
+    let mut tx = pool.begin().await?;
+    let selection = Selection::BookPages(book_id);
+    let before = journal::capture(&mut tx, &selection).await?;
     for (index, page_id) in page_ids_in_order.iter().enumerate() {
         sqlx::query("UPDATE Page SET display_order = ? WHERE id = ? AND fk_book_id = ?")
             .bind(index as i32)
             .bind(page_id)
             .bind(book_id)
-            .execute(&*pool)
+            .execute(&mut *tx)
             .await?;
     }
+    journal::record(&mut tx, JournalScope::Book(book_id), "reorder_pages", &selection, before).await?;
+    tx.commit().await?;
     
     Ok(())
 }
@@ -115,10 +144,13 @@ pub async fn reorder_pages(book_id: i32, page_ids_in_order: Vec<i32>) -> Result<
 pub async fn delete_page(id: i32) -> Result<(), sqlx::Error> {
     let pool = get_db_pool().await;
 
-    sqlx::query("DELETE FROM Page WHERE id = ?")
-        .bind(id)
-        .execute(&*pool)
-        .await?;
+    // 휴지통으로 이동 (영구 삭제는 trash::purge)
+    let mut tx = pool.begin().await?;
+    let selection = Selection::PageRow(id);
+    let before = journal::capture(&mut tx, &selection).await?;
+    trash::trash_page(&mut tx, id).await?;
+    journal::record(&mut tx, JournalScope::Page(id), "delete_page", &selection, before).await?;
+    tx.commit().await?;
     
     Ok(())
 }
\ No newline at end of file
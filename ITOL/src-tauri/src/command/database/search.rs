@@ -0,0 +1,146 @@
+use super::get_db_pool;
+use serde::{Deserialize, Serialize};
+use sqlx::{Pool, Row, Sqlite};
+
+const DEFAULT_LIMIT: i64 = 50;
+
+/// 검색 결과 한 건 (kind: "book" | "page" | "node")
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchHit {
+    pub kind: String,
+    pub book_id: Option<i32>,
+    pub book_title: Option<String>,
+    pub page_id: Option<i32>,
+    pub page_title: Option<String>,
+    pub node_id: Option<String>,
+    pub node_type: Option<String>,
+    pub node_name: Option<String>,
+    /// 일치한 부분을 [ ]로 표시한 발췌
+    pub snippet: String,
+    /// bm25 점수 (작을수록 관련도 높음)
+    pub score: f64,
+}
+
+/// 사용자 입력 → FTS5 쿼리
+/// 각 단어를 따옴표로 감싼 접두어 검색으로 바꾸고 AND로 연결 ("/v2/orders" → "/v2/orders"*)
+pub fn to_fts_query(input: &str) -> Option<String> {
+    let terms: Vec<String> = input
+        .split_whitespace()
+        .filter(|term| term.chars().any(char::is_alphanumeric))
+        .map(|term| format!("\"{}\"*", term.replace('"', "\"\"")))
+        .collect();
+    if terms.is_empty() {
+        None
+    } else {
+        Some(terms.join(" "))
+    }
+}
+
+pub async fn search_with(pool: &Pool<Sqlite>, query: &str, limit: Option<i64>) -> Result<Vec<SearchHit>, sqlx::Error> {
+    let Some(fts_query) = to_fts_query(query) else {
+        return Ok(Vec::new());
+    };
+
+    // 제목 일치를 본문 일치보다 높게 (title 10, content 1)
+    let rows = sqlx::query(
+        "SELECT SearchIndex.kind,
+                COALESCE(SearchIndex.book_id, p.fk_book_id),
+                b.title,
+                SearchIndex.page_id,
+                p.title,
+                SearchIndex.node_id,
+                SearchIndex.node_type,
+                CASE WHEN SearchIndex.kind = 'node' THEN SearchIndex.title END,
+                snippet(SearchIndex, -1, '[', ']', '…', 12),
+                bm25(SearchIndex, 0, 0, 0, 0, 0, 10.0, 1.0) AS score
+         FROM SearchIndex
+         LEFT JOIN Page p ON p.id = SearchIndex.page_id
+         LEFT JOIN Book b ON b.id = COALESCE(SearchIndex.book_id, p.fk_book_id)
+         WHERE SearchIndex MATCH ?
+           AND p.deleted_at IS NULL
+           AND b.deleted_at IS NULL
+         ORDER BY score
+         LIMIT ?",
+    )
+    .bind(fts_query)
+    .bind(limit.unwrap_or(DEFAULT_LIMIT))
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .iter()
+        .map(|row| SearchHit {
+            kind: row.get(0),
+            book_id: row.get(1),
+            book_title: row.get(2),
+            page_id: row.get(3),
+            page_title: row.get(4),
+            node_id: row.get(5),
+            node_type: row.get(6),
+            node_name: row.get(7),
+            snippet: row.get(8),
+            score: row.get(9),
+        })
+        .collect())
+}
+
+pub async fn search(query: &str, limit: Option<i64>) -> Result<Vec<SearchHit>, sqlx::Error> {
+    let pool = get_db_pool().await;
+    search_with(&pool, query, limit).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::database::migration;
+
+    #[test]
+    fn test_to_fts_query() {
+        assert_eq!(to_fts_query("  /v2/orders  GET ").as_deref(), Some("\"/v2/orders\"* \"GET\"*"));
+        assert_eq!(to_fts_query("say \"hi\"").as_deref(), Some("\"say\"* \"\"\"hi\"\"\"*"));
+        assert_eq!(to_fts_query(" -- "), None);
+    }
+
+    #[test]
+    fn test_search_ranks_and_follows_changes() {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let pool = migration::empty_test_pool().await;
+                sqlx::query("INSERT INTO Book (id, title) VALUES (1, 'Order service')").execute(&pool).await.unwrap();
+                sqlx::query("INSERT INTO Page (id, fk_book_id, title) VALUES (10, 1, 'Checkout')").execute(&pool).await.unwrap();
+                sqlx::query("INSERT INTO Node VALUES (?, 10, ?, 'apiNode', 0, 0), ('db', 10, 'not json', 'dbNode', 0, 0)")
+                    .bind("api-node-1")
+                    .bind(r#"{"name": "Create order", "method": "POST", "url": "{{baseUrl}}/v2/orders", "headers": [{"key": "X-Trace", "value": "abc"}]}"#)
+                    .execute(&pool)
+                    .await
+                    .unwrap();
+
+                let hits = search_with(&pool, "/v2/orders", None).await.unwrap();
+                assert_eq!(hits.len(), 1);
+                assert_eq!(hits[0].node_id.as_deref(), Some("api-node-1"));
+                assert_eq!(hits[0].node_name.as_deref(), Some("Create order"));
+                assert_eq!(hits[0].page_title.as_deref(), Some("Checkout"));
+                assert_eq!(hits[0].book_title.as_deref(), Some("Order service"));
+                assert!(hits[0].snippet.contains("orders]"));
+
+                // 제목 일치가 먼저
+                let hits = search_with(&pool, "order", None).await.unwrap();
+                assert_eq!(hits[0].kind, "book");
+                assert_eq!(hits.len(), 2);
+
+                // 수정/삭제가 색인에 반영됨
+                sqlx::query("UPDATE Node SET data = '{\"url\": \"/v3/carts\"}' WHERE id = 'api-node-1'")
+                    .execute(&pool)
+                    .await
+                    .unwrap();
+                assert!(search_with(&pool, "/v2/orders", None).await.unwrap().is_empty());
+                assert_eq!(search_with(&pool, "carts", None).await.unwrap().len(), 1);
+                sqlx::query("DELETE FROM Book WHERE id = 1").execute(&pool).await.unwrap();
+                assert!(search_with(&pool, "carts", None).await.unwrap().is_empty());
+                assert!(search_with(&pool, "json", None).await.unwrap().is_empty());
+            });
+    }
+}
@@ -0,0 +1,376 @@
+use super::book::BOOK_TREE;
+use super::get_db_pool;
+use super::journal::{self, JournalScope, Selection};
+use serde::{Deserialize, Serialize};
+use sqlx::{Pool, Row, Sqlite, SqliteConnection};
+
+/// 휴지통 보관 기간 (앱 시작 시 이보다 오래된 항목은 영구 삭제)
+pub const DEFAULT_RETENTION_DAYS: i64 = 30;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TrashKind {
+    Book,
+    Page,
+}
+
+/// 휴지통 항목 - 함께 삭제된 하위 항목은 최상위 항목에 포함됨
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrashItem {
+    pub kind: TrashKind,
+    pub id: i32,
+    pub title: String,
+    /// 북이면 상위 북, 페이지면 소속 북
+    pub parent_id: Option<i32>,
+    pub parent_title: Option<String>,
+    pub deleted_at: String,
+    pub pages: i64,
+    pub nodes: i64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PurgeReport {
+    pub books: u64,
+    pub pages: u64,
+}
+
+/// 삭제 시각 - 문자열 비교로 기간을 판단하므로 형식을 고정
+fn timestamp(time: chrono::DateTime<chrono::Utc>) -> String {
+    time.to_rfc3339_opts(chrono::SecondsFormat::Micros, true)
+}
+
+/// 북과 아직 휴지통에 없는 하위 북/페이지를 같은 시각으로 휴지통에 넣음
+pub(crate) async fn trash_book(conn: &mut SqliteConnection, book_id: i32) -> Result<(), sqlx::Error> {
+    let deleted_at = timestamp(chrono::Utc::now());
+    sqlx::query(&format!(
+        "{} UPDATE Page SET deleted_at = ? WHERE deleted_at IS NULL AND fk_book_id IN (SELECT id FROM tree)",
+        BOOK_TREE
+    ))
+    .bind(book_id)
+    .bind(&deleted_at)
+    .execute(&mut *conn)
+    .await?;
+    sqlx::query(&format!(
+        "{} UPDATE Book SET deleted_at = ? WHERE deleted_at IS NULL AND id IN (SELECT id FROM tree)",
+        BOOK_TREE
+    ))
+    .bind(book_id)
+    .bind(&deleted_at)
+    .execute(&mut *conn)
+    .await?;
+    Ok(())
+}
+
+pub(crate) async fn trash_page(conn: &mut SqliteConnection, page_id: i32) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE Page SET deleted_at = ? WHERE id = ? AND deleted_at IS NULL")
+        .bind(timestamp(chrono::Utc::now()))
+        .bind(page_id)
+        .execute(&mut *conn)
+        .await?;
+    Ok(())
+}
+
+/// 휴지통에 있는 페이지(휴지통에 있는 북의 페이지 포함)이면 제목을 반환
+pub async fn trashed_page_title_with(pool: &Pool<Sqlite>, page_id: i32) -> Result<Option<String>, sqlx::Error> {
+    sqlx::query_scalar(
+        "WITH RECURSIVE ancestors(id, depth) AS (
+            SELECT fk_book_id, 0 FROM Page WHERE id = ?1
+            UNION ALL
+            SELECT b.parent_id, a.depth + 1 FROM Book b JOIN ancestors a ON b.id = a.id
+            WHERE b.parent_id IS NOT NULL AND a.depth < 64
+        )
+        SELECT title FROM Page WHERE id = ?1 AND (
+            deleted_at IS NOT NULL
+            OR EXISTS (SELECT 1 FROM Book JOIN ancestors ON Book.id = ancestors.id WHERE Book.deleted_at IS NOT NULL)
+        )",
+    )
+    .bind(page_id)
+    .fetch_optional(pool)
+    .await
+}
+
+pub async fn trashed_page_title(page_id: i32) -> Result<Option<String>, sqlx::Error> {
+    let pool = get_db_pool().await;
+    trashed_page_title_with(&pool, page_id).await
+}
+
+pub async fn list_trash_with(pool: &Pool<Sqlite>) -> Result<Vec<TrashItem>, sqlx::Error> {
+    // 부모와 같은 시각에 삭제된 항목은 부모와 함께 표시되므로 제외
+    let book_rows = sqlx::query(
+        "SELECT b.id, b.title, b.parent_id, parent.title, b.deleted_at
+         FROM Book b
+         LEFT JOIN Book parent ON parent.id = b.parent_id
+         WHERE b.deleted_at IS NOT NULL
+           AND (parent.deleted_at IS NULL OR parent.deleted_at != b.deleted_at)",
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut items = Vec::new();
+    for row in book_rows {
+        let id: i32 = row.get(0);
+        let deleted_at: String = row.get(4);
+        let counts = sqlx::query(&format!(
+            "{} SELECT COUNT(DISTINCT Page.id), COUNT(Node.id)
+             FROM Page
+             LEFT JOIN Node ON Node.fk_page_id = Page.id
+             WHERE Page.fk_book_id IN (SELECT id FROM tree) AND Page.deleted_at = ?",
+            BOOK_TREE
+        ))
+        .bind(id)
+        .bind(&deleted_at)
+        .fetch_one(pool)
+        .await?;
+        items.push(TrashItem {
+            kind: TrashKind::Book,
+            id,
+            title: row.get(1),
+            parent_id: row.get(2),
+            parent_title: row.get(3),
+            deleted_at,
+            pages: counts.get(0),
+            nodes: counts.get(1),
+        });
+    }
+
+    let page_rows = sqlx::query(
+        "SELECT p.id, p.title, p.fk_book_id, b.title, p.deleted_at,
+                (SELECT COUNT(*) FROM Node WHERE Node.fk_page_id = p.id)
+         FROM Page p
+         LEFT JOIN Book b ON b.id = p.fk_book_id
+         WHERE p.deleted_at IS NOT NULL
+           AND (b.deleted_at IS NULL OR b.deleted_at != p.deleted_at)",
+    )
+    .fetch_all(pool)
+    .await?;
+    items.extend(page_rows.iter().map(|row| TrashItem {
+        kind: TrashKind::Page,
+        id: row.get(0),
+        title: row.get(1),
+        parent_id: row.get(2),
+        parent_title: row.get(3),
+        deleted_at: row.get(4),
+        pages: 1,
+        nodes: row.get(5),
+    }));
+
+    // 최근에 삭제한 항목부터
+    items.sort_by(|a, b| b.deleted_at.cmp(&a.deleted_at));
+    Ok(items)
+}
+
+pub async fn list_trash() -> Result<Vec<TrashItem>, sqlx::Error> {
+    let pool = get_db_pool().await;
+    list_trash_with(&pool).await
+}
+
+/// 북과 함께 삭제된 하위 북/페이지를 복원
+/// 상위 북이 아직 휴지통에 있으면 최상위로 옮겨서 복원
+pub async fn restore_book_with(pool: &Pool<Sqlite>, book_id: i32) -> Result<(), String> {
+    let map_err = |e: sqlx::Error| format!("Failed to restore book {}: {}", book_id, e);
+    let mut tx = pool.begin().await.map_err(map_err)?;
+
+    let row = sqlx::query(
+        "SELECT b.deleted_at, parent.deleted_at IS NOT NULL
+         FROM Book b LEFT JOIN Book parent ON parent.id = b.parent_id
+         WHERE b.id = ?",
+    )
+    .bind(book_id)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(map_err)?
+    .ok_or_else(|| format!("Book {} not found", book_id))?;
+    let Some(deleted_at) = row.get::<Option<String>, _>(0) else {
+        return Err(format!("Book {} is not in the trash", book_id));
+    };
+    let parent_trashed: bool = row.get(1);
+
+    let selection = Selection::BookTree(book_id);
+    let before = journal::capture(&mut tx, &selection).await.map_err(map_err)?;
+    if parent_trashed {
+        sqlx::query("UPDATE Book SET parent_id = NULL WHERE id = ?")
+            .bind(book_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(map_err)?;
+    }
+    for table in ["Book", "Page"] {
+        let column = if table == "Book" { "id" } else { "fk_book_id" };
+        sqlx::query(&format!(
+            "{} UPDATE {} SET deleted_at = NULL WHERE deleted_at = ? AND {} IN (SELECT id FROM tree)",
+            BOOK_TREE, table, column
+        ))
+        .bind(book_id)
+        .bind(&deleted_at)
+        .execute(&mut *tx)
+        .await
+        .map_err(map_err)?;
+    }
+    journal::record(&mut tx, JournalScope::Book(book_id), "restore_book", &selection, before)
+        .await
+        .map_err(map_err)?;
+    tx.commit().await.map_err(map_err)
+}
+
+pub async fn restore_book(book_id: i32) -> Result<(), String> {
+    let pool = get_db_pool().await;
+    restore_book_with(&pool, book_id).await
+}
+
+/// 페이지 복원 (소속 북이 휴지통에 있으면 북을 먼저 복원해야 함)
+pub async fn restore_page_with(pool: &Pool<Sqlite>, page_id: i32) -> Result<(), String> {
+    let map_err = |e: sqlx::Error| format!("Failed to restore page {}: {}", page_id, e);
+    let mut tx = pool.begin().await.map_err(map_err)?;
+
+    let row = sqlx::query(
+        "SELECT p.deleted_at, p.fk_book_id, b.deleted_at IS NOT NULL
+         FROM Page p LEFT JOIN Book b ON b.id = p.fk_book_id
+         WHERE p.id = ?",
+    )
+    .bind(page_id)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(map_err)?
+    .ok_or_else(|| format!("Page {} not found", page_id))?;
+    if row.get::<Option<String>, _>(0).is_none() {
+        return Err(format!("Page {} is not in the trash", page_id));
+    }
+    if row.get::<bool, _>(2) {
+        return Err(format!("Book {} of page {} is in the trash", row.get::<i32, _>(1), page_id));
+    }
+
+    let selection = Selection::PageRow(page_id);
+    let before = journal::capture(&mut tx, &selection).await.map_err(map_err)?;
+    sqlx::query("UPDATE Page SET deleted_at = NULL WHERE id = ?")
+        .bind(page_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(map_err)?;
+    journal::record(&mut tx, JournalScope::Page(page_id), "restore_page", &selection, before)
+        .await
+        .map_err(map_err)?;
+    tx.commit().await.map_err(map_err)
+}
+
+pub async fn restore_page(page_id: i32) -> Result<(), String> {
+    let pool = get_db_pool().await;
+    restore_page_with(&pool, page_id).await
+}
+
+/// 휴지통 영구 삭제 - older_than_days가 없으면 전체 비우기
+/// 삭제된 북/페이지의 되돌리기 기록도 함께 지움 (되돌리면 다시 살아나므로)
+pub async fn purge_with(pool: &Pool<Sqlite>, older_than_days: Option<i64>) -> Result<PurgeReport, sqlx::Error> {
+    let cutoff = match older_than_days {
+        Some(days) => timestamp(chrono::Utc::now() - chrono::Duration::days(days.max(0))),
+        None => "9999".to_string(),
+    };
+    let mut tx = pool.begin().await?;
+
+    let book_ids: Vec<i32> = sqlx::query_scalar("SELECT id FROM Book WHERE deleted_at < ?")
+        .bind(&cutoff)
+        .fetch_all(&mut *tx)
+        .await?;
+    let page_ids: Vec<i32> = sqlx::query_scalar(
+        "SELECT id FROM Page WHERE deleted_at < ? OR fk_book_id IN (SELECT id FROM Book WHERE deleted_at < ?)",
+    )
+    .bind(&cutoff)
+    .bind(&cutoff)
+    .fetch_all(&mut *tx)
+    .await?;
+
+    // 노드/엣지/버전은 페이지의 CASCADE로 삭제
+    for page_id in &page_ids {
+        sqlx::query("DELETE FROM Page WHERE id = ?").bind(page_id).execute(&mut *tx).await?;
+    }
+    sqlx::query("DELETE FROM Book WHERE deleted_at < ?")
+        .bind(&cutoff)
+        .execute(&mut *tx)
+        .await?;
+
+    let scopes = book_ids
+        .iter()
+        .map(|id| JournalScope::Book(*id))
+        .chain(page_ids.iter().map(|id| JournalScope::Page(*id)));
+    for scope in scopes {
+        sqlx::query("DELETE FROM OperationLog WHERE scope = ?")
+            .bind(scope.key())
+            .execute(&mut *tx)
+            .await?;
+    }
+    tx.commit().await?;
+
+    Ok(PurgeReport { books: book_ids.len() as u64, pages: page_ids.len() as u64 })
+}
+
+pub async fn purge(older_than_days: Option<i64>) -> Result<PurgeReport, sqlx::Error> {
+    let pool = get_db_pool().await;
+    purge_with(&pool, older_than_days).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::database::{migration, search};
+
+    #[test]
+    fn test_trash_restore_and_purge() {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let pool = migration::empty_test_pool().await;
+                sqlx::query("INSERT INTO Book (id, title, parent_id) VALUES (1, 'Shop', NULL), (2, 'Orders', 1)")
+                    .execute(&pool)
+                    .await
+                    .unwrap();
+                sqlx::query("INSERT INTO Page (id, fk_book_id, title) VALUES (10, 1, 'Login'), (11, 2, 'Checkout'), (12, 2, 'Refund')")
+                    .execute(&pool)
+                    .await
+                    .unwrap();
+                sqlx::query("INSERT INTO Node VALUES ('n1', 11, '{\"url\": \"/checkout\"}', 'apiNode', 0, 0)")
+                    .execute(&pool)
+                    .await
+                    .unwrap();
+
+                // 페이지를 먼저 지우고, 나중에 북 전체를 지움
+                let mut conn = pool.acquire().await.unwrap();
+                trash_page(&mut conn, 12).await.unwrap();
+                trash_book(&mut conn, 1).await.unwrap();
+                drop(conn);
+
+                assert_eq!(trashed_page_title_with(&pool, 12).await.unwrap().as_deref(), Some("Refund"));
+                // 페이지 행은 그대로여도 상위 북이 휴지통에 있으면 휴지통에 있는 것으로 봄
+                sqlx::query("UPDATE Page SET deleted_at = NULL WHERE id = 11").execute(&pool).await.unwrap();
+                assert_eq!(trashed_page_title_with(&pool, 11).await.unwrap().as_deref(), Some("Checkout"));
+                sqlx::query("UPDATE Page SET deleted_at = (SELECT deleted_at FROM Book WHERE id = 1) WHERE id = 11")
+                    .execute(&pool)
+                    .await
+                    .unwrap();
+
+                let items = list_trash_with(&pool).await.unwrap();
+                assert_eq!(items.len(), 2);
+                assert_eq!((items[0].kind, items[0].id, items[0].pages, items[0].nodes), (TrashKind::Book, 1, 2, 1));
+                assert_eq!((items[1].kind, items[1].id), (TrashKind::Page, 12));
+                assert!(search::search_with(&pool, "checkout", None).await.unwrap().is_empty());
+
+                assert!(restore_page_with(&pool, 12).await.is_err());
+                restore_book_with(&pool, 1).await.unwrap();
+                let live: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM Page WHERE deleted_at IS NULL")
+                    .fetch_one(&pool)
+                    .await
+                    .unwrap();
+                assert_eq!(live, 2);
+                assert_eq!(trashed_page_title_with(&pool, 11).await.unwrap(), None);
+                assert_eq!(search::search_with(&pool, "checkout", None).await.unwrap().len(), 2);
+
+                // 따로 지운 페이지는 휴지통에 남음 → 기간 안이면 유지, 비우기로 삭제
+                assert_eq!(purge_with(&pool, Some(DEFAULT_RETENTION_DAYS)).await.unwrap().pages, 0);
+                let report = purge_with(&pool, None).await.unwrap();
+                assert_eq!((report.books, report.pages), (0, 1));
+                assert!(list_trash_with(&pool).await.unwrap().is_empty());
+                let pages: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM Page").fetch_one(&pool).await.unwrap();
+                assert_eq!(pages, 2);
+            });
+    }
+}
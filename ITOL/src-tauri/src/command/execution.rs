@@ -2,3 +2,10 @@ pub mod node_system;
 pub mod api_system;
 pub mod db_system;
 pub mod request;
+pub mod db_schema;
+pub mod db_result;
+pub mod ssh_tunnel;
+pub mod oracle_net;
+pub mod oracle_plsql;
+pub mod flow_runner;
+pub mod flow_report;
@@ -0,0 +1,242 @@
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// 전체 결과를 파일로 저장할 때의 형식
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ResultFileFormat {
+    Ndjson,
+    Csv,
+}
+
+impl ResultFileFormat {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.to_ascii_lowercase().as_str() {
+            "ndjson" | "jsonl" => Ok(ResultFileFormat::Ndjson),
+            "csv" => Ok(ResultFileFormat::Csv),
+            other => Err(format!("Unsupported result file format: {}", other)),
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ResultFileFormat::Ndjson => "ndjson",
+            ResultFileFormat::Csv => "csv",
+        }
+    }
+}
+
+enum ResultWriter {
+    Ndjson(BufWriter<File>),
+    Csv {
+        writer: Box<csv::Writer<File>>,
+        header_written: bool,
+    },
+}
+
+impl ResultWriter {
+    fn create(format: ResultFileFormat, path: &Path) -> Result<Self, String> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create result directory: {}", e))?;
+        }
+        let file = File::create(path)
+            .map_err(|e| format!("Failed to create result file: {}", e))?;
+        Ok(match format {
+            ResultFileFormat::Ndjson => ResultWriter::Ndjson(BufWriter::new(file)),
+            ResultFileFormat::Csv => ResultWriter::Csv {
+                writer: Box::new(csv::Writer::from_writer(file)),
+                header_written: false,
+            },
+        })
+    }
+}
+
+/// 노드 이름을 결과 파일 이름으로 쓸 수 있게 정리 (경로 구분자/예약 문자 → '_')
+pub fn result_file_stem(node_name: &str) -> String {
+    let stem: String = node_name
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .collect();
+    // "."/".."나 끝의 점/공백(Windows에서 무시됨)으로 다른 파일을 가리키지 않도록 정리
+    let stem = stem.trim().trim_matches('.').trim();
+    if stem.is_empty() {
+        "db_result".to_string()
+    } else {
+        stem.to_string()
+    }
+}
+
+/// 스트리밍으로 받은 행을 max_rows까지 메모리에 보관하고, 설정된 경우 전체 행을 파일에 기록
+///
+/// 결과 파일은 첫 행을 받거나 쿼리가 성공적으로 끝났을 때 만들어지므로
+/// 연결 실패나 쿼리 오류로는 빈 파일이 남지 않는다.
+pub struct ResultSink {
+    max_rows: usize,
+    rows: Vec<HashMap<String, serde_json::Value>>,
+    total_rows: usize,
+    output: Option<(ResultFileFormat, PathBuf)>,
+    writer: Option<ResultWriter>,
+}
+
+impl ResultSink {
+    pub fn new(max_rows: Option<i32>, output: Option<(ResultFileFormat, PathBuf)>) -> Self {
+        ResultSink {
+            max_rows: max_rows.unwrap_or(1000).max(0) as usize,
+            rows: Vec::new(),
+            total_rows: 0,
+            output,
+            writer: None,
+        }
+    }
+
+    fn writer(&mut self) -> Result<Option<&mut ResultWriter>, String> {
+        if self.writer.is_none() {
+            if let Some((format, path)) = &self.output {
+                self.writer = Some(ResultWriter::create(*format, path)?);
+            }
+        }
+        Ok(self.writer.as_mut())
+    }
+
+    /// 더 읽을 필요가 있는지 여부
+    ///
+    /// 파일 저장이 없으면 잘림 여부만 판단하면 되므로 max_rows + 1 행에서 멈춘다.
+    pub fn wants_more(&self) -> bool {
+        self.output.is_some() || self.total_rows <= self.max_rows
+    }
+
+    /// 컬럼 순서를 유지한 한 행을 추가
+    pub fn push(&mut self, row: Vec<(String, serde_json::Value)>) -> Result<(), String> {
+        self.total_rows += 1;
+
+        if let Some(writer) = self.writer()? {
+            write_row(writer, &row)?;
+        }
+
+        if self.rows.len() < self.max_rows {
+            self.rows.push(row.into_iter().collect());
+        }
+        Ok(())
+    }
+
+    /// 파일을 닫고 노드 결과 JSON 문자열 생성
+    pub fn finish(mut self) -> Result<String, String> {
+        let truncated = self.total_rows > self.max_rows;
+        let mut response = serde_json::json!({
+            "success": true,
+            "rowCount": self.rows.len(),
+            "data": self.rows,
+            "truncated": truncated,
+        });
+
+        // 결과가 0행이어도 성공한 쿼리는 빈 결과 파일을 남김
+        self.writer()?;
+        if let (Some((format, path)), Some(writer)) = (self.output, self.writer) {
+            match writer {
+                ResultWriter::Ndjson(mut out) => out.flush(),
+                ResultWriter::Csv { mut writer, .. } => writer.flush(),
+            }
+            .map_err(|e| format!("Failed to write result file: {}", e))?;
+
+            println!("Saved {} rows to {}", self.total_rows, path.display());
+
+            response["totalRows"] = serde_json::json!(self.total_rows);
+            response["outputFile"] = serde_json::json!(path.display().to_string());
+            response["outputFormat"] = serde_json::json!(format.extension());
+        }
+
+        Ok(response.to_string())
+    }
+}
+
+fn write_row(writer: &mut ResultWriter, row: &[(String, serde_json::Value)]) -> Result<(), String> {
+    match writer {
+        ResultWriter::Ndjson(out) => {
+            let object: serde_json::Map<String, serde_json::Value> = row.iter().cloned().collect();
+            serde_json::to_writer(&mut *out, &object)
+                .map_err(|e| format!("Failed to write result file: {}", e))?;
+            out.write_all(b"\n")
+                .map_err(|e| format!("Failed to write result file: {}", e))
+        }
+        ResultWriter::Csv { writer, header_written } => {
+            // 첫 행의 컬럼 순서를 헤더로 사용
+            if !*header_written {
+                writer
+                    .write_record(row.iter().map(|(name, _)| name.as_str()))
+                    .map_err(|e| format!("Failed to write result file: {}", e))?;
+                *header_written = true;
+            }
+
+            let record = row.iter().map(|(_, value)| match value {
+                serde_json::Value::Null => String::new(),
+                serde_json::Value::String(s) => s.clone(),
+                other => other.to_string(),
+            });
+            writer
+                .write_record(record)
+                .map_err(|e| format!("Failed to write result file: {}", e))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn row(id: i64, name: &str) -> Vec<(String, serde_json::Value)> {
+        vec![("id".to_string(), json!(id)), ("name".to_string(), json!(name))]
+    }
+
+    #[test]
+    fn test_inline_only_stops_after_max_rows() {
+        let mut sink = ResultSink::new(Some(2), None);
+        let mut read = 0;
+        while sink.wants_more() {
+            sink.push(row(read, "a")).unwrap();
+            read += 1;
+        }
+        assert_eq!(read, 3);
+
+        let response: serde_json::Value = serde_json::from_str(&sink.finish().unwrap()).unwrap();
+        assert_eq!(response["rowCount"], json!(2));
+        assert_eq!(response["truncated"], json!(true));
+        assert!(response.get("outputFile").is_none());
+    }
+
+    #[test]
+    fn test_full_result_written_to_csv() {
+        let path = std::env::temp_dir()
+            .join(format!("itol_result_{}", std::process::id()))
+            .join("node.csv");
+        let mut sink = ResultSink::new(Some(1), Some((ResultFileFormat::Csv, path.clone())));
+        // 첫 행을 받기 전에는 파일을 만들지 않음
+        assert!(!path.exists());
+        sink.push(row(1, "plain")).unwrap();
+        sink.push(row(2, "with, \"comma\"")).unwrap();
+        assert!(sink.wants_more());
+
+        let response: serde_json::Value = serde_json::from_str(&sink.finish().unwrap()).unwrap();
+        assert_eq!(response["rowCount"], json!(1));
+        assert_eq!(response["totalRows"], json!(2));
+        assert_eq!(response["outputFile"], json!(path.display().to_string()));
+
+        let written = fs::read_to_string(&path).unwrap();
+        assert_eq!(written, "id,name\n1,plain\n2,\"with, \"\"comma\"\"\"\n");
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn test_result_file_stem_strips_path_and_reserved_chars() {
+        assert_eq!(result_file_stem("orders/../../etc"), "orders_.._.._etc");
+        assert_eq!(result_file_stem("a:b*c?\"d<e>f|g\\h"), "a_b_c__d_e_f_g_h");
+        assert_eq!(result_file_stem(" .. "), "db_result");
+        assert_eq!(result_file_stem("고객 목록."), "고객 목록");
+    }
+}
@@ -0,0 +1,872 @@
+use serde::{Deserialize, Serialize};
+use sqlx::{Column, Row};
+use std::collections::HashMap;
+
+use super::db_system::{self, DatabaseConnection};
+use super::oracle_net::OracleConnectTarget;
+use super::ssh_tunnel;
+
+#[derive(Debug, Deserialize)]
+pub struct SchemaIntrospectionParams {
+    pub connection: DatabaseConnection,
+    pub schema: Option<String>,
+    pub table: Option<String>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct TableInfo {
+    pub schema: Option<String>,
+    pub name: String,
+    /// "TABLE" 또는 "VIEW"
+    pub table_type: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ColumnInfo {
+    pub schema: Option<String>,
+    pub table: String,
+    pub name: String,
+    pub data_type: String,
+    pub nullable: bool,
+    pub default_value: Option<String>,
+    pub ordinal_position: i32,
+    pub is_primary_key: bool,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct IndexInfo {
+    pub name: String,
+    pub columns: Vec<String>,
+    pub is_unique: bool,
+    pub is_primary: bool,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ForeignKeyInfo {
+    pub name: String,
+    pub columns: Vec<String>,
+    pub referenced_schema: Option<String>,
+    pub referenced_table: String,
+    pub referenced_columns: Vec<String>,
+    pub on_update: Option<String>,
+    pub on_delete: Option<String>,
+}
+
+/// 메타데이터 쿼리 결과 한 행 (소문자 컬럼 별칭 -> 텍스트 값)
+type MetaRow = HashMap<String, Option<String>>;
+
+#[derive(Debug, Clone, Copy)]
+enum MetadataKind {
+    Schemas,
+    Tables,
+    Columns,
+    Indexes,
+    ForeignKeys,
+}
+
+/// 스키마(또는 SQLite의 attach된 데이터베이스) 목록 조회
+pub async fn list_schemas(connection: DatabaseConnection) -> Result<Vec<String>, String> {
+    let rows = fetch_metadata(&connection, MetadataKind::Schemas, None, None).await?;
+    Ok(rows.iter().filter_map(|row| text(row, "schema_name")).collect())
+}
+
+/// 테이블/뷰 목록 조회 (schema가 없으면 현재 기본 스키마)
+pub async fn list_tables(
+    connection: DatabaseConnection,
+    schema: Option<String>,
+) -> Result<Vec<TableInfo>, String> {
+    let rows = fetch_metadata(&connection, MetadataKind::Tables, schema.as_deref(), None).await?;
+    Ok(rows
+        .iter()
+        .filter_map(|row| {
+            Some(TableInfo {
+                schema: text(row, "schema_name"),
+                name: text(row, "table_name")?,
+                table_type: text(row, "table_type").unwrap_or_else(|| "TABLE".to_string()),
+            })
+        })
+        .collect())
+}
+
+/// 테이블 컬럼과 타입 조회
+pub async fn list_columns(
+    connection: DatabaseConnection,
+    schema: Option<String>,
+    table: String,
+) -> Result<Vec<ColumnInfo>, String> {
+    let rows =
+        fetch_metadata(&connection, MetadataKind::Columns, schema.as_deref(), Some(&table)).await?;
+    Ok(rows
+        .iter()
+        .filter_map(|row| {
+            Some(ColumnInfo {
+                schema: text(row, "schema_name"),
+                table: text(row, "table_name").unwrap_or_else(|| table.clone()),
+                name: text(row, "column_name")?,
+                data_type: text(row, "data_type").unwrap_or_default(),
+                nullable: flag(row, "is_nullable"),
+                default_value: text(row, "column_default"),
+                ordinal_position: text(row, "ordinal_position")
+                    .and_then(|v| v.parse().ok())
+                    .unwrap_or(0),
+                is_primary_key: flag(row, "is_primary_key"),
+            })
+        })
+        .collect())
+}
+
+/// 테이블 인덱스 조회 (인덱스 단위로 컬럼을 묶어서 반환)
+pub async fn list_indexes(
+    connection: DatabaseConnection,
+    schema: Option<String>,
+    table: String,
+) -> Result<Vec<IndexInfo>, String> {
+    let rows =
+        fetch_metadata(&connection, MetadataKind::Indexes, schema.as_deref(), Some(&table)).await?;
+
+    let mut indexes: Vec<IndexInfo> = Vec::new();
+    for row in &rows {
+        let Some(name) = text(row, "index_name") else { continue };
+        let column = text(row, "column_name");
+
+        match indexes.iter_mut().find(|index| index.name == name) {
+            Some(index) => index.columns.extend(column),
+            None => indexes.push(IndexInfo {
+                name,
+                columns: column.into_iter().collect(),
+                is_unique: flag(row, "is_unique"),
+                is_primary: flag(row, "is_primary"),
+            }),
+        }
+    }
+    Ok(indexes)
+}
+
+/// 테이블 외래 키 조회 (제약 조건 단위로 컬럼을 묶어서 반환)
+pub async fn list_foreign_keys(
+    connection: DatabaseConnection,
+    schema: Option<String>,
+    table: String,
+) -> Result<Vec<ForeignKeyInfo>, String> {
+    let rows = fetch_metadata(&connection, MetadataKind::ForeignKeys, schema.as_deref(), Some(&table))
+        .await?;
+
+    let mut foreign_keys: Vec<ForeignKeyInfo> = Vec::new();
+    for row in &rows {
+        let Some(name) = text(row, "constraint_name") else { continue };
+        let column = text(row, "column_name");
+        let referenced_column = text(row, "referenced_column");
+
+        match foreign_keys.iter_mut().find(|fk| fk.name == name) {
+            Some(fk) => {
+                fk.columns.extend(column);
+                fk.referenced_columns.extend(referenced_column);
+            }
+            None => foreign_keys.push(ForeignKeyInfo {
+                name,
+                columns: column.into_iter().collect(),
+                referenced_schema: text(row, "referenced_schema"),
+                referenced_table: text(row, "referenced_table").unwrap_or_default(),
+                referenced_columns: referenced_column.into_iter().collect(),
+                on_update: text(row, "on_update"),
+                on_delete: text(row, "on_delete"),
+            }),
+        }
+    }
+    Ok(foreign_keys)
+}
+
+fn text(row: &MetaRow, key: &str) -> Option<String> {
+    row.get(key).cloned().flatten()
+}
+
+/// DB마다 다른 불리언 표현('YES', 'Y', '1', 't' 등)을 정규화
+fn flag(row: &MetaRow, key: &str) -> bool {
+    matches!(
+        text(row, key).map(|v| v.to_ascii_lowercase()).as_deref(),
+        Some("1" | "y" | "yes" | "t" | "true")
+    )
+}
+
+fn json_to_text(value: serde_json::Value) -> Option<String> {
+    match value {
+        serde_json::Value::Null => None,
+        serde_json::Value::String(s) => Some(s),
+        other => Some(other.to_string()),
+    }
+}
+
+/// 식별자를 큰따옴표로 감싸 SQL에 안전하게 삽입
+fn quote_identifier(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
+
+fn required_table(table: Option<&str>) -> Result<String, String> {
+    table
+        .map(str::to_string)
+        .ok_or_else(|| "Table name is required".to_string())
+}
+
+/// 연결 종류별 메타데이터 쿼리를 실행하고 결과를 텍스트 행으로 반환
+async fn fetch_metadata(
+    connection: &DatabaseConnection,
+    kind: MetadataKind,
+    schema: Option<&str>,
+    table: Option<&str>,
+) -> Result<Vec<MetaRow>, String> {
+    match &db_system::resolve_connection(connection.clone()).await? {
+        DatabaseConnection::Sqlite { file_path } => {
+            let path = file_path.clone().ok_or("SQLite file path is required")?;
+            let (sql, binds) = sqlite_statement(kind, schema, table)?;
+            fetch_sqlite(&path, &sql, binds).await
+        }
+        DatabaseConnection::PostgreSQL { host, port, database, username, password, tls, ssh_tunnel, .. } => {
+            let host = host.clone().ok_or("PostgreSQL host is required")?;
+            let port = port.unwrap_or(5432);
+            let (_tunnel, host, port) = ssh_tunnel::forward(ssh_tunnel.as_ref(), host, port).await?;
+            let database = database.clone().ok_or("Database name is required")?;
+            let username = username.clone().ok_or("Username is required")?;
+            let password = password.clone().ok_or("Password is required")?;
+
+            let options = db_system::postgresql_connect_options(
+                &host, port, &database, &username, &password, tls,
+            )?;
+            let (sql, binds) = postgresql_statement(kind, schema, table)?;
+            fetch_postgresql(options, sql, binds).await
+        }
+        oracle @ DatabaseConnection::Oracle { .. } => {
+            let (_tunnel, target) = db_system::oracle_connect_target(oracle).await?;
+            let (sql, binds) = oracle_statement(kind, schema, table)?;
+            fetch_oracle(target, sql, binds).await
+        }
+        DatabaseConnection::MySql { host, port, database, username, password, ssh_tunnel } => {
+            let host = host.clone().ok_or("MySQL host is required")?;
+            let port = port.unwrap_or(3306);
+            let (_tunnel, host, port) = ssh_tunnel::forward(ssh_tunnel.as_ref(), host, port).await?;
+            let database = database.clone().ok_or("Database name is required")?;
+            let username = username.clone().ok_or("Username is required")?;
+            let password = password.clone().ok_or("Password is required")?;
+
+            let options =
+                db_system::mysql_connect_options(&host, port, &database, &username, &password);
+            let (sql, binds) = mysql_statement(kind, schema, table)?;
+            fetch_mysql(options, sql, binds).await
+        }
+        DatabaseConnection::SqlServer {
+            host, port, instance_name, database, username, password, trust_server_certificate
+        } => {
+            let host = host.clone().ok_or("SQL Server host is required")?;
+            let database = database.clone().ok_or("Database name is required")?;
+            let username = username.clone().ok_or("Username is required")?;
+            let password = password.clone().ok_or("Password is required")?;
+
+            let config = db_system::sqlserver_config(
+                &host,
+                *port,
+                instance_name.as_deref(),
+                &database,
+                &username,
+                &password,
+                trust_server_certificate.unwrap_or(false),
+            );
+            let (sql, binds) = sqlserver_statement(kind, schema, table)?;
+            fetch_sqlserver(config, sql, binds).await
+        }
+        DatabaseConnection::Profile { .. } => Err("Connection profile was not resolved".to_string()),
+    }
+}
+
+// ==================== SQLite (sqlite_master / PRAGMA) ====================
+
+fn sqlite_statement(
+    kind: MetadataKind,
+    schema: Option<&str>,
+    table: Option<&str>,
+) -> Result<(String, Vec<Option<String>>), String> {
+    let schema = schema.unwrap_or("main").to_string();
+
+    let statement = match kind {
+        MetadataKind::Schemas => ("SELECT name AS schema_name FROM pragma_database_list ORDER BY seq".to_string(), vec![]),
+        MetadataKind::Tables => (
+            format!(
+                "SELECT ?1 AS schema_name, name AS table_name, UPPER(type) AS table_type
+                 FROM {}.sqlite_master
+                 WHERE type IN ('table', 'view') AND name NOT LIKE 'sqlite_%'
+                 ORDER BY name",
+                quote_identifier(&schema)
+            ),
+            vec![Some(schema)],
+        ),
+        MetadataKind::Columns => (
+            "SELECT ?2 AS schema_name, ?1 AS table_name, name AS column_name, type AS data_type,
+                    CASE WHEN \"notnull\" = 0 THEN 1 ELSE 0 END AS is_nullable,
+                    dflt_value AS column_default, cid + 1 AS ordinal_position,
+                    CASE WHEN pk > 0 THEN 1 ELSE 0 END AS is_primary_key
+             FROM pragma_table_info(?1, ?2)
+             ORDER BY cid"
+                .to_string(),
+            vec![Some(required_table(table)?), Some(schema)],
+        ),
+        MetadataKind::Indexes => (
+            "SELECT il.name AS index_name, ii.name AS column_name, il.\"unique\" AS is_unique,
+                    CASE WHEN il.origin = 'pk' THEN 1 ELSE 0 END AS is_primary
+             FROM pragma_index_list(?1, ?2) il
+             JOIN pragma_index_info(il.name, ?2) ii
+             ORDER BY il.name, ii.seqno"
+                .to_string(),
+            vec![Some(required_table(table)?), Some(schema)],
+        ),
+        MetadataKind::ForeignKeys => (
+            "SELECT 'fk_' || id AS constraint_name, \"from\" AS column_name, ?2 AS referenced_schema,
+                    \"table\" AS referenced_table, \"to\" AS referenced_column, on_update, on_delete
+             FROM pragma_foreign_key_list(?1, ?2)
+             ORDER BY id, seq"
+                .to_string(),
+            vec![Some(required_table(table)?), Some(schema)],
+        ),
+    };
+    Ok(statement)
+}
+
+async fn fetch_sqlite(
+    file_path: &str,
+    sql: &str,
+    binds: Vec<Option<String>>,
+) -> Result<Vec<MetaRow>, String> {
+    let pool = sqlx::sqlite::SqlitePool::connect(&format!("sqlite:{}", file_path))
+        .await
+        .map_err(|e| format!("Failed to connect to SQLite: {}", e))?;
+
+    let mut query = sqlx::query(sql);
+    for bind in binds {
+        query = query.bind(bind);
+    }
+    let rows = query
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| format!("Metadata query failed: {}", e))?;
+    pool.close().await;
+
+    Ok(rows
+        .iter()
+        .map(|row| {
+            row.columns()
+                .iter()
+                .map(|col| {
+                    let idx = col.ordinal();
+                    let value = if let Ok(val) = row.try_get::<Option<String>, _>(idx) {
+                        val
+                    } else if let Ok(val) = row.try_get::<Option<i64>, _>(idx) {
+                        val.map(|v| v.to_string())
+                    } else {
+                        None
+                    };
+                    (col.name().to_lowercase(), value)
+                })
+                .collect()
+        })
+        .collect())
+}
+
+// ==================== PostgreSQL (information_schema / pg_catalog) ====================
+
+fn postgresql_statement(
+    kind: MetadataKind,
+    schema: Option<&str>,
+    table: Option<&str>,
+) -> Result<(&'static str, Vec<Option<String>>), String> {
+    let schema = schema.map(str::to_string);
+
+    let statement = match kind {
+        MetadataKind::Schemas => (
+            "SELECT schema_name::text AS schema_name
+             FROM information_schema.schemata
+             WHERE schema_name NOT IN ('pg_catalog', 'information_schema')
+               AND schema_name NOT LIKE 'pg\\_toast%' AND schema_name NOT LIKE 'pg\\_temp%'
+             ORDER BY schema_name",
+            vec![],
+        ),
+        MetadataKind::Tables => (
+            "SELECT table_schema::text AS schema_name, table_name::text AS table_name,
+                    CASE WHEN table_type = 'VIEW' THEN 'VIEW' ELSE 'TABLE' END AS table_type
+             FROM information_schema.tables
+             WHERE table_schema = COALESCE($1::text, current_schema())
+             ORDER BY table_name",
+            vec![schema],
+        ),
+        MetadataKind::Columns => (
+            "SELECT c.table_schema::text AS schema_name, c.table_name::text AS table_name,
+                    c.column_name::text AS column_name,
+                    CASE WHEN c.data_type IN ('USER-DEFINED', 'ARRAY') THEN c.udt_name::text
+                         ELSE c.data_type::text END AS data_type,
+                    c.is_nullable::text AS is_nullable, c.column_default::text AS column_default,
+                    c.ordinal_position::text AS ordinal_position,
+                    CASE WHEN pk.column_name IS NULL THEN 'NO' ELSE 'YES' END AS is_primary_key
+             FROM information_schema.columns c
+             LEFT JOIN (
+                 SELECT kcu.table_schema, kcu.table_name, kcu.column_name
+                 FROM information_schema.table_constraints tc
+                 JOIN information_schema.key_column_usage kcu
+                   ON kcu.constraint_name = tc.constraint_name
+                  AND kcu.constraint_schema = tc.constraint_schema
+                 WHERE tc.constraint_type = 'PRIMARY KEY'
+             ) pk ON pk.table_schema = c.table_schema
+                 AND pk.table_name = c.table_name
+                 AND pk.column_name = c.column_name
+             WHERE c.table_schema = COALESCE($1::text, current_schema()) AND c.table_name = $2
+             ORDER BY c.ordinal_position",
+            vec![schema, Some(required_table(table)?)],
+        ),
+        MetadataKind::Indexes => (
+            "SELECT i.relname::text AS index_name, a.attname::text AS column_name,
+                    ix.indisunique::text AS is_unique, ix.indisprimary::text AS is_primary
+             FROM pg_catalog.pg_index ix
+             JOIN pg_catalog.pg_class t ON t.oid = ix.indrelid
+             JOIN pg_catalog.pg_class i ON i.oid = ix.indexrelid
+             JOIN pg_catalog.pg_namespace n ON n.oid = t.relnamespace
+             JOIN LATERAL unnest(ix.indkey) WITH ORDINALITY AS k(attnum, ord) ON true
+             JOIN pg_catalog.pg_attribute a ON a.attrelid = t.oid AND a.attnum = k.attnum
+             WHERE n.nspname = COALESCE($1::text, current_schema()) AND t.relname = $2
+             ORDER BY i.relname, k.ord",
+            vec![schema, Some(required_table(table)?)],
+        ),
+        MetadataKind::ForeignKeys => (
+            "SELECT c.conname::text AS constraint_name, a.attname::text AS column_name,
+                    rn.nspname::text AS referenced_schema, rt.relname::text AS referenced_table,
+                    ra.attname::text AS referenced_column,
+                    CASE c.confupdtype WHEN 'c' THEN 'CASCADE' WHEN 'n' THEN 'SET NULL'
+                         WHEN 'd' THEN 'SET DEFAULT' WHEN 'r' THEN 'RESTRICT' ELSE 'NO ACTION' END AS on_update,
+                    CASE c.confdeltype WHEN 'c' THEN 'CASCADE' WHEN 'n' THEN 'SET NULL'
+                         WHEN 'd' THEN 'SET DEFAULT' WHEN 'r' THEN 'RESTRICT' ELSE 'NO ACTION' END AS on_delete
+             FROM pg_catalog.pg_constraint c
+             JOIN pg_catalog.pg_class t ON t.oid = c.conrelid
+             JOIN pg_catalog.pg_namespace n ON n.oid = t.relnamespace
+             JOIN pg_catalog.pg_class rt ON rt.oid = c.confrelid
+             JOIN pg_catalog.pg_namespace rn ON rn.oid = rt.relnamespace
+             JOIN LATERAL unnest(c.conkey, c.confkey) WITH ORDINALITY AS k(attnum, refattnum, ord) ON true
+             JOIN pg_catalog.pg_attribute a ON a.attrelid = c.conrelid AND a.attnum = k.attnum
+             JOIN pg_catalog.pg_attribute ra ON ra.attrelid = c.confrelid AND ra.attnum = k.refattnum
+             WHERE c.contype = 'f'
+               AND n.nspname = COALESCE($1::text, current_schema()) AND t.relname = $2
+             ORDER BY c.conname, k.ord",
+            vec![schema, Some(required_table(table)?)],
+        ),
+    };
+    Ok(statement)
+}
+
+async fn fetch_postgresql(
+    options: sqlx::postgres::PgConnectOptions,
+    sql: &str,
+    binds: Vec<Option<String>>,
+) -> Result<Vec<MetaRow>, String> {
+    let pool = sqlx::postgres::PgPool::connect_with(options)
+        .await
+        .map_err(|e| format!("Failed to connect to PostgreSQL: {}", e))?;
+
+    let mut query = sqlx::query(sql);
+    for bind in binds {
+        query = query.bind(bind);
+    }
+    let rows = query
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| format!("Metadata query failed: {}", e))?;
+    pool.close().await;
+
+    Ok(rows
+        .iter()
+        .map(|row| {
+            row.columns()
+                .iter()
+                .map(|col| {
+                    let value = row.try_get::<Option<String>, _>(col.ordinal()).ok().flatten();
+                    (col.name().to_lowercase(), value)
+                })
+                .collect()
+        })
+        .collect())
+}
+
+// ==================== Oracle (ALL_* dictionary views) ====================
+
+/// Oracle 위치 바인드는 등장 순서대로 값이 필요하므로 동일한 값도 반복해서 전달한다.
+fn oracle_statement(
+    kind: MetadataKind,
+    schema: Option<&str>,
+    table: Option<&str>,
+) -> Result<(&'static str, Vec<Option<String>>), String> {
+    let schema = schema.map(str::to_string);
+
+    let statement = match kind {
+        MetadataKind::Schemas => (
+            "SELECT username AS schema_name FROM all_users ORDER BY username",
+            vec![],
+        ),
+        MetadataKind::Tables => (
+            "SELECT owner AS schema_name, table_name, 'TABLE' AS table_type
+             FROM all_tables WHERE owner = NVL(:1, USER)
+             UNION ALL
+             SELECT owner, view_name, 'VIEW' FROM all_views WHERE owner = NVL(:2, USER)
+             ORDER BY 2",
+            vec![schema.clone(), schema],
+        ),
+        MetadataKind::Columns => (
+            "SELECT c.owner AS schema_name, c.table_name, c.column_name,
+                    CASE WHEN c.data_precision IS NOT NULL
+                              THEN c.data_type || '(' || c.data_precision || ',' || NVL(c.data_scale, 0) || ')'
+                         WHEN c.data_type IN ('VARCHAR2', 'NVARCHAR2', 'CHAR', 'NCHAR', 'RAW')
+                              THEN c.data_type || '(' || c.char_length || ')'
+                         ELSE c.data_type END AS data_type,
+                    c.nullable AS is_nullable, c.data_default AS column_default,
+                    c.column_id AS ordinal_position,
+                    CASE WHEN EXISTS (
+                        SELECT 1 FROM all_constraints pc
+                        JOIN all_cons_columns pcc
+                          ON pcc.owner = pc.owner AND pcc.constraint_name = pc.constraint_name
+                        WHERE pc.constraint_type = 'P' AND pc.owner = c.owner
+                          AND pc.table_name = c.table_name AND pcc.column_name = c.column_name
+                    ) THEN 'Y' ELSE 'N' END AS is_primary_key
+             FROM all_tab_columns c
+             WHERE c.owner = NVL(:1, USER) AND c.table_name = :2
+             ORDER BY c.column_id",
+            vec![schema, Some(required_table(table)?)],
+        ),
+        MetadataKind::Indexes => (
+            "SELECT i.index_name, ic.column_name,
+                    CASE WHEN i.uniqueness = 'UNIQUE' THEN 'Y' ELSE 'N' END AS is_unique,
+                    CASE WHEN EXISTS (
+                        SELECT 1 FROM all_constraints pc
+                        WHERE pc.constraint_type = 'P' AND pc.owner = i.table_owner
+                          AND pc.table_name = i.table_name AND pc.index_name = i.index_name
+                    ) THEN 'Y' ELSE 'N' END AS is_primary
+             FROM all_indexes i
+             JOIN all_ind_columns ic ON ic.index_owner = i.owner AND ic.index_name = i.index_name
+             WHERE i.table_owner = NVL(:1, USER) AND i.table_name = :2
+             ORDER BY i.index_name, ic.column_position",
+            vec![schema, Some(required_table(table)?)],
+        ),
+        MetadataKind::ForeignKeys => (
+            "SELECT c.constraint_name, cc.column_name, r.owner AS referenced_schema,
+                    r.table_name AS referenced_table, rc.column_name AS referenced_column,
+                    'NO ACTION' AS on_update, c.delete_rule AS on_delete
+             FROM all_constraints c
+             JOIN all_cons_columns cc ON cc.owner = c.owner AND cc.constraint_name = c.constraint_name
+             JOIN all_constraints r ON r.owner = c.r_owner AND r.constraint_name = c.r_constraint_name
+             JOIN all_cons_columns rc
+               ON rc.owner = r.owner AND rc.constraint_name = r.constraint_name
+              AND rc.position = cc.position
+             WHERE c.constraint_type = 'R' AND c.owner = NVL(:1, USER) AND c.table_name = :2
+             ORDER BY c.constraint_name, cc.position",
+            vec![schema, Some(required_table(table)?)],
+        ),
+    };
+    Ok(statement)
+}
+
+async fn fetch_oracle(
+    target: OracleConnectTarget,
+    sql: &'static str,
+    binds: Vec<Option<String>>,
+) -> Result<Vec<MetaRow>, String> {
+    // Oracle 드라이버는 동기 방식이므로 blocking task로 실행
+    tokio::task::spawn_blocking(move || {
+        let conn = target
+            .connect()
+            .map_err(|e| format!("Failed to connect to Oracle: {}", e))?;
+
+        let params: Vec<&dyn oracle::sql_type::ToSql> =
+            binds.iter().map(|b| b as &dyn oracle::sql_type::ToSql).collect();
+        let rows = conn
+            .query(sql, &params)
+            .map_err(|e| format!("Metadata query failed: {}", e))?;
+
+        let mut results = Vec::new();
+        for row_result in rows {
+            let row = row_result.map_err(|e| format!("Failed to fetch row: {}", e))?;
+            let mut meta_row = MetaRow::new();
+            for (col_idx, col_info) in row.column_info().iter().enumerate() {
+                let value = row.get::<usize, Option<String>>(col_idx).ok().flatten();
+                meta_row.insert(col_info.name().to_lowercase(), value);
+            }
+            results.push(meta_row);
+        }
+        Ok::<Vec<MetaRow>, String>(results)
+    })
+    .await
+    .map_err(|e| format!("Oracle task failed: {}", e))?
+}
+
+// ==================== MySQL / MariaDB (information_schema) ====================
+
+fn mysql_statement(
+    kind: MetadataKind,
+    schema: Option<&str>,
+    table: Option<&str>,
+) -> Result<(&'static str, Vec<Option<String>>), String> {
+    let schema = schema.map(str::to_string);
+
+    let statement = match kind {
+        MetadataKind::Schemas => (
+            "SELECT CAST(SCHEMA_NAME AS CHAR) AS schema_name
+             FROM information_schema.SCHEMATA
+             WHERE SCHEMA_NAME NOT IN ('mysql', 'information_schema', 'performance_schema', 'sys')
+             ORDER BY SCHEMA_NAME",
+            vec![],
+        ),
+        MetadataKind::Tables => (
+            "SELECT CAST(TABLE_SCHEMA AS CHAR) AS schema_name, CAST(TABLE_NAME AS CHAR) AS table_name,
+                    CASE WHEN TABLE_TYPE = 'VIEW' THEN 'VIEW' ELSE 'TABLE' END AS table_type
+             FROM information_schema.TABLES
+             WHERE TABLE_SCHEMA = COALESCE(?, DATABASE())
+             ORDER BY TABLE_NAME",
+            vec![schema],
+        ),
+        MetadataKind::Columns => (
+            "SELECT CAST(TABLE_SCHEMA AS CHAR) AS schema_name, CAST(TABLE_NAME AS CHAR) AS table_name,
+                    CAST(COLUMN_NAME AS CHAR) AS column_name, CAST(COLUMN_TYPE AS CHAR) AS data_type,
+                    CAST(IS_NULLABLE AS CHAR) AS is_nullable, CAST(COLUMN_DEFAULT AS CHAR) AS column_default,
+                    CAST(ORDINAL_POSITION AS CHAR) AS ordinal_position,
+                    CASE WHEN COLUMN_KEY = 'PRI' THEN 'YES' ELSE 'NO' END AS is_primary_key
+             FROM information_schema.COLUMNS
+             WHERE TABLE_SCHEMA = COALESCE(?, DATABASE()) AND TABLE_NAME = ?
+             ORDER BY ORDINAL_POSITION",
+            vec![schema, Some(required_table(table)?)],
+        ),
+        MetadataKind::Indexes => (
+            "SELECT CAST(INDEX_NAME AS CHAR) AS index_name, CAST(COLUMN_NAME AS CHAR) AS column_name,
+                    CASE WHEN NON_UNIQUE = 0 THEN 'YES' ELSE 'NO' END AS is_unique,
+                    CASE WHEN INDEX_NAME = 'PRIMARY' THEN 'YES' ELSE 'NO' END AS is_primary
+             FROM information_schema.STATISTICS
+             WHERE TABLE_SCHEMA = COALESCE(?, DATABASE()) AND TABLE_NAME = ?
+             ORDER BY INDEX_NAME, SEQ_IN_INDEX",
+            vec![schema, Some(required_table(table)?)],
+        ),
+        MetadataKind::ForeignKeys => (
+            "SELECT CAST(k.CONSTRAINT_NAME AS CHAR) AS constraint_name,
+                    CAST(k.COLUMN_NAME AS CHAR) AS column_name,
+                    CAST(k.REFERENCED_TABLE_SCHEMA AS CHAR) AS referenced_schema,
+                    CAST(k.REFERENCED_TABLE_NAME AS CHAR) AS referenced_table,
+                    CAST(k.REFERENCED_COLUMN_NAME AS CHAR) AS referenced_column,
+                    CAST(r.UPDATE_RULE AS CHAR) AS on_update, CAST(r.DELETE_RULE AS CHAR) AS on_delete
+             FROM information_schema.KEY_COLUMN_USAGE k
+             JOIN information_schema.REFERENTIAL_CONSTRAINTS r
+               ON r.CONSTRAINT_SCHEMA = k.CONSTRAINT_SCHEMA
+              AND r.CONSTRAINT_NAME = k.CONSTRAINT_NAME
+              AND r.TABLE_NAME = k.TABLE_NAME
+             WHERE k.TABLE_SCHEMA = COALESCE(?, DATABASE()) AND k.TABLE_NAME = ?
+               AND k.REFERENCED_TABLE_NAME IS NOT NULL
+             ORDER BY k.CONSTRAINT_NAME, k.ORDINAL_POSITION",
+            vec![schema, Some(required_table(table)?)],
+        ),
+    };
+    Ok(statement)
+}
+
+async fn fetch_mysql(
+    options: sqlx::mysql::MySqlConnectOptions,
+    sql: &str,
+    binds: Vec<Option<String>>,
+) -> Result<Vec<MetaRow>, String> {
+    let pool = sqlx::mysql::MySqlPool::connect_with(options)
+        .await
+        .map_err(|e| format!("Failed to connect to MySQL: {}", e))?;
+
+    let mut query = sqlx::query(sql);
+    for bind in binds {
+        query = query.bind(bind);
+    }
+    let rows = query
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| format!("Metadata query failed: {}", e))?;
+    pool.close().await;
+
+    Ok(rows
+        .iter()
+        .map(|row| {
+            (0..row.len())
+                .map(|idx| {
+                    let value = json_to_text(db_system::mysql_column_to_json(row, idx));
+                    (row.column(idx).name().to_lowercase(), value)
+                })
+                .collect()
+        })
+        .collect())
+}
+
+// ==================== SQL Server (INFORMATION_SCHEMA / sys catalog) ====================
+
+fn sqlserver_statement(
+    kind: MetadataKind,
+    schema: Option<&str>,
+    table: Option<&str>,
+) -> Result<(&'static str, Vec<Option<String>>), String> {
+    let schema = schema.map(str::to_string);
+
+    let statement = match kind {
+        MetadataKind::Schemas => (
+            "SELECT s.name AS schema_name FROM sys.schemas s
+             WHERE s.name NOT IN ('sys', 'INFORMATION_SCHEMA', 'guest') AND s.name NOT LIKE 'db[_]%'
+             ORDER BY s.name",
+            vec![],
+        ),
+        MetadataKind::Tables => (
+            "SELECT TABLE_SCHEMA AS schema_name, TABLE_NAME AS table_name,
+                    CASE WHEN TABLE_TYPE = 'VIEW' THEN 'VIEW' ELSE 'TABLE' END AS table_type
+             FROM INFORMATION_SCHEMA.TABLES
+             WHERE TABLE_SCHEMA = COALESCE(@P1, SCHEMA_NAME())
+             ORDER BY TABLE_NAME",
+            vec![schema],
+        ),
+        MetadataKind::Columns => (
+            "SELECT c.TABLE_SCHEMA AS schema_name, c.TABLE_NAME AS table_name,
+                    c.COLUMN_NAME AS column_name, c.DATA_TYPE AS data_type,
+                    c.IS_NULLABLE AS is_nullable, c.COLUMN_DEFAULT AS column_default,
+                    CAST(c.ORDINAL_POSITION AS NVARCHAR(10)) AS ordinal_position,
+                    CASE WHEN EXISTS (
+                        SELECT 1 FROM INFORMATION_SCHEMA.TABLE_CONSTRAINTS tc
+                        JOIN INFORMATION_SCHEMA.KEY_COLUMN_USAGE k
+                          ON k.CONSTRAINT_NAME = tc.CONSTRAINT_NAME
+                         AND k.CONSTRAINT_SCHEMA = tc.CONSTRAINT_SCHEMA
+                        WHERE tc.CONSTRAINT_TYPE = 'PRIMARY KEY' AND k.TABLE_SCHEMA = c.TABLE_SCHEMA
+                          AND k.TABLE_NAME = c.TABLE_NAME AND k.COLUMN_NAME = c.COLUMN_NAME
+                    ) THEN 'YES' ELSE 'NO' END AS is_primary_key
+             FROM INFORMATION_SCHEMA.COLUMNS c
+             WHERE c.TABLE_SCHEMA = COALESCE(@P1, SCHEMA_NAME()) AND c.TABLE_NAME = @P2
+             ORDER BY c.ORDINAL_POSITION",
+            vec![schema, Some(required_table(table)?)],
+        ),
+        MetadataKind::Indexes => (
+            "SELECT i.name AS index_name, col.name AS column_name,
+                    CASE WHEN i.is_unique = 1 THEN 'YES' ELSE 'NO' END AS is_unique,
+                    CASE WHEN i.is_primary_key = 1 THEN 'YES' ELSE 'NO' END AS is_primary
+             FROM sys.indexes i
+             JOIN sys.index_columns ic ON ic.object_id = i.object_id AND ic.index_id = i.index_id
+             JOIN sys.columns col ON col.object_id = ic.object_id AND col.column_id = ic.column_id
+             JOIN sys.tables t ON t.object_id = i.object_id
+             JOIN sys.schemas s ON s.schema_id = t.schema_id
+             WHERE s.name = COALESCE(@P1, SCHEMA_NAME()) AND t.name = @P2 AND i.name IS NOT NULL
+             ORDER BY i.name, ic.key_ordinal",
+            vec![schema, Some(required_table(table)?)],
+        ),
+        MetadataKind::ForeignKeys => (
+            "SELECT fk.name AS constraint_name, pc.name AS column_name,
+                    rs.name AS referenced_schema, rt.name AS referenced_table,
+                    rc.name AS referenced_column,
+                    REPLACE(fk.update_referential_action_desc, '_', ' ') AS on_update,
+                    REPLACE(fk.delete_referential_action_desc, '_', ' ') AS on_delete
+             FROM sys.foreign_keys fk
+             JOIN sys.foreign_key_columns fkc ON fkc.constraint_object_id = fk.object_id
+             JOIN sys.tables t ON t.object_id = fk.parent_object_id
+             JOIN sys.schemas s ON s.schema_id = t.schema_id
+             JOIN sys.columns pc ON pc.object_id = fkc.parent_object_id AND pc.column_id = fkc.parent_column_id
+             JOIN sys.tables rt ON rt.object_id = fk.referenced_object_id
+             JOIN sys.schemas rs ON rs.schema_id = rt.schema_id
+             JOIN sys.columns rc ON rc.object_id = fkc.referenced_object_id AND rc.column_id = fkc.referenced_column_id
+             WHERE s.name = COALESCE(@P1, SCHEMA_NAME()) AND t.name = @P2
+             ORDER BY fk.name, fkc.constraint_column_id",
+            vec![schema, Some(required_table(table)?)],
+        ),
+    };
+    Ok(statement)
+}
+
+async fn fetch_sqlserver(
+    config: tiberius::Config,
+    sql: &str,
+    binds: Vec<Option<String>>,
+) -> Result<Vec<MetaRow>, String> {
+    let mut client = db_system::connect_sqlserver(config).await?;
+
+    let params: Vec<&dyn tiberius::ToSql> = binds.iter().map(|b| b as &dyn tiberius::ToSql).collect();
+    let rows = client
+        .query(sql, &params)
+        .await
+        .map_err(|e| format!("Metadata query failed: {}", e))?
+        .into_first_result()
+        .await
+        .map_err(|e| format!("Metadata query failed: {}", e))?;
+
+    let _ = client.close().await;
+
+    Ok(rows
+        .iter()
+        .map(|row| {
+            row.cells()
+                .map(|(column, data)| {
+                    let value = json_to_text(db_system::sqlserver_column_to_json(data));
+                    (column.name().to_lowercase(), value)
+                })
+                .collect()
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flag_normalizes_vendor_booleans() {
+        let row: MetaRow = [
+            ("a".to_string(), Some("YES".to_string())),
+            ("b".to_string(), Some("t".to_string())),
+            ("c".to_string(), Some("N".to_string())),
+            ("d".to_string(), None),
+        ]
+        .into_iter()
+        .collect();
+
+        assert!(flag(&row, "a"));
+        assert!(flag(&row, "b"));
+        assert!(!flag(&row, "c"));
+        assert!(!flag(&row, "d"));
+    }
+
+    #[test]
+    fn test_sqlite_introspection() {
+        let path = std::env::temp_dir().join(format!("itol_schema_{}.db", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        std::fs::File::create(&path).unwrap();
+        let connection = || DatabaseConnection::Sqlite {
+            file_path: Some(path.to_string_lossy().to_string()),
+        };
+
+        let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+        runtime.block_on(async {
+            let setup = "CREATE TABLE parent (id INTEGER PRIMARY KEY, code TEXT NOT NULL UNIQUE);
+                         CREATE TABLE child (id INTEGER PRIMARY KEY, parent_id INTEGER
+                             REFERENCES parent(id) ON DELETE CASCADE, note TEXT DEFAULT 'x');
+                         CREATE VIEW child_view AS SELECT * FROM child;";
+            let pool = sqlx::sqlite::SqlitePool::connect(&format!("sqlite:{}", path.display()))
+                .await
+                .unwrap();
+            sqlx::raw_sql(setup).execute(&pool).await.unwrap();
+            pool.close().await;
+
+            let tables = list_tables(connection(), None).await.unwrap();
+            let names: Vec<_> = tables.iter().map(|t| (t.name.as_str(), t.table_type.as_str())).collect();
+            assert_eq!(names, vec![("child", "TABLE"), ("child_view", "VIEW"), ("parent", "TABLE")]);
+
+            let columns = list_columns(connection(), None, "child".to_string()).await.unwrap();
+            assert_eq!(columns.len(), 3);
+            assert!(columns[0].is_primary_key);
+            assert_eq!(columns[2].default_value.as_deref(), Some("'x'"));
+            assert!(columns[2].nullable);
+
+            let indexes = list_indexes(connection(), None, "parent".to_string()).await.unwrap();
+            assert_eq!(indexes.len(), 1);
+            assert!(indexes[0].is_unique);
+            assert_eq!(indexes[0].columns, vec!["code".to_string()]);
+
+            let foreign_keys = list_foreign_keys(connection(), None, "child".to_string()).await.unwrap();
+            assert_eq!(foreign_keys.len(), 1);
+            assert_eq!(foreign_keys[0].referenced_table, "parent");
+            assert_eq!(foreign_keys[0].columns, vec!["parent_id".to_string()]);
+            assert_eq!(foreign_keys[0].on_delete.as_deref(), Some("CASCADE"));
+        });
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -1,6 +1,13 @@
 use serde::{Deserialize, Serialize};
+use futures_util::TryStreamExt;
 use sqlx::{Column, Row};
-use std::collections::HashMap;
+
+use crate::command::database::connection_profile;
+use super::db_result::{result_file_stem, ResultFileFormat, ResultSink};
+use super::request::get_run_dir;
+use super::oracle_net::{self, OracleConnectTarget, OracleNetOptions};
+use super::oracle_plsql::{self, OracleBind};
+use super::ssh_tunnel::{self, SshTunnel, SshTunnelConfig};
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(tag = "type", rename_all = "lowercase")]
@@ -18,8 +25,10 @@ pub enum DatabaseConnection {
         username: Option<String>,
         password: Option<String>,
         schema: Option<String>,
-        #[serde(rename = "sslMode")]
-        ssl_mode: Option<bool>,
+        #[serde(flatten)]
+        tls: PgTlsConfig,
+        #[serde(rename = "sshTunnel")]
+        ssh_tunnel: Option<SshTunnelConfig>,
     },
     #[serde(rename = "oracle")]
     Oracle {
@@ -30,9 +39,112 @@ pub enum DatabaseConnection {
         sid: Option<String>,
         username: Option<String>,
         password: Option<String>,
+        #[serde(flatten)]
+        net: OracleNetOptions,
+        #[serde(rename = "sshTunnel")]
+        ssh_tunnel: Option<SshTunnelConfig>,
+    },
+    #[serde(rename = "mysql", alias = "mariadb")]
+    MySql {
+        host: Option<String>,
+        port: Option<u16>,
+        database: Option<String>,
+        username: Option<String>,
+        password: Option<String>,
+        #[serde(rename = "sshTunnel")]
+        ssh_tunnel: Option<SshTunnelConfig>,
+    },
+    #[serde(rename = "sqlserver")]
+    SqlServer {
+        host: Option<String>,
+        port: Option<u16>,
+        #[serde(rename = "instanceName")]
+        instance_name: Option<String>,
+        database: Option<String>,
+        username: Option<String>,
+        password: Option<String>,
+        #[serde(rename = "trustServerCertificate")]
+        trust_server_certificate: Option<bool>,
+    },
+    /// 저장된 접속 프로필 참조 (실행 시 프로필의 접속 정보로 대체)
+    #[serde(rename = "profile")]
+    Profile {
+        #[serde(rename = "profileId")]
+        profile_id: i32,
     },
 }
 
+/// 프로필 참조를 실제 접속 정보로 변환
+pub async fn resolve_connection(connection: DatabaseConnection) -> Result<DatabaseConnection, String> {
+    match connection {
+        DatabaseConnection::Profile { profile_id } => {
+            let profile = connection_profile::get_connection_profile_by_id(profile_id)
+                .await
+                .map_err(|e| format!("Failed to load connection profile {}: {}", profile_id, e))?;
+            match profile.connection {
+                DatabaseConnection::Profile { .. } => {
+                    Err(format!("Connection profile {} references another profile", profile_id))
+                }
+                connection => Ok(connection),
+            }
+        }
+        connection => Ok(connection),
+    }
+}
+
+impl DatabaseConnection {
+    /// 비밀번호와 SSH 비밀 값(password, passphrase)의 위치
+    fn secrets_mut(&mut self) -> Option<(&mut Option<String>, Option<&mut SshTunnelConfig>)> {
+        match self {
+            DatabaseConnection::PostgreSQL { password, ssh_tunnel, .. }
+            | DatabaseConnection::Oracle { password, ssh_tunnel, .. }
+            | DatabaseConnection::MySql { password, ssh_tunnel, .. } => Some((password, ssh_tunnel.as_mut())),
+            DatabaseConnection::SqlServer { password, .. } => Some((password, None)),
+            DatabaseConnection::Sqlite { .. } | DatabaseConnection::Profile { .. } => None,
+        }
+    }
+
+    /// 비밀 값을 뺀 사본 (프로필 목록을 프론트엔드로 보낼 때 사용)
+    pub fn without_secrets(&self) -> Self {
+        let mut connection = self.clone();
+        if let Some((password, tunnel)) = connection.secrets_mut() {
+            *password = None;
+            if let Some(tunnel) = tunnel {
+                tunnel.password = None;
+                tunnel.passphrase = None;
+            }
+        }
+        connection
+    }
+
+    /// 비어 있는 비밀 값을 저장된 접속 정보에서 채움
+    ///
+    /// 목록에는 비밀 값이 빠져 있으므로 프로필 수정 시 비밀번호를 다시 입력하지 않아도 유지된다.
+    /// DB 종류가 바뀌면 채우지 않는다.
+    pub fn keep_secrets_from(mut self, stored: &DatabaseConnection) -> Self {
+        if std::mem::discriminant(&self) != std::mem::discriminant(stored) {
+            return self;
+        }
+        let mut stored = stored.clone();
+        if let (Some((password, tunnel)), Some((stored_password, stored_tunnel))) =
+            (self.secrets_mut(), stored.secrets_mut())
+        {
+            if password.is_none() {
+                *password = stored_password.take();
+            }
+            if let (Some(tunnel), Some(stored_tunnel)) = (tunnel, stored_tunnel) {
+                if tunnel.password.is_none() {
+                    tunnel.password = stored_tunnel.password.take();
+                }
+                if tunnel.passphrase.is_none() {
+                    tunnel.passphrase = stored_tunnel.passphrase.take();
+                }
+            }
+        }
+        self
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ExecuteDbParams {
     pub connection: DatabaseConnection,
@@ -42,6 +154,21 @@ pub struct ExecuteDbParams {
     pub project_id: Option<i32>,
     pub page_id: i32,
     pub run_id: String,
+    /// 결과 파일 이름으로 사용할 노드 이름
+    pub node_name: Option<String>,
+    /// 전체 결과를 실행 디렉터리에 저장할 형식 ("ndjson" | "csv"), 없으면 저장하지 않음
+    ///
+    /// Oracle plsql/procedure 모드는 결과가 OUT 바인드 한 행이므로 지원하지 않는다 (지정하면 오류).
+    pub output_format: Option<String>,
+    /// Oracle 실행 모드 ("query" | "plsql" | "procedure"), 기본값 "query"
+    ///
+    /// procedure 모드에서는 query에 프로시저/함수 이름을 넣고 binds로 호출 블록을 만든다.
+    pub mode: Option<String>,
+    /// plsql/procedure 모드의 바인드 변수
+    pub binds: Option<Vec<OracleBind>>,
+    /// 결과 파일을 저장할 실행 디렉터리 (없으면 project/page로 찾음)
+    #[serde(default)]
+    pub run_dir: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -49,17 +176,154 @@ pub struct TestConnectionParams {
     pub connection: DatabaseConnection,
 }
 
+/// PostgreSQL sslMode 값 (예전 형식인 true/false도 허용)
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(untagged)]
+pub enum PgSslModeSetting {
+    Enabled(bool),
+    Mode(String),
+}
+
+/// PostgreSQL TLS 설정
+///
+/// SSH 터널을 함께 쓰면 접속 호스트가 127.0.0.1이 되므로 verify-full 대신 verify-ca를 사용해야 한다.
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct PgTlsConfig {
+    /// disable | allow | prefer | require | verify-ca | verify-full (기본값 prefer)
+    #[serde(rename = "sslMode")]
+    pub ssl_mode: Option<PgSslModeSetting>,
+    #[serde(rename = "sslRootCert")]
+    pub ssl_root_cert: Option<String>,
+    #[serde(rename = "sslClientCert")]
+    pub ssl_client_cert: Option<String>,
+    #[serde(rename = "sslClientKey")]
+    pub ssl_client_key: Option<String>,
+}
+
+impl PgTlsConfig {
+    fn ssl_mode(&self) -> Result<sqlx::postgres::PgSslMode, String> {
+        use sqlx::postgres::PgSslMode;
+
+        match &self.ssl_mode {
+            None => Ok(PgSslMode::Prefer),
+            Some(PgSslModeSetting::Enabled(true)) => Ok(PgSslMode::Require),
+            Some(PgSslModeSetting::Enabled(false)) => Ok(PgSslMode::Disable),
+            Some(PgSslModeSetting::Mode(mode)) => mode
+                .parse()
+                .map_err(|_| format!("Unsupported PostgreSQL sslMode: {}", mode)),
+        }
+    }
+}
+
+/// PostgreSQL 접속 옵션 생성 (URL을 거치지 않으므로 자격 증명의 특수 문자도 그대로 전달됨)
+pub fn postgresql_connect_options(
+    host: &str,
+    port: u16,
+    database: &str,
+    username: &str,
+    password: &str,
+    tls: &PgTlsConfig,
+) -> Result<sqlx::postgres::PgConnectOptions, String> {
+    let mut options = sqlx::postgres::PgConnectOptions::new()
+        .host(host)
+        .port(port)
+        .database(database)
+        .username(username)
+        .password(password)
+        .ssl_mode(tls.ssl_mode()?);
+
+    if let Some(path) = &tls.ssl_root_cert {
+        options = options.ssl_root_cert(path);
+    }
+
+    // 클라이언트 인증서와 키는 함께 지정해야 함
+    match (&tls.ssl_client_cert, &tls.ssl_client_key) {
+        (Some(cert), Some(key)) => {
+            options = options.ssl_client_cert(cert).ssl_client_key(key);
+        }
+        (None, None) => {}
+        _ => return Err("Both sslClientCert and sslClientKey are required for client certificate authentication".to_string()),
+    }
+
+    Ok(options)
+}
+
+/// Oracle 접속 정보를 연결 대상으로 정리 (host/port 접속이면 SSH 터널도 함께 열어서 반환)
+pub async fn oracle_connect_target(
+    connection: &DatabaseConnection,
+) -> Result<(Option<SshTunnel>, OracleConnectTarget), String> {
+    let DatabaseConnection::Oracle { host, port, service_name, sid, username, password, net, ssh_tunnel } = connection
+    else {
+        return Err("Not an Oracle connection".to_string());
+    };
+
+    let port = port.unwrap_or(1521);
+    let (tunnel, host, port) = match host.clone().filter(|_| !net.uses_named_target()) {
+        Some(host) => {
+            let (tunnel, host, port) = ssh_tunnel::forward(ssh_tunnel.as_ref(), host, port).await?;
+            (tunnel, Some(host), port)
+        }
+        None if ssh_tunnel.is_some() => {
+            return Err("SSH tunnel requires an Oracle host/port connection".to_string())
+        }
+        None => (None, None, port),
+    };
+
+    let external_auth = net.external_auth.unwrap_or(false);
+    let (username, password) = if external_auth {
+        (username.clone().unwrap_or_default(), password.clone().unwrap_or_default())
+    } else {
+        (
+            username.clone().ok_or("Username is required")?,
+            password.clone().ok_or("Password is required")?,
+        )
+    };
+
+    let connect_string = oracle_net::build_connect_string(
+        host.as_deref(),
+        port,
+        service_name.as_deref(),
+        sid.as_deref(),
+        net,
+    )?;
+
+    let target = OracleConnectTarget {
+        username,
+        password,
+        connect_string,
+        privilege: oracle_net::parse_privilege(net.privilege.as_deref())?,
+        external_auth,
+    };
+    Ok((tunnel, target))
+}
+
+/// SQLite 컬럼 값을 JSON 값으로 변환
+fn sqlite_column_to_json(row: &sqlx::sqlite::SqliteRow, col_idx: usize) -> serde_json::Value {
+    // 다양한 타입 처리
+    if let Ok(val) = row.try_get::<String, _>(col_idx) {
+        serde_json::Value::String(val)
+    } else if let Ok(val) = row.try_get::<i64, _>(col_idx) {
+        serde_json::Value::Number(val.into())
+    } else if let Ok(val) = row.try_get::<f64, _>(col_idx) {
+        serde_json::json!(val)
+    } else if let Ok(val) = row.try_get::<bool, _>(col_idx) {
+        serde_json::Value::Bool(val)
+    } else {
+        serde_json::Value::Null
+    }
+}
+
 /// SQLite 데이터베이스에 연결하고 쿼리 실행
 pub async fn execute_sqlite_query(
     file_path: &str,
     query: &str,
-    max_rows: Option<i32>,
+    mut sink: ResultSink,
 ) -> Result<String, String> {
     println!("Connecting to SQLite database: {}", file_path);
 
     // 연결 문자열 생성
     let connection_string = format!("sqlite:{}", file_path);
-    
+
     // 데이터베이스 풀 생성
     let pool = sqlx::sqlite::SqlitePool::connect(&connection_string)
         .await
@@ -67,83 +331,73 @@ pub async fn execute_sqlite_query(
 
     println!("Connected to SQLite. Executing query: {}", query);
 
-    // 쿼리 실행
-    let rows = sqlx::query(query)
-        .fetch_all(&pool)
-        .await
-        .map_err(|e| format!("Query execution failed: {}", e))?;
-
-    println!("Query executed successfully. Rows fetched: {}", rows.len());
-
-    // 결과를 JSON으로 변환
-    let mut results: Vec<HashMap<String, serde_json::Value>> = Vec::new();
-    let max = max_rows.unwrap_or(1000) as usize;
-    
-    for (idx, row) in rows.iter().enumerate() {
-        if idx >= max {
-            break;
-        }
-
-        let mut row_map = HashMap::new();
-        
-        // SQLite Row에서 컬럼 추출
-        for col_idx in 0..row.len() {
-            let col = row.column(col_idx);
-            let col_name = col.name();
-            
-            // 다양한 타입 처리
-            let value: serde_json::Value = if let Ok(val) = row.try_get::<String, _>(col_idx) {
-                serde_json::Value::String(val)
-            } else if let Ok(val) = row.try_get::<i64, _>(col_idx) {
-                serde_json::Value::Number(val.into())
-            } else if let Ok(val) = row.try_get::<f64, _>(col_idx) {
-                serde_json::json!(val)
-            } else if let Ok(val) = row.try_get::<bool, _>(col_idx) {
-                serde_json::Value::Bool(val)
-            } else {
-                serde_json::Value::Null
+    // 쿼리 실행 (필요한 만큼만 행 단위로 읽음)
+    {
+        let mut rows = sqlx::query(query).fetch(&pool);
+        while sink.wants_more() {
+            let Some(row) = rows
+                .try_next()
+                .await
+                .map_err(|e| format!("Query execution failed: {}", e))?
+            else {
+                break;
             };
-            
-            row_map.insert(col_name.to_string(), value);
+
+            let values = (0..row.len())
+                .map(|col_idx| (row.column(col_idx).name().to_string(), sqlite_column_to_json(&row, col_idx)))
+                .collect();
+            sink.push(values)?;
         }
-        
-        results.push(row_map);
     }
 
     pool.close().await;
 
     // 결과를 JSON 문자열로 반환
-    let response = serde_json::json!({
-        "success": true,
-        "rowCount": results.len(),
-        "data": results,
-        "truncated": rows.len() > max,
-    });
-
-    Ok(response.to_string())
+    sink.finish()
+}
+
+/// PostgreSQL 컬럼 값을 JSON 값으로 변환
+fn postgresql_column_to_json(row: &sqlx::postgres::PgRow, col_idx: usize) -> serde_json::Value {
+    // PostgreSQL 타입 처리
+    if let Ok(val) = row.try_get::<String, _>(col_idx) {
+        serde_json::Value::String(val)
+    } else if let Ok(val) = row.try_get::<i32, _>(col_idx) {
+        serde_json::Value::Number(val.into())
+    } else if let Ok(val) = row.try_get::<i64, _>(col_idx) {
+        serde_json::Value::Number(val.into())
+    } else if let Ok(val) = row.try_get::<f32, _>(col_idx) {
+        serde_json::json!(val)
+    } else if let Ok(val) = row.try_get::<f64, _>(col_idx) {
+        serde_json::json!(val)
+    } else if let Ok(val) = row.try_get::<bool, _>(col_idx) {
+        serde_json::Value::Bool(val)
+    } else {
+        serde_json::Value::Null
+    }
 }
 
 /// PostgreSQL 데이터베이스에 연결하고 쿼리 실행
 pub async fn execute_postgresql_query(
-    host: &str,
-    port: u16,
-    database: &str,
-    username: &str,
-    password: &str,
+    options: sqlx::postgres::PgConnectOptions,
     schema: Option<&str>,
     query: &str,
-    max_rows: Option<i32>,
+    mut sink: ResultSink,
 ) -> Result<String, String> {
-    println!("Connecting to PostgreSQL database: {}:{}/{}", host, port, database);
-
-    // 연결 문자열 생성
-    let connection_string = format!(
-        "postgresql://{}:{}@{}:{}/{}",
-        username, password, host, port, database
+    println!(
+        "Connecting to PostgreSQL database: {}:{}/{}",
+        options.get_host(),
+        options.get_port(),
+        options.get_database().unwrap_or_default()
     );
 
     // 데이터베이스 풀 생성
-    let pool = sqlx::postgres::PgPool::connect(&connection_string)
+    let pool = sqlx::postgres::PgPool::connect_with(options)
+        .await
+        .map_err(|e| format!("Failed to connect to PostgreSQL: {}", e))?;
+
+    // search_path가 쿼리에도 적용되도록 하나의 커넥션에서 실행
+    let mut conn = pool
+        .acquire()
         .await
         .map_err(|e| format!("Failed to connect to PostgreSQL: {}", e))?;
 
@@ -153,169 +407,111 @@ pub async fn execute_postgresql_query(
     if let Some(schema_name) = schema {
         let set_schema = format!("SET search_path TO {};", schema_name);
         sqlx::query(&set_schema)
-            .execute(&pool)
+            .execute(&mut *conn)
             .await
             .map_err(|e| format!("Failed to set schema: {}", e))?;
     }
 
-    // 쿼리 실행
-    let rows = sqlx::query(query)
-        .fetch_all(&pool)
-        .await
-        .map_err(|e| format!("Query execution failed: {}", e))?;
+    // 쿼리 실행 (필요한 만큼만 행 단위로 읽음)
+    {
+        let mut rows = sqlx::query(query).fetch(&mut *conn);
+        while sink.wants_more() {
+            let Some(row) = rows
+                .try_next()
+                .await
+                .map_err(|e| format!("Query execution failed: {}", e))?
+            else {
+                break;
+            };
 
-    println!("Query executed successfully. Rows fetched: {}", rows.len());
+            let values = (0..row.len())
+                .map(|col_idx| (row.column(col_idx).name().to_string(), postgresql_column_to_json(&row, col_idx)))
+                .collect();
+            sink.push(values)?;
+        }
+    }
 
-    // 결과를 JSON으로 변환
-    let mut results: Vec<HashMap<String, serde_json::Value>> = Vec::new();
-    let max = max_rows.unwrap_or(1000) as usize;
+    drop(conn);
+    pool.close().await;
 
-    for (idx, row) in rows.iter().enumerate() {
-        if idx >= max {
-            break;
-        }
+    // 결과를 JSON 문자열로 반환
+    sink.finish()
+}
 
-        let mut row_map = HashMap::new();
-        let columns = row.columns();
+/// Oracle 행을 (컬럼 이름, JSON 값) 목록으로 변환 (REF CURSOR 결과에도 사용)
+pub(crate) fn oracle_row_to_values(row: &oracle::Row) -> Vec<(String, serde_json::Value)> {
+    let mut values = Vec::new();
 
-        for col in columns {
-            let col_name = col.name();
-            
-            // PostgreSQL 타입 처리
-            let value: serde_json::Value = if let Ok(val) = row.try_get::<String, _>(col_name) {
-                serde_json::Value::String(val)
-            } else if let Ok(val) = row.try_get::<i32, _>(col_name) {
-                serde_json::Value::Number(val.into())
-            } else if let Ok(val) = row.try_get::<i64, _>(col_name) {
-                serde_json::Value::Number(val.into())
-            } else if let Ok(val) = row.try_get::<f32, _>(col_name) {
-                serde_json::json!(val)
-            } else if let Ok(val) = row.try_get::<f64, _>(col_name) {
-                serde_json::json!(val)
-            } else if let Ok(val) = row.try_get::<bool, _>(col_name) {
-                serde_json::Value::Bool(val)
-            } else {
-                serde_json::Value::Null
-            };
+    // Oracle Row에서 컬럼 추출
+    let column_info = row.column_info();
+    for (col_idx, col_info) in column_info.iter().enumerate() {
+        let col_name = col_info.name();
 
-            row_map.insert(col_name.to_string(), value);
-        }
+        // Oracle 타입에 따라 값 추출
+        let value: serde_json::Value = match col_info.oracle_type() {
+            _ if row.get::<usize, Option<String>>(col_idx).is_ok() => {
+                if let Ok(Some(val)) = row.get::<usize, Option<String>>(col_idx) {
+                    serde_json::Value::String(val)
+                } else {
+                    serde_json::Value::Null
+                }
+            }
+            _ if row.get::<usize, Option<i64>>(col_idx).is_ok() => {
+                if let Ok(Some(val)) = row.get::<usize, Option<i64>>(col_idx) {
+                    serde_json::Value::Number(val.into())
+                } else {
+                    serde_json::Value::Null
+                }
+            }
+            _ if row.get::<usize, Option<f64>>(col_idx).is_ok() => {
+                if let Ok(Some(val)) = row.get::<usize, Option<f64>>(col_idx) {
+                    serde_json::json!(val)
+                } else {
+                    serde_json::Value::Null
+                }
+            }
+            _ => serde_json::Value::Null,
+        };
 
-        results.push(row_map);
+        values.push((col_name.to_string(), value));
     }
 
-    pool.close().await;
-
-    // 결과를 JSON 문자열로 반환
-    let response = serde_json::json!({
-        "success": true,
-        "rowCount": results.len(),
-        "data": results,
-        "truncated": rows.len() > max,
-    });
-
-    Ok(response.to_string())
+    values
 }
 
 /// Oracle 데이터베이스에 연결하고 쿼리 실행
 pub async fn execute_oracle_query(
-    host: &str,
-    port: u16,
-    service_name: Option<&str>,
-    sid: Option<&str>,
-    username: &str,
-    password: &str,
+    target: OracleConnectTarget,
     query: &str,
-    max_rows: Option<i32>,
+    mut sink: ResultSink,
 ) -> Result<String, String> {
-    println!("Connecting to Oracle database: {}:{}", host, port);
-
-    // Oracle 연결 문자열 생성
-    let connect_string = if let Some(service) = service_name {
-        format!("//{}:{}/{}", host, port, service)
-    } else if let Some(sid_val) = sid {
-        format!("{}:{}/{}", host, port, sid_val)
-    } else {
-        return Err("Either service_name or sid must be provided for Oracle connection".to_string());
-    };
-
-    println!("Oracle connect string: {}", connect_string);
+    println!("Oracle connect string: {}", target.connect_string);
 
     // Oracle 연결 생성 (동기 방식이므로 blocking task로 실행)
-    let username_owned = username.to_string();
-    let password_owned = password.to_string();
-    let connect_string_owned = connect_string.clone();
     let query_owned = query.to_string();
-    let max = max_rows.unwrap_or(1000) as usize;
 
     let result = tokio::task::spawn_blocking(move || {
         // Oracle 연결 생성
-        let conn = oracle::Connection::connect(&username_owned, &password_owned, &connect_string_owned)
+        let conn = target
+            .connect()
             .map_err(|e| format!("Failed to connect to Oracle: {}", e))?;
 
         println!("Connected to Oracle. Executing query: {}", query_owned);
 
-        // 쿼리 실행
+        // 쿼리 실행 (ResultSet은 필요할 때마다 행을 가져오는 iterator)
         let rows = conn.query(&query_owned, &[])
             .map_err(|e| format!("Query execution failed: {}", e))?;
 
-        // 결과를 JSON으로 변환
-        let mut results: Vec<HashMap<String, serde_json::Value>> = Vec::new();
-
-        for (idx, row_result) in rows.enumerate() {
-            if idx >= max {
+        for row_result in rows {
+            if !sink.wants_more() {
                 break;
             }
 
             let row = row_result.map_err(|e| format!("Failed to fetch row: {}", e))?;
-            let mut row_map = HashMap::new();
-
-            // Oracle Row에서 컬럼 추출
-            let column_info = row.column_info();
-            for (col_idx, col_info) in column_info.iter().enumerate() {
-                let col_name = col_info.name();
-
-                // Oracle 타입에 따라 값 추출
-                let value: serde_json::Value = match col_info.oracle_type() {
-                    _ if row.get::<usize, Option<String>>(col_idx).is_ok() => {
-                        if let Ok(Some(val)) = row.get::<usize, Option<String>>(col_idx) {
-                            serde_json::Value::String(val)
-                        } else {
-                            serde_json::Value::Null
-                        }
-                    }
-                    _ if row.get::<usize, Option<i64>>(col_idx).is_ok() => {
-                        if let Ok(Some(val)) = row.get::<usize, Option<i64>>(col_idx) {
-                            serde_json::Value::Number(val.into())
-                        } else {
-                            serde_json::Value::Null
-                        }
-                    }
-                    _ if row.get::<usize, Option<f64>>(col_idx).is_ok() => {
-                        if let Ok(Some(val)) = row.get::<usize, Option<f64>>(col_idx) {
-                            serde_json::json!(val)
-                        } else {
-                            serde_json::Value::Null
-                        }
-                    }
-                    _ => serde_json::Value::Null,
-                };
-
-                row_map.insert(col_name.to_string(), value);
-            }
-
-            results.push(row_map);
+            sink.push(oracle_row_to_values(&row))?;
         }
 
-        let total_rows = results.len();
-        let response = serde_json::json!({
-            "success": true,
-            "rowCount": total_rows,
-            "data": results,
-            "truncated": total_rows >= max,
-        });
-
-        Ok::<String, String>(response.to_string())
+        sink.finish()
     })
     .await
     .map_err(|e| format!("Oracle task failed: {}", e))??;
@@ -323,9 +519,262 @@ pub async fn execute_oracle_query(
     Ok(result)
 }
 
+/// MySQL/MariaDB 접속 옵션 생성 (자격 증명은 URL을 거치지 않으므로 별도 이스케이프가 필요 없음)
+pub fn mysql_connect_options(
+    host: &str,
+    port: u16,
+    database: &str,
+    username: &str,
+    password: &str,
+) -> sqlx::mysql::MySqlConnectOptions {
+    sqlx::mysql::MySqlConnectOptions::new()
+        .host(host)
+        .port(port)
+        .database(database)
+        .username(username)
+        .password(password)
+}
+
+/// MySQL 컬럼 값을 타입 정보에 맞는 JSON 값으로 변환
+///
+/// DECIMAL은 정밀도 손실을 피하기 위해 문자열로, 날짜/시간은 ISO 8601 문자열로 반환한다.
+pub(crate) fn mysql_column_to_json(row: &sqlx::mysql::MySqlRow, col_idx: usize) -> serde_json::Value {
+    use sqlx::{TypeInfo, ValueRef};
+
+    match row.try_get_raw(col_idx) {
+        Ok(raw) if raw.is_null() => return serde_json::Value::Null,
+        Err(_) => return serde_json::Value::Null,
+        _ => {}
+    }
+
+    let type_name = row.column(col_idx).type_info().name().to_string();
+    let value = match type_name.as_str() {
+        "BOOLEAN" => row.try_get::<bool, _>(col_idx).ok().map(serde_json::Value::Bool),
+        "TINYINT" | "SMALLINT" | "MEDIUMINT" | "INT" | "BIGINT" => {
+            row.try_get::<i64, _>(col_idx).ok().map(|v| v.into())
+        }
+        "TINYINT UNSIGNED" | "SMALLINT UNSIGNED" | "MEDIUMINT UNSIGNED" | "INT UNSIGNED"
+        | "BIGINT UNSIGNED" | "YEAR" => row.try_get::<u64, _>(col_idx).ok().map(|v| v.into()),
+        "FLOAT" => row.try_get::<f32, _>(col_idx).ok().map(|v| serde_json::json!(v)),
+        "DOUBLE" => row.try_get::<f64, _>(col_idx).ok().map(|v| serde_json::json!(v)),
+        "DECIMAL" => row
+            .try_get_unchecked::<String, _>(col_idx)
+            .ok()
+            .map(serde_json::Value::String),
+        "DATE" => row
+            .try_get::<chrono::NaiveDate, _>(col_idx)
+            .ok()
+            .map(|v| serde_json::Value::String(v.to_string())),
+        "TIME" => row
+            .try_get::<chrono::NaiveTime, _>(col_idx)
+            .ok()
+            .map(|v| serde_json::Value::String(v.to_string())),
+        "DATETIME" => row
+            .try_get::<chrono::NaiveDateTime, _>(col_idx)
+            .ok()
+            .map(|v| serde_json::Value::String(v.format("%Y-%m-%dT%H:%M:%S%.f").to_string())),
+        "TIMESTAMP" => row
+            .try_get::<chrono::DateTime<chrono::Utc>, _>(col_idx)
+            .ok()
+            .map(|v| serde_json::Value::String(v.to_rfc3339())),
+        "JSON" => row.try_get::<serde_json::Value, _>(col_idx).ok(),
+        "BINARY" | "VARBINARY" | "TINYBLOB" | "BLOB" | "MEDIUMBLOB" | "LONGBLOB" | "BIT"
+        | "GEOMETRY" => row
+            .try_get::<Vec<u8>, _>(col_idx)
+            .ok()
+            .map(bytes_to_json),
+        _ => row.try_get::<String, _>(col_idx).ok().map(serde_json::Value::String),
+    };
+
+    value.unwrap_or(serde_json::Value::Null)
+}
+
+/// 바이너리 값은 UTF-8이면 문자열로, 아니면 바이트 배열로 반환
+fn bytes_to_json(bytes: Vec<u8>) -> serde_json::Value {
+    match String::from_utf8(bytes) {
+        Ok(text) => serde_json::Value::String(text),
+        Err(e) => serde_json::json!(e.into_bytes()),
+    }
+}
+
+/// MySQL/MariaDB 데이터베이스에 연결하고 쿼리 실행
+pub async fn execute_mysql_query(
+    options: sqlx::mysql::MySqlConnectOptions,
+    query: &str,
+    mut sink: ResultSink,
+) -> Result<String, String> {
+    println!("Connecting to MySQL database: {}:{}", options.get_host(), options.get_port());
+
+    let pool = sqlx::mysql::MySqlPool::connect_with(options)
+        .await
+        .map_err(|e| format!("Failed to connect to MySQL: {}", e))?;
+
+    println!("Connected to MySQL. Executing query: {}", query);
+
+    // 쿼리 실행 (필요한 만큼만 행 단위로 읽음)
+    {
+        let mut rows = sqlx::query(query).fetch(&pool);
+        while sink.wants_more() {
+            let Some(row) = rows
+                .try_next()
+                .await
+                .map_err(|e| format!("Query execution failed: {}", e))?
+            else {
+                break;
+            };
+
+            let values = (0..row.len())
+                .map(|col_idx| (row.column(col_idx).name().to_string(), mysql_column_to_json(&row, col_idx)))
+                .collect();
+            sink.push(values)?;
+        }
+    }
+
+    pool.close().await;
+
+    // 결과를 JSON 문자열로 반환
+    sink.finish()
+}
+
+/// SQL Server 접속 설정 생성
+///
+/// 포트를 지정하면 그 포트로 바로 접속하고, 포트 없이 인스턴스 이름만 있으면
+/// SQL Browser(UDP 1434)에서 명명된 인스턴스의 포트를 찾아 접속한다.
+pub fn sqlserver_config(
+    host: &str,
+    port: Option<u16>,
+    instance_name: Option<&str>,
+    database: &str,
+    username: &str,
+    password: &str,
+    trust_server_certificate: bool,
+) -> tiberius::Config {
+    let mut config = tiberius::Config::new();
+    config.host(host);
+    match (port, instance_name) {
+        (Some(port), _) => config.port(port),
+        (None, Some(instance)) => config.instance_name(instance),
+        (None, None) => config.port(1433),
+    }
+    config.database(database);
+    config.application_name("ITOL");
+    config.authentication(tiberius::AuthMethod::sql_server(username, password));
+    if trust_server_certificate {
+        config.trust_cert();
+    }
+    config
+}
+
+pub(crate) type SqlServerClient = tiberius::Client<tokio_util::compat::Compat<tokio::net::TcpStream>>;
+
+/// SQL Server에 TDS 연결 생성 (명명된 인스턴스는 SQL Browser로 포트 확인)
+pub(crate) async fn connect_sqlserver(config: tiberius::Config) -> Result<SqlServerClient, String> {
+    use tiberius::SqlBrowser;
+    use tokio_util::compat::TokioAsyncWriteCompatExt;
+
+    let tcp = tokio::net::TcpStream::connect_named(&config)
+        .await
+        .map_err(|e| format!("Failed to connect to SQL Server: {}", e))?;
+
+    tiberius::Client::connect(config, tcp.compat_write())
+        .await
+        .map_err(|e| format!("Failed to connect to SQL Server: {}", e))
+}
+
+/// SQL Server 컬럼 값을 타입에 맞는 JSON 값으로 변환
+///
+/// DECIMAL/NUMERIC은 정밀도 손실을 피하기 위해 문자열로, 날짜/시간은 ISO 8601 문자열로 반환한다.
+pub(crate) fn sqlserver_column_to_json(data: &tiberius::ColumnData<'static>) -> serde_json::Value {
+    use tiberius::{ColumnData, FromSql};
+
+    let value = match data {
+        ColumnData::U8(v) => v.map(|v| v.into()),
+        ColumnData::I16(v) => v.map(|v| v.into()),
+        ColumnData::I32(v) => v.map(|v| v.into()),
+        ColumnData::I64(v) => v.map(|v| v.into()),
+        ColumnData::F32(v) => v.map(|v| serde_json::json!(v)),
+        ColumnData::F64(v) => v.map(|v| serde_json::json!(v)),
+        ColumnData::Bit(v) => v.map(serde_json::Value::Bool),
+        ColumnData::String(v) => v.as_ref().map(|v| serde_json::Value::String(v.to_string())),
+        ColumnData::Guid(v) => v.map(|v| serde_json::Value::String(v.to_string())),
+        ColumnData::Binary(v) => v.as_ref().map(|v| bytes_to_json(v.to_vec())),
+        ColumnData::Numeric(v) => v.map(|v| serde_json::Value::String(v.to_string())),
+        ColumnData::Xml(v) => v.as_ref().map(|v| serde_json::Value::String(v.to_string())),
+        ColumnData::DateTime(_) | ColumnData::SmallDateTime(_) | ColumnData::DateTime2(_) => {
+            chrono::NaiveDateTime::from_sql(data).ok().flatten().map(|v| {
+                serde_json::Value::String(v.format("%Y-%m-%dT%H:%M:%S%.f").to_string())
+            })
+        }
+        ColumnData::Date(_) => chrono::NaiveDate::from_sql(data)
+            .ok()
+            .flatten()
+            .map(|v| serde_json::Value::String(v.to_string())),
+        ColumnData::Time(_) => chrono::NaiveTime::from_sql(data)
+            .ok()
+            .flatten()
+            .map(|v| serde_json::Value::String(v.to_string())),
+        ColumnData::DateTimeOffset(_) => {
+            chrono::DateTime::<chrono::FixedOffset>::from_sql(data)
+                .ok()
+                .flatten()
+                .map(|v| serde_json::Value::String(v.to_rfc3339()))
+        }
+    };
+
+    value.unwrap_or(serde_json::Value::Null)
+}
+
+/// SQL Server 데이터베이스에 연결하고 쿼리 실행
+pub async fn execute_sqlserver_query(
+    config: tiberius::Config,
+    query: &str,
+    mut sink: ResultSink,
+) -> Result<String, String> {
+    println!("Connecting to SQL Server database: {}", config.get_addr());
+
+    let mut client = connect_sqlserver(config).await?;
+
+    println!("Connected to SQL Server. Executing query: {}", query);
+
+    // 쿼리 실행 (첫 번째 결과 집합만 행 단위로 읽음)
+    {
+        let mut stream = client
+            .simple_query(query)
+            .await
+            .map_err(|e| format!("Query execution failed: {}", e))?;
+
+        while sink.wants_more() {
+            let Some(item) = stream
+                .try_next()
+                .await
+                .map_err(|e| format!("Query execution failed: {}", e))?
+            else {
+                break;
+            };
+
+            match item {
+                tiberius::QueryItem::Row(row) if row.result_index() == 0 => {
+                    let values = row
+                        .cells()
+                        .map(|(column, data)| (column.name().to_string(), sqlserver_column_to_json(data)))
+                        .collect();
+                    sink.push(values)?;
+                }
+                tiberius::QueryItem::Metadata(meta) if meta.result_index() > 0 => break,
+                _ => {}
+            }
+        }
+    }
+
+    let _ = client.close().await;
+
+    // 결과를 JSON 문자열로 반환
+    sink.finish()
+}
+
 /// 데이터베이스 연결 테스트
 pub async fn test_connection(connection: DatabaseConnection) -> Result<String, String> {
-    match connection {
+    match resolve_connection(connection).await? {
         DatabaseConnection::Sqlite { file_path } => {
             let path = file_path.ok_or("SQLite file path is required")?;
             
@@ -343,19 +792,18 @@ pub async fn test_connection(connection: DatabaseConnection) -> Result<String, S
             pool.close().await;
             Ok("SQLite connection successful".to_string())
         }
-        DatabaseConnection::PostgreSQL { host, port, database, username, password, .. } => {
+        DatabaseConnection::PostgreSQL { host, port, database, username, password, tls, ssh_tunnel, .. } => {
             let host = host.ok_or("PostgreSQL host is required")?;
             let port = port.unwrap_or(5432);
+            let (_tunnel, host, port) = ssh_tunnel::forward(ssh_tunnel.as_ref(), host, port).await?;
             let database = database.ok_or("Database name is required")?;
             let username = username.ok_or("Username is required")?;
             let password = password.ok_or("Password is required")?;
 
-            let connection_string = format!(
-                "postgresql://{}:{}@{}:{}/{}",
-                username, password, host, port, database
-            );
+            let options =
+                postgresql_connect_options(&host, port, &database, &username, &password, &tls)?;
 
-            let pool = sqlx::postgres::PgPool::connect(&connection_string)
+            let pool = sqlx::postgres::PgPool::connect_with(options)
                 .await
                 .map_err(|e| format!("Connection failed: {}", e))?;
 
@@ -368,29 +816,13 @@ pub async fn test_connection(connection: DatabaseConnection) -> Result<String, S
             pool.close().await;
             Ok("PostgreSQL connection successful".to_string())
         }
-        DatabaseConnection::Oracle { host, port, service_name, sid, username, password } => {
-            let host = host.ok_or("Oracle host is required")?;
-            let port = port.unwrap_or(1521);
-            let username = username.ok_or("Username is required")?;
-            let password = password.ok_or("Password is required")?;
-
-            let host_owned = host.clone();
-            let username_owned = username.clone();
-            let password_owned = password.clone();
-            let service_name_owned = service_name.clone();
-            let sid_owned = sid.clone();
+        oracle @ DatabaseConnection::Oracle { .. } => {
+            let (_tunnel, target) = oracle_connect_target(&oracle).await?;
 
             // Oracle 연결 테스트 (동기 방식이므로 blocking task로 실행)
             let result = tokio::task::spawn_blocking(move || {
-                let connect_string = if let Some(service) = service_name_owned {
-                    format!("//{}:{}/{}", host_owned, port, service)
-                } else if let Some(sid_val) = sid_owned {
-                    format!("{}:{}/{}", host_owned, port, sid_val)
-                } else {
-                    return Err("Either service_name or sid must be provided for Oracle connection".to_string());
-                };
-
-                let conn = oracle::Connection::connect(&username_owned, &password_owned, &connect_string)
+                let conn = target
+                    .connect()
                     .map_err(|e| format!("Connection failed: {}", e))?;
 
                 // 간단한 쿼리로 연결 테스트
@@ -404,6 +836,62 @@ pub async fn test_connection(connection: DatabaseConnection) -> Result<String, S
 
             Ok(result)
         }
+        DatabaseConnection::MySql { host, port, database, username, password, ssh_tunnel } => {
+            let host = host.ok_or("MySQL host is required")?;
+            let port = port.unwrap_or(3306);
+            let (_tunnel, host, port) = ssh_tunnel::forward(ssh_tunnel.as_ref(), host, port).await?;
+            let database = database.ok_or("Database name is required")?;
+            let username = username.ok_or("Username is required")?;
+            let password = password.ok_or("Password is required")?;
+
+            let options = mysql_connect_options(&host, port, &database, &username, &password);
+            let pool = sqlx::mysql::MySqlPool::connect_with(options)
+                .await
+                .map_err(|e| format!("Connection failed: {}", e))?;
+
+            // 간단한 쿼리로 연결 테스트
+            sqlx::query("SELECT 1")
+                .fetch_one(&pool)
+                .await
+                .map_err(|e| format!("Test query failed: {}", e))?;
+
+            pool.close().await;
+            Ok("MySQL connection successful".to_string())
+        }
+        DatabaseConnection::SqlServer {
+            host, port, instance_name, database, username, password, trust_server_certificate
+        } => {
+            let host = host.ok_or("SQL Server host is required")?;
+            let database = database.ok_or("Database name is required")?;
+            let username = username.ok_or("Username is required")?;
+            let password = password.ok_or("Password is required")?;
+
+            let config = sqlserver_config(
+                &host,
+                port,
+                instance_name.as_deref(),
+                &database,
+                &username,
+                &password,
+                trust_server_certificate.unwrap_or(false),
+            );
+            let mut client = connect_sqlserver(config)
+                .await
+                .map_err(|e| format!("Connection failed: {}", e))?;
+
+            // 간단한 쿼리로 연결 테스트
+            client
+                .simple_query("SELECT 1")
+                .await
+                .map_err(|e| format!("Test query failed: {}", e))?
+                .into_row()
+                .await
+                .map_err(|e| format!("Test query failed: {}", e))?;
+
+            let _ = client.close().await;
+            Ok("SQL Server connection successful".to_string())
+        }
+        DatabaseConnection::Profile { .. } => Err("Connection profile was not resolved".to_string()),
     }
 }
 
@@ -412,51 +900,201 @@ pub async fn execute_db_query(params: ExecuteDbParams) -> Result<String, String>
     println!("Executing database query...");
     println!("Query: {}", params.query);
 
-    match params.connection {
+    // 전체 결과 파일 저장 (옵션): <run dir>/<node_name>.<ext>
+    let output = match params.output_format.as_deref() {
+        Some(format) => {
+            let format = ResultFileFormat::parse(format)?;
+            let run_dir = match &params.run_dir {
+                Some(dir) => std::path::PathBuf::from(dir),
+                None => get_run_dir(params.project_id, params.page_id, &params.run_id).await?,
+            };
+            let stem = result_file_stem(params.node_name.as_deref().unwrap_or("db_result"));
+            Some((format, run_dir.join(format!("{}.{}", stem, format.extension()))))
+        }
+        None => None,
+    };
+    let sink = ResultSink::new(params.max_rows, output);
+
+    match resolve_connection(params.connection).await? {
         DatabaseConnection::Sqlite { file_path } => {
             let path = file_path.ok_or("SQLite file path is required")?;
-            execute_sqlite_query(&path, &params.query, params.max_rows).await
+            execute_sqlite_query(&path, &params.query, sink).await
         }
         DatabaseConnection::PostgreSQL { 
-            host, port, database, username, password, schema, ..
+            host, port, database, username, password, schema, tls, ssh_tunnel
         } => {
             let host = host.ok_or("PostgreSQL host is required")?;
             let port = port.unwrap_or(5432);
+            let (_tunnel, host, port) = ssh_tunnel::forward(ssh_tunnel.as_ref(), host, port).await?;
             let database = database.ok_or("Database name is required")?;
             let username = username.ok_or("Username is required")?;
             let password = password.ok_or("Password is required")?;
 
-            execute_postgresql_query(
-                &host,
-                port,
-                &database,
-                &username,
-                &password,
-                schema.as_deref(),
-                &params.query,
-                params.max_rows,
-            )
-            .await
+            let options =
+                postgresql_connect_options(&host, port, &database, &username, &password, &tls)?;
+            execute_postgresql_query(options, schema.as_deref(), &params.query, sink).await
         }
-        DatabaseConnection::Oracle { 
-            host, port, service_name, sid, username, password 
+        oracle @ DatabaseConnection::Oracle { .. } => {
+            let mode = params.mode.as_deref().unwrap_or("query");
+            if mode != "query" && params.output_format.is_some() {
+                return Err(format!("outputFormat is only supported in Oracle query mode, not {} mode", mode));
+            }
+            let (_tunnel, target) = oracle_connect_target(&oracle).await?;
+            let binds = params.binds.unwrap_or_default();
+            match mode {
+                "query" => execute_oracle_query(target, &params.query, sink).await,
+                "plsql" => oracle_plsql::execute_oracle_plsql(target, &params.query, &binds, params.max_rows).await,
+                "procedure" => {
+                    let block = oracle_plsql::build_call_block(&params.query, &binds)?;
+                    oracle_plsql::execute_oracle_plsql(target, &block, &binds, params.max_rows).await
+                }
+                other => Err(format!("Unsupported Oracle execution mode: {}", other)),
+            }
+        }
+        DatabaseConnection::MySql { host, port, database, username, password, ssh_tunnel } => {
+            let host = host.ok_or("MySQL host is required")?;
+            let port = port.unwrap_or(3306);
+            let (_tunnel, host, port) = ssh_tunnel::forward(ssh_tunnel.as_ref(), host, port).await?;
+            let database = database.ok_or("Database name is required")?;
+            let username = username.ok_or("Username is required")?;
+            let password = password.ok_or("Password is required")?;
+
+            let options = mysql_connect_options(&host, port, &database, &username, &password);
+            execute_mysql_query(options, &params.query, sink).await
+        }
+        DatabaseConnection::SqlServer {
+            host, port, instance_name, database, username, password, trust_server_certificate
         } => {
-            let host = host.ok_or("Oracle host is required")?;
-            let port = port.unwrap_or(1521);
+            let host = host.ok_or("SQL Server host is required")?;
+            let database = database.ok_or("Database name is required")?;
             let username = username.ok_or("Username is required")?;
             let password = password.ok_or("Password is required")?;
 
-            execute_oracle_query(
+            let config = sqlserver_config(
                 &host,
                 port,
-                service_name.as_deref(),
-                sid.as_deref(),
+                instance_name.as_deref(),
+                &database,
                 &username,
                 &password,
-                &params.query,
-                params.max_rows,
-            )
-            .await
+                trust_server_certificate.unwrap_or(false),
+            );
+            execute_sqlserver_query(config, &params.query, sink).await
         }
+        DatabaseConnection::Profile { .. } => Err("Connection profile was not resolved".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+    use tiberius::ColumnData;
+
+    #[test]
+    fn test_sqlserver_column_to_json_keeps_types() {
+        assert_eq!(sqlserver_column_to_json(&ColumnData::I32(Some(42))), serde_json::json!(42));
+        assert_eq!(sqlserver_column_to_json(&ColumnData::Bit(Some(true))), serde_json::json!(true));
+        assert_eq!(
+            sqlserver_column_to_json(&ColumnData::String(Some(Cow::Owned("abc".to_string())))),
+            serde_json::json!("abc")
+        );
+        assert_eq!(sqlserver_column_to_json(&ColumnData::I64(None)), serde_json::Value::Null);
+    }
+
+    #[test]
+    fn test_sqlserver_numeric_is_exact_string() {
+        let numeric = tiberius::numeric::Numeric::new_with_scale(12345, 2);
+        assert_eq!(
+            sqlserver_column_to_json(&ColumnData::Numeric(Some(numeric))),
+            serde_json::json!("123.45")
+        );
+    }
+
+    #[test]
+    fn test_sqlserver_config_uses_browser_port_for_named_instance() {
+        let config = |port, instance| sqlserver_config("db.local", port, instance, "app", "sa", "pw", false);
+        assert_eq!(config(None, None).get_addr(), "db.local:1433");
+        // 포트 없이 인스턴스 이름만 있으면 SQL Browser 포트로 조회
+        assert_eq!(config(None, Some("SQLEXPRESS")).get_addr(), "db.local:1434");
+        assert_eq!(config(Some(50123), Some("SQLEXPRESS")).get_addr(), "db.local:50123");
+    }
+
+    #[test]
+    fn test_oracle_plsql_mode_rejects_output_format() {
+        let run_dir = std::env::temp_dir().join(format!("itol_plsql_output_{}", std::process::id()));
+        let params: ExecuteDbParams = serde_json::from_value(serde_json::json!({
+            "connection": { "type": "oracle", "host": "db.local", "serviceName": "XE" },
+            "query": "BEGIN NULL; END;",
+            "page_id": 1,
+            "run_id": "run_test",
+            "node_name": "proc",
+            "output_format": "csv",
+            "mode": "plsql",
+            "run_dir": run_dir.to_string_lossy(),
+        }))
+        .unwrap();
+
+        let err = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(execute_db_query(params))
+            .unwrap_err();
+        assert!(err.contains("only supported in Oracle query mode"), "{}", err);
+        assert!(!run_dir.join("proc.csv").exists());
+    }
+
+    #[test]
+    fn test_postgresql_ssl_mode_accepts_legacy_flag_and_modes() {
+        use sqlx::postgres::PgSslMode;
+
+        let connection: DatabaseConnection = serde_json::from_value(serde_json::json!({
+            "type": "postgresql",
+            "host": "db",
+            "sslMode": true,
+        }))
+        .unwrap();
+        let DatabaseConnection::PostgreSQL { tls, .. } = connection else { panic!("expected PostgreSQL") };
+        assert!(matches!(tls.ssl_mode().unwrap(), PgSslMode::Require));
+
+        let connection: DatabaseConnection = serde_json::from_value(serde_json::json!({
+            "type": "postgresql",
+            "sslMode": "verify-full",
+            "sslRootCert": "/certs/ca.pem",
+        }))
+        .unwrap();
+        let DatabaseConnection::PostgreSQL { tls, .. } = connection else { panic!("expected PostgreSQL") };
+        assert!(matches!(tls.ssl_mode().unwrap(), PgSslMode::VerifyFull));
+        assert_eq!(tls.ssl_root_cert.as_deref(), Some("/certs/ca.pem"));
+
+        assert!(matches!(PgTlsConfig::default().ssl_mode().unwrap(), PgSslMode::Prefer));
+        let invalid = PgTlsConfig {
+            ssl_mode: Some(PgSslModeSetting::Mode("always".to_string())),
+            ..Default::default()
+        };
+        assert!(invalid.ssl_mode().is_err());
+    }
+
+    #[test]
+    fn test_postgresql_connect_options_keep_special_characters() {
+        let options = postgresql_connect_options(
+            "db.local",
+            5433,
+            "app/db",
+            "user@corp",
+            "p@ss:w/rd#1",
+            &PgTlsConfig::default(),
+        )
+        .unwrap();
+        assert_eq!(options.get_username(), "user@corp");
+        assert_eq!(options.get_database(), Some("app/db"));
+        assert_eq!(options.get_port(), 5433);
+
+        let cert_without_key = PgTlsConfig {
+            ssl_client_cert: Some("/certs/client.pem".to_string()),
+            ..Default::default()
+        };
+        assert!(postgresql_connect_options("db", 5432, "db", "u", "p", &cert_without_key).is_err());
     }
 }
@@ -0,0 +1,340 @@
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::flow_runner::{FlowRunReport, NodeStatus};
+
+/// 여러 페이지 실행 결과의 요약 (JSON 리포트 최상위)
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunSummary<'a> {
+    pub success: bool,
+    pub total: usize,
+    pub passed: usize,
+    pub failed: usize,
+    pub errors: usize,
+    pub skipped: usize,
+    pub flows: &'a [FlowRunReport],
+}
+
+impl<'a> RunSummary<'a> {
+    pub fn new(flows: &'a [FlowRunReport]) -> Self {
+        let count = |status| flows.iter().map(|f| f.count(status)).sum::<usize>();
+        RunSummary {
+            success: flows.iter().all(FlowRunReport::success),
+            total: flows.iter().map(|f| f.nodes.len()).sum(),
+            passed: count(NodeStatus::Passed),
+            failed: count(NodeStatus::Failed),
+            errors: count(NodeStatus::Error),
+            skipped: count(NodeStatus::Skipped),
+            flows,
+        }
+    }
+}
+
+fn write_report(path: &Path, content: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create report directory: {}", e))?;
+    }
+    fs::write(path, content).map_err(|e| format!("Failed to write report {}: {}", path.display(), e))
+}
+
+pub fn write_json_report(path: &Path, flows: &[FlowRunReport]) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(&RunSummary::new(flows))
+        .map_err(|e| format!("Failed to serialize report: {}", e))?;
+    write_report(path, &json)
+}
+
+fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // XML 1.0에서 허용되지 않는 제어 문자 제거
+            c if (c as u32) < 0x20 && !matches!(c, '\t' | '\n' | '\r') => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn seconds(ms: u64) -> String {
+    format!("{:.3}", ms as f64 / 1000.0)
+}
+
+/// 페이지 = testsuite, 노드 = testcase 인 JUnit XML
+pub fn junit_xml(flows: &[FlowRunReport]) -> String {
+    let summary = RunSummary::new(flows);
+    let total_ms: u64 = flows.iter().map(|f| f.duration_ms).sum();
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(&format!(
+        "<testsuites name=\"itol\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\" time=\"{}\">\n",
+        summary.total, summary.failed, summary.errors, summary.skipped, seconds(total_ms)
+    ));
+
+    for flow in flows {
+        let suite = escape_xml(&flow.title);
+        xml.push_str(&format!(
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\" time=\"{}\" timestamp=\"{}\">\n",
+            suite,
+            flow.nodes.len(),
+            flow.count(NodeStatus::Failed),
+            flow.count(NodeStatus::Error),
+            flow.count(NodeStatus::Skipped),
+            seconds(flow.duration_ms),
+            escape_xml(&flow.started_at)
+        ));
+
+        for node in &flow.nodes {
+            xml.push_str(&format!(
+                "    <testcase classname=\"{}\" name=\"{}\" time=\"{}\"",
+                suite,
+                escape_xml(&node.node_name),
+                seconds(node.duration_ms)
+            ));
+
+            let message = escape_xml(node.error.as_deref().unwrap_or(""));
+            let mut children = String::new();
+            match node.status {
+                NodeStatus::Passed => {}
+                NodeStatus::Failed => children.push_str(&format!(
+                    "      <failure message=\"{}\" type=\"assertion\"/>\n",
+                    message
+                )),
+                NodeStatus::Error => children.push_str(&format!(
+                    "      <error message=\"{}\" type=\"{}\"/>\n",
+                    message,
+                    escape_xml(&node.node_type)
+                )),
+                NodeStatus::Skipped => children.push_str(&format!(
+                    "      <skipped message=\"{}\"/>\n",
+                    escape_xml(node.logs.first().map(String::as_str).unwrap_or(""))
+                )),
+            }
+            if node.status != NodeStatus::Skipped && !node.logs.is_empty() {
+                children.push_str(&format!(
+                    "      <system-out>{}</system-out>\n",
+                    escape_xml(&node.logs.join("\n"))
+                ));
+            }
+            if let Some(stderr) = node.stderr.as_deref().filter(|s| !s.is_empty()) {
+                children.push_str(&format!("      <system-err>{}</system-err>\n", escape_xml(stderr)));
+            }
+
+            if children.is_empty() {
+                xml.push_str("/>\n");
+            } else {
+                xml.push_str(">\n");
+                xml.push_str(&children);
+                xml.push_str("    </testcase>\n");
+            }
+        }
+        xml.push_str("  </testsuite>\n");
+    }
+
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+pub fn write_junit_report(path: &Path, flows: &[FlowRunReport]) -> Result<(), String> {
+    write_report(path, &junit_xml(flows))
+}
+
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// 큰 응답이 리포트를 무겁게 만들지 않도록 잘라서 표시
+const HTML_VALUE_LIMIT: usize = 64 * 1024;
+
+fn html_block(label: &str, content: &str) -> String {
+    if content.is_empty() {
+        return String::new();
+    }
+    let mut end = content.len().min(HTML_VALUE_LIMIT);
+    while !content.is_char_boundary(end) {
+        end -= 1;
+    }
+    let truncated = if end < content.len() {
+        format!("\n... ({} bytes truncated)", content.len() - end)
+    } else {
+        String::new()
+    };
+    format!(
+        "<h4>{}</h4><pre>{}{}</pre>",
+        label,
+        escape_html(&content[..end]),
+        truncated
+    )
+}
+
+fn pretty(value: Option<&serde_json::Value>) -> String {
+    match value {
+        None | Some(serde_json::Value::Null) => String::new(),
+        Some(serde_json::Value::String(s)) => s.clone(),
+        Some(value) => serde_json::to_string_pretty(value).unwrap_or_default(),
+    }
+}
+
+const HTML_STYLE: &str = "
+body { font-family: -apple-system, 'Segoe UI', sans-serif; margin: 24px; color: #1f2937; }
+h1 { margin-bottom: 4px; }
+.meta { color: #6b7280; margin-bottom: 16px; }
+.summary span { display: inline-block; margin-right: 12px; padding: 2px 10px; border-radius: 10px; background: #f3f4f6; }
+table { border-collapse: collapse; width: 100%; margin-top: 16px; }
+th, td { text-align: left; padding: 6px 8px; border-bottom: 1px solid #e5e7eb; vertical-align: top; }
+.bar { height: 10px; background: #93c5fd; border-radius: 2px; min-width: 2px; }
+.passed { color: #15803d; } .failed, .error { color: #b91c1c; } .skipped { color: #6b7280; }
+details { margin: 4px 0; } summary { cursor: pointer; }
+pre { background: #f9fafb; border: 1px solid #e5e7eb; padding: 8px; overflow-x: auto; white-space: pre-wrap; word-break: break-all; }
+h4 { margin: 8px 0 4px; }
+";
+
+/// 외부 리소스 없이 열 수 있는 페이지 실행 HTML 리포트 (노드 소요 시간, 입력/출력, 로그)
+pub fn html_report(flow: &FlowRunReport) -> String {
+    let max_ms = flow.nodes.iter().map(|n| n.duration_ms).max().unwrap_or(0).max(1);
+    let title = escape_html(&flow.title);
+
+    let mut html = format!(
+        "<!DOCTYPE html>\n<html><head><meta charset=\"utf-8\"><title>{} - ITOL run report</title><style>{}</style></head><body>\n",
+        title, HTML_STYLE
+    );
+    html.push_str(&format!(
+        "<h1>{}</h1>\n<div class=\"meta\">Run {} · started {} · {} ms</div>\n",
+        title,
+        escape_html(&flow.run_id),
+        escape_html(&flow.started_at),
+        flow.duration_ms
+    ));
+    html.push_str(&format!(
+        "<div class=\"summary\"><span class=\"{}\">{}</span><span class=\"passed\">{} passed</span><span class=\"failed\">{} failed</span><span class=\"error\">{} errors</span><span class=\"skipped\">{} skipped</span></div>\n",
+        if flow.success() { "passed" } else { "failed" },
+        if flow.success() { "PASSED" } else { "FAILED" },
+        flow.count(NodeStatus::Passed),
+        flow.count(NodeStatus::Failed),
+        flow.count(NodeStatus::Error),
+        flow.count(NodeStatus::Skipped)
+    ));
+
+    html.push_str("<table>\n<tr><th>Node</th><th>Type</th><th>Status</th><th>Time</th><th style=\"width:30%\">Timing</th></tr>\n");
+    for node in &flow.nodes {
+        let status = serde_json::to_value(node.status)
+            .ok()
+            .and_then(|v| v.as_str().map(str::to_string))
+            .unwrap_or_default();
+        html.push_str(&format!(
+            "<tr><td>{}</td><td>{}</td><td class=\"{}\">{}</td><td>{} ms</td><td><div class=\"bar\" style=\"width:{}%\"></div></td></tr>\n",
+            escape_html(&node.node_name),
+            escape_html(&node.node_type),
+            status,
+            status,
+            node.duration_ms,
+            node.duration_ms * 100 / max_ms
+        ));
+
+        let details = [
+            html_block("Error", node.error.as_deref().unwrap_or("")),
+            html_block("Input", &pretty(node.input.as_ref())),
+            html_block("Output", &pretty(node.output.as_ref())),
+            html_block("Logs", &node.logs.join("\n")),
+            html_block("stderr", node.stderr.as_deref().unwrap_or("")),
+        ]
+        .concat();
+        if !details.is_empty() {
+            html.push_str(&format!(
+                "<tr><td colspan=\"5\"><details{}><summary>Details</summary>{}</details></td></tr>\n",
+                if matches!(node.status, NodeStatus::Failed | NodeStatus::Error) { " open" } else { "" },
+                details
+            ));
+        }
+    }
+    html.push_str("</table>\n</body></html>\n");
+    html
+}
+
+/// 페이지 실행 후 <run_dir>/junit.xml, <run_dir>/report.html 저장
+pub fn write_run_reports(flow: &FlowRunReport) -> Result<(PathBuf, PathBuf), String> {
+    let run_dir = Path::new(&flow.run_dir);
+    let junit_path = run_dir.join("junit.xml");
+    let html_path = run_dir.join("report.html");
+    write_report(&junit_path, &junit_xml(std::slice::from_ref(flow)))?;
+    write_report(&html_path, &html_report(flow))?;
+    Ok((junit_path, html_path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::execution::flow_runner::NodeRunResult;
+
+    fn node(name: &str, status: NodeStatus, error: Option<&str>) -> NodeRunResult {
+        NodeRunResult {
+            node_id: name.to_string(),
+            node_name: name.to_string(),
+            node_type: "api".to_string(),
+            status,
+            duration_ms: 1500,
+            input: None,
+            output: None,
+            error: error.map(str::to_string),
+            stderr: None,
+            logs: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_junit_counts_and_escapes() {
+        let flows = vec![FlowRunReport {
+            title: "Login & Order".to_string(),
+            page_id: Some(1),
+            run_id: "run_1".to_string(),
+            run_dir: String::new(),
+            started_at: "2025-01-01T00:00:00+00:00".to_string(),
+            duration_ms: 3000,
+            nodes: vec![
+                node("login", NodeStatus::Passed, None),
+                node("order", NodeStatus::Failed, Some("HTTP 500: <Internal>")),
+            ],
+        }];
+
+        let xml = junit_xml(&flows);
+        assert!(xml.contains("<testsuites name=\"itol\" tests=\"2\" failures=\"1\" errors=\"0\" skipped=\"0\" time=\"3.000\">"));
+        assert!(xml.contains("<testsuite name=\"Login &amp; Order\""));
+        assert!(xml.contains("<testcase classname=\"Login &amp; Order\" name=\"login\" time=\"1.500\"/>"));
+        assert!(xml.contains("<failure message=\"HTTP 500: &lt;Internal&gt;\" type=\"assertion\"/>"));
+        assert!(!RunSummary::new(&flows).success);
+    }
+
+    #[test]
+    fn test_junit_stderr_and_html_report() {
+        let mut failed = node("script", NodeStatus::Error, Some("TypeError"));
+        failed.stderr = Some("TypeError: x is undefined\n    at main.js:3".to_string());
+        failed.input = Some(serde_json::json!({ "id": "<1>" }));
+        let flow = FlowRunReport {
+            title: "Checkout".to_string(),
+            page_id: None,
+            run_id: "run_2".to_string(),
+            run_dir: String::new(),
+            started_at: String::new(),
+            duration_ms: 1500,
+            nodes: vec![failed],
+        };
+
+        let xml = junit_xml(std::slice::from_ref(&flow));
+        assert!(xml.contains("<system-err>TypeError: x is undefined\n    at main.js:3</system-err>"));
+
+        let html = html_report(&flow);
+        assert!(html.contains("<details open>"));
+        assert!(html.contains("&quot;id&quot;: &quot;&lt;1&gt;&quot;"));
+        assert!(!html.contains("<script") && !html.contains("<link"));
+    }
+}
@@ -0,0 +1,1096 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::time::Instant;
+use log::error;
+
+use crate::command::database::{book, edge, node, page, trash};
+use super::api_system::{self, ExecuteApiParams};
+use super::db_system::{self, DatabaseConnection, ExecuteDbParams};
+use super::flow_report;
+use super::node_system::{self, ExecuteFileParams};
+use super::oracle_plsql::OracleBind;
+use super::request::{get_local_request_json_path, get_run_dir};
+
+/// 플로우의 노드 (DB Node 행 또는 내보낸 React Flow JSON의 노드)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlowNode {
+    pub id: String,
+    #[serde(rename = "type", default)]
+    pub node_type: String,
+    #[serde(default)]
+    pub data: Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlowEdge {
+    pub source: String,
+    pub target: String,
+}
+
+/// 한 페이지의 실행 그래프
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlowGraph {
+    #[serde(default)]
+    pub page_id: Option<i32>,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub nodes: Vec<FlowNode>,
+    #[serde(default)]
+    pub edges: Vec<FlowEdge>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NodeStatus {
+    Passed,
+    /// 실행은 됐지만 검증 실패 (HTTP 2xx 아님 등)
+    Failed,
+    /// 실행 자체가 실패 (연결 오류, 스크립트 에러 등)
+    Error,
+    Skipped,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeRunResult {
+    pub node_id: String,
+    pub node_name: String,
+    pub node_type: String,
+    pub status: NodeStatus,
+    pub duration_ms: u64,
+    pub input: Option<Value>,
+    pub output: Option<Value>,
+    pub error: Option<String>,
+    pub stderr: Option<String>,
+    pub logs: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlowRunReport {
+    pub title: String,
+    pub page_id: Option<i32>,
+    pub run_id: String,
+    pub run_dir: String,
+    pub started_at: String,
+    pub duration_ms: u64,
+    pub nodes: Vec<NodeRunResult>,
+}
+
+impl FlowRunReport {
+    pub fn count(&self, status: NodeStatus) -> usize {
+        self.nodes.iter().filter(|n| n.status == status).count()
+    }
+
+    /// 실패/에러 노드가 없으면 성공 (건너뛴 노드는 실패 노드가 있을 때만 생김)
+    pub fn success(&self) -> bool {
+        self.count(NodeStatus::Failed) == 0 && self.count(NodeStatus::Error) == 0
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct FlowRunOptions {
+    /// 실행 디렉터리의 프로젝트 이름 (기본값 "root")
+    pub project_name: Option<String>,
+    pub run_id: String,
+    /// 환경 변수 - {{name}} 치환에 사용
+    pub variables: HashMap<String, String>,
+    /// 첫 실패 이후의 노드를 모두 건너뜀
+    pub fail_fast: bool,
+    /// 실행 디렉터리 직접 지정 (기본값 <log>/<project>/<page>/<run_id>)
+    pub run_dir: Option<PathBuf>,
+    /// 서브플로우로 호출 중인 상위 페이지 id (순환 호출 방지)
+    pub call_stack: Vec<i32>,
+}
+
+/// 실행 진행 이벤트
+pub enum FlowEvent<'a> {
+    NodeStarted { flow: &'a str, node_id: &'a str, node_name: &'a str },
+    NodeFinished { flow: &'a str, result: &'a NodeRunResult },
+}
+
+impl FlowGraph {
+    /// DB에 저장된 페이지 그래프 로드 (노드가 없으면 flow_data로 대체 - 프론트엔드와 동일)
+    /// 휴지통에 있는 페이지(휴지통에 있는 북의 페이지 포함)는 실행하지 않음
+    pub async fn load_page(page_id: i32) -> Result<Self, String> {
+        let trashed = trash::trashed_page_title(page_id)
+            .await
+            .map_err(|e| format!("Failed to load page {}: {}", page_id, e))?;
+        if let Some(title) = trashed {
+            return Err(format!("Page '{}' ({}) is in the trash", title, page_id));
+        }
+        let page = page::get_page_by_id(page_id)
+            .await
+            .map_err(|e| format!("Failed to load page {}: {}", page_id, e))?;
+        let nodes = node::get_nodes_by_page_id(page_id)
+            .await
+            .map_err(|e| format!("Failed to load nodes: {}", e))?;
+
+        if nodes.is_empty() {
+            if let Some(flow_data) = page.flow_data.as_deref().filter(|s| !s.is_empty()) {
+                let mut graph: FlowGraph = serde_json::from_str(flow_data)
+                    .map_err(|e| format!("Failed to parse flow data of page {}: {}", page_id, e))?;
+                graph.page_id = Some(page.id);
+                graph.title = page.title;
+                return Ok(graph);
+            }
+        }
+
+        let edges = edge::get_edges_by_page_id(page_id)
+            .await
+            .map_err(|e| format!("Failed to load edges: {}", e))?;
+
+        Ok(FlowGraph {
+            page_id: Some(page.id),
+            title: page.title,
+            nodes: nodes
+                .into_iter()
+                .map(|n| FlowNode {
+                    data: serde_json::from_str(&n.data).unwrap_or(Value::Null),
+                    id: n.id,
+                    node_type: n.node_type,
+                })
+                .collect(),
+            edges: edges
+                .into_iter()
+                .map(|e| FlowEdge { source: e.source, target: e.target })
+                .collect(),
+        })
+    }
+
+    /// 북의 모든 페이지 (표시 순서) 와 하위 북의 페이지를 차례로 로드
+    pub async fn load_book(book_id: i32) -> Result<Vec<Self>, String> {
+        let books = book::get_all_books()
+            .await
+            .map_err(|e| format!("Failed to load books: {}", e))?;
+        if !books.iter().any(|b| b.id == book_id) {
+            return Err(format!("Book {} not found", book_id));
+        }
+
+        let mut graphs = Vec::new();
+        let mut visited = HashSet::new();
+        let mut stack = vec![book_id];
+        while let Some(id) = stack.pop() {
+            if !visited.insert(id) {
+                continue;
+            }
+            let pages = page::get_pages_by_book_id(id)
+                .await
+                .map_err(|e| format!("Failed to load pages of book {}: {}", id, e))?;
+            for page in pages {
+                graphs.push(Self::load_page(page.id).await?);
+            }
+            // 하위 북은 id 순서로 실행 (스택이므로 역순으로 넣음)
+            let mut children: Vec<i32> = books
+                .iter()
+                .filter(|b| b.parent_id == Some(id))
+                .map(|b| b.id)
+                .collect();
+            children.sort_unstable_by(|a, b| b.cmp(a));
+            stack.extend(children);
+        }
+        Ok(graphs)
+    }
+
+    /// 내보낸 파일 파싱: {title, nodes, edges} 하나, 그 배열, 또는 {pages: [...]}
+    pub fn from_export(content: &str, default_title: &str) -> Result<Vec<Self>, String> {
+        let value: Value = serde_json::from_str(content)
+            .map_err(|e| format!("Failed to parse flow file: {}", e))?;
+        let items = match value {
+            Value::Array(items) => items,
+            Value::Object(mut map) => match map.remove("pages") {
+                Some(Value::Array(pages)) => pages,
+                _ => vec![Value::Object(map)],
+            },
+            _ => return Err("Flow file must contain an object or an array".to_string()),
+        };
+
+        items
+            .into_iter()
+            .enumerate()
+            .map(|(index, item)| {
+                let mut graph: FlowGraph = serde_json::from_value(item)
+                    .map_err(|e| format!("Invalid flow #{}: {}", index + 1, e))?;
+                if graph.title.is_empty() {
+                    graph.title = if index == 0 {
+                        default_title.to_string()
+                    } else {
+                        format!("{}-{}", default_title, index + 1)
+                    };
+                }
+                Ok(graph)
+            })
+            .collect()
+    }
+
+    /// 위상 정렬된 노드 인덱스 (같은 단계에서는 저장 순서 유지), 순환이 있으면 에러
+    pub fn execution_order(&self) -> Result<Vec<usize>, String> {
+        let index: HashMap<&str, usize> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(i, n)| (n.id.as_str(), i))
+            .collect();
+
+        let mut in_degree = vec![0usize; self.nodes.len()];
+        let mut next: Vec<Vec<usize>> = vec![Vec::new(); self.nodes.len()];
+        for edge in &self.edges {
+            if let (Some(&s), Some(&t)) = (index.get(edge.source.as_str()), index.get(edge.target.as_str())) {
+                if !next[s].contains(&t) {
+                    next[s].push(t);
+                    in_degree[t] += 1;
+                }
+            }
+        }
+
+        let mut queue: VecDeque<usize> = (0..self.nodes.len()).filter(|&i| in_degree[i] == 0).collect();
+        let mut order = Vec::with_capacity(self.nodes.len());
+        while let Some(i) = queue.pop_front() {
+            order.push(i);
+            for &t in &next[i] {
+                in_degree[t] -= 1;
+                if in_degree[t] == 0 {
+                    queue.push_back(t);
+                }
+            }
+        }
+
+        if order.len() != self.nodes.len() {
+            return Err(format!("Flow '{}' contains a cycle", self.title));
+        }
+        Ok(order)
+    }
+
+    fn predecessors(&self, node_id: &str) -> Vec<&str> {
+        self.edges
+            .iter()
+            .filter(|e| e.target == node_id)
+            .map(|e| e.source.as_str())
+            .collect()
+    }
+}
+
+/// 환경 변수 파일 파싱: {"key": "value"} 또는 Postman 환경 ({"values": [{key, value, enabled}]})
+pub fn parse_variables(content: &str) -> Result<HashMap<String, String>, String> {
+    let value: Value = serde_json::from_str(content)
+        .map_err(|e| format!("Failed to parse variables: {}", e))?;
+
+    if let Some(values) = value.get("values").and_then(Value::as_array) {
+        return Ok(values
+            .iter()
+            .filter(|v| v.get("enabled").and_then(Value::as_bool).unwrap_or(true))
+            .filter_map(|v| {
+                let key = v.get("key")?.as_str()?;
+                Some((key.to_string(), value_to_string(v.get("value").unwrap_or(&Value::Null))))
+            })
+            .collect());
+    }
+
+    match value {
+        Value::Object(map) => Ok(map.iter().map(|(k, v)| (k.clone(), value_to_string(v))).collect()),
+        _ => Err("Variables must be a JSON object".to_string()),
+    }
+}
+
+/// JSON 값을 변수/필드 문자열로 변환 (문자열은 따옴표 없이, null은 빈 문자열)
+pub(crate) fn value_to_string(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+/// "result.items[0].id" 형식의 경로로 값 추출 (프론트엔드 extractValueFromPath와 동일)
+pub fn extract_value_from_path<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    let mut current = value;
+    for part in path.split('.') {
+        current = match part.find('[') {
+            Some(open) if part.ends_with(']') => {
+                let index: usize = part[open + 1..part.len() - 1].parse().ok()?;
+                let array = if open == 0 { current } else { current.get(&part[..open])? };
+                array.get(index)?
+            }
+            _ => current.get(part)?,
+        };
+        if current.is_null() {
+            return None;
+        }
+    }
+    Some(current)
+}
+
+/// {{name}} 은 환경 변수로, {{nodeId.path}} 는 이전 노드 결과로 치환 (해석되지 않으면 그대로 둠)
+pub fn resolve_template(
+    template: &str,
+    variables: &HashMap<String, String>,
+    results: &HashMap<String, Value>,
+) -> String {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start + 2..].find("}}") else {
+            break;
+        };
+        let reference = &rest[start + 2..start + 2 + len];
+        let end = start + len + 4;
+        output.push_str(&rest[..start]);
+
+        let resolved = variables.get(reference).cloned().or_else(|| {
+            let (node_id, path) = reference.split_once('.')?;
+            extract_value_from_path(results.get(node_id)?, path).map(value_to_string)
+        });
+        output.push_str(resolved.as_deref().unwrap_or(&rest[start..end]));
+        rest = &rest[end..];
+    }
+    output.push_str(rest);
+    output
+}
+
+/// JSON 값 안의 모든 문자열에 resolve_template 적용
+fn resolve_value(value: &Value, variables: &HashMap<String, String>, results: &HashMap<String, Value>) -> Value {
+    match value {
+        Value::String(s) => Value::String(resolve_template(s, variables, results)),
+        Value::Array(items) => Value::Array(items.iter().map(|v| resolve_value(v, variables, results)).collect()),
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(k, v)| (k.clone(), resolve_value(v, variables, results)))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+/// encodeURIComponent와 같은 퍼센트 인코딩
+fn encode_component(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9'
+            | b'-' | b'_' | b'.' | b'!' | b'~' | b'*' | b'\'' | b'(' | b')' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+/// 활성화된 key/value 목록 ([{key, value, enabled}])
+fn enabled_pairs<'a>(data: &'a Value, field: &str) -> Vec<(&'a str, &'a str)> {
+    data.get(field)
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter(|p| p.get("enabled").and_then(Value::as_bool).unwrap_or(true))
+        .filter_map(|p| {
+            let key = p.get("key")?.as_str()?;
+            Some((key, p.get("value").and_then(Value::as_str).unwrap_or("")))
+        })
+        .filter(|(key, _)| !key.is_empty())
+        .collect()
+}
+
+fn str_field<'a>(data: &'a Value, field: &str) -> Option<&'a str> {
+    data.get(field).and_then(Value::as_str).filter(|s| !s.is_empty())
+}
+
+fn timeout_secs(data: &Value) -> u64 {
+    data.get("timeout")
+        .and_then(Value::as_u64)
+        .map(|ms| ms / 1000)
+        .filter(|&s| s > 0)
+        .unwrap_or(30)
+}
+
+/// 노드 종류 ("api" | "db" | "file" | "subflow")
+fn node_kind(node: &FlowNode) -> &'static str {
+    match node.data.get("type").and_then(Value::as_str) {
+        Some("api") => "api",
+        Some("db") => "db",
+        Some("subflow") => "subflow",
+        _ if node.data.get("filePath").is_some() => "file",
+        _ => "unknown",
+    }
+}
+
+fn node_name(node: &FlowNode) -> String {
+    str_field(&node.data, "name")
+        .or_else(|| str_field(&node.data, "fileName"))
+        .unwrap_or(&node.id)
+        .to_string()
+}
+
+struct NodeContext<'a> {
+    variables: &'a HashMap<String, String>,
+    results: &'a HashMap<String, Value>,
+    page_id: Option<i32>,
+    run_id: &'a str,
+    run_dir: &'a Path,
+    options: &'a FlowRunOptions,
+    on_event: &'a (dyn Fn(FlowEvent<'_>) + Send + Sync),
+}
+
+impl NodeContext<'_> {
+    fn resolve(&self, template: &str) -> String {
+        resolve_template(template, self.variables, self.results)
+    }
+}
+
+/// 노드 실행 결과 - context는 이후 노드의 {{nodeId.path}} 참조 대상
+struct NodeOutcome {
+    status: NodeStatus,
+    input: Option<Value>,
+    output: Option<Value>,
+    context: Option<Value>,
+    error: Option<String>,
+    stderr: Option<String>,
+    logs: Vec<String>,
+}
+
+impl NodeOutcome {
+    fn error(input: Option<Value>, error: String) -> Self {
+        NodeOutcome {
+            status: NodeStatus::Error,
+            input,
+            output: None,
+            context: None,
+            error: Some(error),
+            stderr: None,
+            logs: Vec::new(),
+        }
+    }
+}
+
+/// 프론트엔드 ApiExecutionService와 같은 규칙으로 요청 파라미터 구성
+fn build_api_params(data: &Value, ctx: &NodeContext<'_>) -> Result<ExecuteApiParams, String> {
+    let method = str_field(data, "method").unwrap_or("GET").to_string();
+    let mut url = ctx.resolve(str_field(data, "url").ok_or("API URL is required")?);
+    for (key, value) in enabled_pairs(data, "pathParams") {
+        if !value.is_empty() {
+            url = url.replace(&format!("{{{}}}", key), &encode_component(&ctx.resolve(value)));
+        }
+    }
+
+    let query: HashMap<String, String> = enabled_pairs(data, "queryParams")
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), ctx.resolve(v)))
+        .collect();
+    let mut headers: HashMap<String, String> = enabled_pairs(data, "headers")
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), ctx.resolve(v)))
+        .collect();
+
+    let auth_data = data.get("auth").cloned().unwrap_or(Value::Null);
+    let auth = match str_field(&auth_data, "type") {
+        Some("bearer") => str_field(&auth_data, "token")
+            .map(|token| json!({ "type": "bearer", "token": ctx.resolve(token) })),
+        Some("basic") => match (str_field(&auth_data, "username"), str_field(&auth_data, "password")) {
+            (Some(username), Some(password)) => Some(json!({
+                "type": "basic",
+                "username": ctx.resolve(username),
+                "password": ctx.resolve(password),
+            })),
+            _ => None,
+        },
+        Some("api-key") => {
+            if let (Some(key), Some(header)) = (str_field(&auth_data, "apiKey"), str_field(&auth_data, "apiKeyHeader")) {
+                headers.insert(header.to_string(), ctx.resolve(key));
+            }
+            None
+        }
+        _ => None,
+    };
+
+    let body_data = data.get("body").cloned().unwrap_or(Value::Null);
+    let encode_pairs = |field: &str| {
+        enabled_pairs(&body_data, field)
+            .into_iter()
+            .map(|(k, v)| format!("{}={}", encode_component(k), encode_component(&ctx.resolve(v))))
+            .collect::<Vec<_>>()
+            .join("&")
+    };
+    let body = match str_field(&body_data, "type") {
+        Some("json") => str_field(&body_data, "raw").map(|raw| {
+            headers.insert("Content-Type".to_string(), "application/json".to_string());
+            ctx.resolve(raw)
+        }),
+        Some("raw") => str_field(&body_data, "raw").map(|raw| ctx.resolve(raw)),
+        Some("x-www-form-urlencoded") => {
+            headers.insert("Content-Type".to_string(), "application/x-www-form-urlencoded".to_string());
+            Some(encode_pairs("urlEncoded"))
+        }
+        Some("form-data") => {
+            headers.insert("Content-Type".to_string(), "multipart/form-data".to_string());
+            Some(encode_pairs("formData"))
+        }
+        _ => None,
+    };
+
+    let to_json = |map: &HashMap<String, String>| {
+        (!map.is_empty()).then(|| serde_json::to_string(map).unwrap_or_default())
+    };
+    Ok(ExecuteApiParams {
+        method,
+        base_url: url,
+        query: to_json(&query),
+        headers: to_json(&headers),
+        body,
+        auth: auth.map(|a| a.to_string()),
+        timeout: Some(timeout_secs(data)),
+        project_id: None,
+        page_id: ctx.page_id.unwrap_or(0),
+        run_id: ctx.run_id.to_string(),
+    })
+}
+
+async fn run_api_node(data: &Value, ctx: &NodeContext<'_>) -> NodeOutcome {
+    let params = match build_api_params(data, ctx) {
+        Ok(params) => params,
+        Err(e) => return NodeOutcome::error(None, e),
+    };
+    let input = json!({
+        "method": params.method,
+        "url": params.base_url,
+        "query": params.query.as_deref().and_then(|q| serde_json::from_str::<Value>(q).ok()),
+        "headers": params.headers.as_deref().and_then(|h| serde_json::from_str::<Value>(h).ok()),
+        "body": params.body,
+    });
+    let mut logs = vec![format!("{} {}", params.method, params.base_url)];
+
+    let response = match api_system::execute_api_request(params).await {
+        Ok(text) => serde_json::from_str::<Value>(&text).unwrap_or(Value::String(text)),
+        Err(e) => return NodeOutcome::error(Some(input), e),
+    };
+
+    let status = response.get("status").and_then(Value::as_u64).unwrap_or(0);
+    let status_text = response.get("statusText").and_then(Value::as_str).unwrap_or("").to_string();
+    logs.push(format!("Response: {} {}", status, status_text));
+
+    // 프론트엔드와 동일하게 2xx만 성공
+    let passed = (200..300).contains(&status);
+    NodeOutcome {
+        status: if passed { NodeStatus::Passed } else { NodeStatus::Failed },
+        input: Some(input),
+        context: passed.then(|| json!({ "status": "success", "result": response.clone() })),
+        output: Some(response),
+        error: (!passed).then(|| format!("HTTP {}: {}", status, status_text)),
+        stderr: None,
+        logs,
+    }
+}
+
+async fn run_db_node(data: &Value, name: &str, ctx: &NodeContext<'_>) -> NodeOutcome {
+    let resolved = resolve_value(data, ctx.variables, ctx.results);
+    let connection: DatabaseConnection = match resolved.get("connection").cloned().map(serde_json::from_value) {
+        Some(Ok(connection)) => connection,
+        Some(Err(e)) => return NodeOutcome::error(None, format!("Invalid connection: {}", e)),
+        None => return NodeOutcome::error(None, "DB connection is required".to_string()),
+    };
+    let binds: Option<Vec<OracleBind>> = match resolved.get("binds").filter(|b| !b.is_null()) {
+        Some(binds) => match serde_json::from_value(binds.clone()) {
+            Ok(binds) => Some(binds),
+            Err(e) => return NodeOutcome::error(None, format!("Invalid binds: {}", e)),
+        },
+        None => None,
+    };
+    let query = str_field(&resolved, "query").unwrap_or_default().to_string();
+    let input = json!({ "query": query, "binds": resolved.get("binds") });
+
+    let params = ExecuteDbParams {
+        connection,
+        query,
+        timeout: Some(timeout_secs(data)),
+        max_rows: Some(data.get("maxRows").and_then(Value::as_i64).map(|n| n as i32).unwrap_or(1000)),
+        project_id: None,
+        page_id: ctx.page_id.unwrap_or(0),
+        run_id: ctx.run_id.to_string(),
+        node_name: Some(name.to_string()),
+        output_format: str_field(data, "outputFormat").map(str::to_string),
+        mode: str_field(data, "oracleMode").map(str::to_string),
+        binds,
+        run_dir: Some(ctx.run_dir.display().to_string()),
+    };
+
+    let result: Value = match db_system::execute_db_query(params).await {
+        Ok(text) => match serde_json::from_str(&text) {
+            Ok(result) => result,
+            Err(e) => return NodeOutcome::error(Some(input), format!("Failed to parse query result: {}", e)),
+        },
+        Err(e) => return NodeOutcome::error(Some(input), e),
+    };
+
+    let mut rows = result.get("data").cloned().unwrap_or(Value::Array(Vec::new()));
+    let mut logs = Vec::new();
+
+    // 컬럼 선택/별칭 적용
+    let select_all = data.get("selectAllColumns").and_then(Value::as_bool).unwrap_or(true);
+    let columns: Vec<(&str, &str)> = data
+        .get("columnSelection")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter(|c| c.get("enabled").and_then(Value::as_bool).unwrap_or(false))
+        .filter_map(|c| {
+            let column = c.get("columnName")?.as_str()?;
+            Some((column, str_field(c, "alias").unwrap_or(column)))
+        })
+        .collect();
+    if !select_all && !columns.is_empty() {
+        if let Value::Array(items) = &mut rows {
+            for row in items.iter_mut() {
+                let filtered: serde_json::Map<String, Value> = columns
+                    .iter()
+                    .filter_map(|(column, alias)| Some((alias.to_string(), row.get(*column)?.clone())))
+                    .collect();
+                *row = Value::Object(filtered);
+            }
+        }
+    }
+
+    if data.pointer("/postProcessScript/code").and_then(Value::as_str).is_some_and(|c| !c.is_empty()) {
+        logs.push("Post-process script skipped (only available in the app)".to_string());
+    }
+
+    let row_count = rows.as_array().map(Vec::len).unwrap_or(0);
+    logs.push(format!("Query completed: {} rows", row_count));
+    let output = json!({
+        "success": true,
+        "rowCount": row_count,
+        "data": rows,
+        "truncated": result.get("truncated").and_then(Value::as_bool).unwrap_or(false),
+    });
+    NodeOutcome {
+        status: NodeStatus::Passed,
+        input: Some(input),
+        context: Some(json!({ "status": "success", "data": output.clone() })),
+        output: Some(output),
+        error: None,
+        stderr: None,
+        logs,
+    }
+}
+
+/// 파일 위치에서 위로 올라가며 package.json이 있는 프로젝트 루트를 찾음
+fn find_project_path(file_path: &Path) -> PathBuf {
+    let parent = file_path.parent().unwrap_or(Path::new("."));
+    parent
+        .ancestors()
+        .find(|dir| dir.join("package.json").exists())
+        .unwrap_or(parent)
+        .to_path_buf()
+}
+
+async fn run_file_node(data: &Value, name: &str, ctx: &NodeContext<'_>) -> NodeOutcome {
+    let Some(file_path) = str_field(data, "filePath") else {
+        return NodeOutcome::error(None, "Invalid file node data".to_string());
+    };
+
+    // requestProperties → 실행 파라미터 (참조가 있으면 이전 노드 결과에서 추출)
+    let mut param = serde_json::Map::new();
+    for property in data.get("requestProperties").and_then(Value::as_array).into_iter().flatten() {
+        let Some(key) = str_field(property, "key") else {
+            continue;
+        };
+        let value = match (str_field(property, "referenceNodeId"), str_field(property, "referencePath")) {
+            (Some(node_id), Some(path)) => ctx
+                .results
+                .get(node_id)
+                .and_then(|result| extract_value_from_path(result, path))
+                .cloned()
+                .unwrap_or(Value::Null),
+            _ => resolve_value(property.get("value").unwrap_or(&Value::Null), ctx.variables, ctx.results),
+        };
+        param.insert(key.to_string(), value);
+    }
+    let input = Value::Object(param);
+
+    let params = ExecuteFileParams::with_run_dir(
+        find_project_path(Path::new(file_path)).display().to_string(),
+        file_path.to_string(),
+        input.to_string(),
+        name.to_string(),
+        ctx.run_id.to_string(),
+        ctx.run_dir.display().to_string(),
+    );
+
+    match node_system::execute_file_by_type(params).await {
+        Ok(stdout) => {
+            // stdout이 JSON이면 구조화해서 참조할 수 있게 함
+            let output = serde_json::from_str::<Value>(stdout.trim()).unwrap_or(Value::String(stdout));
+            NodeOutcome {
+                status: NodeStatus::Passed,
+                input: Some(input),
+                context: Some(json!({ "status": "success", "result": output.clone() })),
+                output: Some(output),
+                error: None,
+                stderr: None,
+                logs: Vec::new(),
+            }
+        }
+        Err(stderr) => {
+            let message = stderr.lines().find(|l| !l.trim().is_empty()).unwrap_or("Execution failed").to_string();
+            NodeOutcome {
+                stderr: Some(stderr),
+                ..NodeOutcome::error(Some(input), message)
+            }
+        }
+    }
+}
+
+/// 서브플로우 결과로 돌려줄 노드: isOutput으로 지정한 노드, 없으면 실행 순서의 마지막 노드
+fn output_node_id<'a>(graph: &'a FlowGraph, order: &[usize]) -> Option<&'a str> {
+    graph
+        .nodes
+        .iter()
+        .find(|node| node.data.get("isOutput").and_then(Value::as_bool).unwrap_or(false))
+        .or_else(|| order.last().map(|&index| &graph.nodes[index]))
+        .map(|node| node.id.as_str())
+}
+
+/// 다른 페이지의 그래프를 함수처럼 실행
+///
+/// inputs([{key, value, enabled}])는 변수로 전달되어 호출된 페이지에서 {{key}}로 쓰고,
+/// 호출된 페이지의 출력 노드 결과가 이 노드의 결과({{nodeId.result...}})가 된다.
+async fn run_subflow_node(node: &FlowNode, ctx: &NodeContext<'_>) -> NodeOutcome {
+    let data = &node.data;
+    let Some(page_id) = data.get("pageId").and_then(Value::as_i64).map(|id| id as i32) else {
+        return NodeOutcome::error(None, "Sub-flow page is required".to_string());
+    };
+
+    let mut variables = ctx.variables.clone();
+    let mut inputs = serde_json::Map::new();
+    for (key, value) in enabled_pairs(data, "inputs") {
+        let value = ctx.resolve(value);
+        inputs.insert(key.to_string(), Value::String(value.clone()));
+        variables.insert(key.to_string(), value);
+    }
+    let input = json!({ "pageId": page_id, "inputs": inputs });
+
+    let mut call_stack = ctx.options.call_stack.clone();
+    call_stack.extend(ctx.page_id);
+    if call_stack.contains(&page_id) {
+        return NodeOutcome::error(Some(input), format!("Sub-flow page {} calls itself recursively", page_id));
+    }
+
+    let graph = match FlowGraph::load_page(page_id).await {
+        Ok(graph) => graph,
+        Err(e) => return NodeOutcome::error(Some(input), e),
+    };
+    let options = FlowRunOptions {
+        project_name: ctx.options.project_name.clone(),
+        run_id: ctx.run_id.to_string(),
+        variables,
+        fail_fast: ctx.options.fail_fast,
+        run_dir: Some(ctx.run_dir.join("subflows").join(&node.id)),
+        call_stack,
+    };
+
+    let (report, results) = match run_graph(&graph, &options, ctx.on_event).await {
+        Ok(run) => run,
+        Err(e) => return NodeOutcome::error(Some(input), e),
+    };
+
+    let order = graph.execution_order().unwrap_or_default();
+    let output_id = str_field(data, "outputNodeId").or_else(|| output_node_id(&graph, &order));
+    let result = output_id.and_then(|id| results.get(id)).cloned();
+    let logs = report
+        .nodes
+        .iter()
+        .map(|n| format!("{} [{}] {:?}", n.node_name, n.node_type, n.status))
+        .collect();
+    let passed = report.success() && result.is_some();
+    NodeOutcome {
+        status: if passed { NodeStatus::Passed } else { NodeStatus::Failed },
+        input: Some(input),
+        output: Some(json!({
+            "page": report.title,
+            "runDir": report.run_dir,
+            "outputNodeId": output_id,
+            "passed": report.count(NodeStatus::Passed),
+            "failed": report.count(NodeStatus::Failed) + report.count(NodeStatus::Error),
+            "skipped": report.count(NodeStatus::Skipped),
+            "result": result.as_ref().and_then(|r| r.get("result")),
+        })),
+        context: if passed { result } else { None },
+        error: (!passed).then(|| match output_id {
+            Some(id) if report.success() => format!("Output node '{}' of sub-flow '{}' has no result", id, report.title),
+            Some(_) => format!("Sub-flow '{}' did not pass", report.title),
+            None => format!("Sub-flow '{}' has no nodes", report.title),
+        }),
+        stderr: None,
+        logs,
+    }
+}
+
+async fn execute_node(node: &FlowNode, name: &str, ctx: &NodeContext<'_>) -> NodeOutcome {
+    match node_kind(node) {
+        "api" => run_api_node(&node.data, ctx).await,
+        "db" => run_db_node(&node.data, name, ctx).await,
+        "file" => run_file_node(&node.data, name, ctx).await,
+        "subflow" => run_subflow_node(node, ctx).await,
+        _ => NodeOutcome::error(None, format!("Unsupported node type: {}", node.node_type)),
+    }
+}
+
+fn finished(node: &FlowNode, name: String, outcome: NodeOutcome, started: Instant) -> NodeRunResult {
+    NodeRunResult {
+        node_id: node.id.clone(),
+        node_name: name,
+        node_type: node_kind(node).to_string(),
+        status: outcome.status,
+        duration_ms: started.elapsed().as_millis() as u64,
+        input: outcome.input,
+        output: outcome.output,
+        error: outcome.error,
+        stderr: outcome.stderr,
+        logs: outcome.logs,
+    }
+}
+
+fn skipped(node: &FlowNode, name: String, reason: String) -> NodeRunResult {
+    NodeRunResult {
+        node_id: node.id.clone(),
+        node_name: name,
+        node_type: node_kind(node).to_string(),
+        status: NodeStatus::Skipped,
+        duration_ms: 0,
+        input: None,
+        output: None,
+        error: None,
+        stderr: None,
+        logs: vec![reason],
+    }
+}
+
+/// 에디터에서 서브플로우 노드 하나를 실행
+///
+/// inputs 값의 {{nodeId.path}} 참조는 프론트엔드가 자신의 실행 결과로 치환해서 넘기고,
+/// 호출된 페이지의 실행 결과는 호출한 페이지의 <run_dir>/subflows/<nodeId>에 저장된다.
+pub async fn run_subflow(node: FlowNode, caller_page_id: i32, run_id: String) -> Result<NodeRunResult, String> {
+    let run_dir = get_run_dir(None, caller_page_id, &run_id).await?;
+    let options = FlowRunOptions { run_id, ..Default::default() };
+    let results = HashMap::new();
+    let ctx = NodeContext {
+        variables: &options.variables,
+        results: &results,
+        page_id: Some(caller_page_id),
+        run_id: &options.run_id,
+        run_dir: &run_dir,
+        options: &options,
+        on_event: &|_: FlowEvent<'_>| {},
+    };
+    let name = node_name(&node);
+    let started = Instant::now();
+    let outcome = run_subflow_node(&node, &ctx).await;
+    Ok(finished(&node, name, outcome, started))
+}
+
+/// 페이지 그래프를 위상 순서대로 실행
+///
+/// 선행 노드가 통과하지 못한 노드는 건너뛰고, 실행 결과는 <run_dir>에 저장된다.
+pub async fn run_flow(
+    graph: &FlowGraph,
+    options: &FlowRunOptions,
+    on_event: &(dyn Fn(FlowEvent<'_>) + Send + Sync),
+) -> Result<FlowRunReport, String> {
+    run_graph(graph, options, on_event).await.map(|(report, _)| report)
+}
+
+type GraphRun = Result<(FlowRunReport, HashMap<String, Value>), String>;
+
+/// run_flow 본체 - 서브플로우가 출력 노드 결과를 꺼낼 수 있도록 노드별 결과도 반환
+/// (서브플로우 노드에서 재귀 호출되므로 Box로 감싼 Future를 반환)
+fn run_graph<'a>(
+    graph: &'a FlowGraph,
+    options: &'a FlowRunOptions,
+    on_event: &'a (dyn Fn(FlowEvent<'_>) + Send + Sync),
+) -> Pin<Box<dyn Future<Output = GraphRun> + Send + 'a>> {
+    Box::pin(async move {
+        let order = graph.execution_order()?;
+        let run_dir = match &options.run_dir {
+            Some(dir) => dir.clone(),
+            None => get_local_request_json_path(
+                options.project_name.clone().unwrap_or_else(|| "root".to_string()),
+                graph.title.clone(),
+            )?
+            .join(&options.run_id),
+        };
+        std::fs::create_dir_all(&run_dir)
+            .map_err(|e| format!("Failed to create run directory {}: {}", run_dir.display(), e))?;
+
+        let started_at = chrono::Local::now().to_rfc3339();
+        let flow_started = Instant::now();
+        let mut statuses: HashMap<&str, NodeStatus> = HashMap::new();
+        let mut results: HashMap<String, Value> = HashMap::new();
+        let mut node_results = Vec::with_capacity(order.len());
+        let mut has_failure = false;
+
+        for index in order {
+            let node = &graph.nodes[index];
+            let name = node_name(node);
+
+            let blocked = graph
+                .predecessors(&node.id)
+                .into_iter()
+                .find(|id| statuses.get(id) != Some(&NodeStatus::Passed));
+            let skip_reason = if options.fail_fast && has_failure {
+                Some("Skipped after an earlier failure (fail-fast)".to_string())
+            } else {
+                blocked.map(|id| format!("Skipped because upstream node '{}' did not pass", id))
+            };
+
+            let result = match skip_reason {
+                Some(reason) => skipped(node, name, reason),
+                None => {
+                    on_event(FlowEvent::NodeStarted { flow: &graph.title, node_id: &node.id, node_name: &name });
+                    let ctx = NodeContext {
+                        variables: &options.variables,
+                        results: &results,
+                        page_id: graph.page_id,
+                        run_id: &options.run_id,
+                        run_dir: &run_dir,
+                        options,
+                        on_event,
+                    };
+                    let started = Instant::now();
+                    let mut outcome = execute_node(node, &name, &ctx).await;
+                    if let Some(context) = outcome.context.take() {
+                        results.insert(node.id.clone(), context);
+                    }
+                    finished(node, name, outcome, started)
+                }
+            };
+
+            has_failure |= matches!(result.status, NodeStatus::Failed | NodeStatus::Error);
+            statuses.insert(&node.id, result.status);
+            on_event(FlowEvent::NodeFinished { flow: &graph.title, result: &result });
+            node_results.push(result);
+        }
+
+        let report = FlowRunReport {
+            title: graph.title.clone(),
+            page_id: graph.page_id,
+            run_id: options.run_id.clone(),
+            run_dir: run_dir.display().to_string(),
+            started_at,
+            duration_ms: flow_started.elapsed().as_millis() as u64,
+            nodes: node_results,
+        };
+
+        // 실행 디렉터리에 JUnit/HTML 리포트 저장 (실패해도 실행 결과는 반환)
+        if let Err(e) = flow_report::write_run_reports(&report) {
+            error!("❌ Failed to write run reports: {}", e);
+        }
+        Ok((report, results))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph(nodes: &[&str], edges: &[(&str, &str)]) -> FlowGraph {
+        FlowGraph {
+            page_id: None,
+            title: "test".to_string(),
+            nodes: nodes
+                .iter()
+                .map(|id| FlowNode { id: id.to_string(), node_type: "custom".to_string(), data: json!({}) })
+                .collect(),
+            edges: edges
+                .iter()
+                .map(|(s, t)| FlowEdge { source: s.to_string(), target: t.to_string() })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_resolve_template_variables_and_references() {
+        let variables = HashMap::from([("baseUrl".to_string(), "https://api.test".to_string())]);
+        let results = HashMap::from([(
+            "login".to_string(),
+            json!({ "status": "success", "result": { "data": { "token": "abc", "ids": [7, 8] } } }),
+        )]);
+
+        assert_eq!(
+            resolve_template("{{baseUrl}}/items/{{login.result.data.ids[1]}}?t={{login.result.data.token}}", &variables, &results),
+            "https://api.test/items/8?t=abc"
+        );
+        assert_eq!(resolve_template("{{missing}} {{login.result.none}}", &variables, &results), "{{missing}} {{login.result.none}}");
+    }
+
+    #[test]
+    fn test_execution_order_and_cycle() {
+        let g = graph(&["c", "a", "b"], &[("a", "b"), ("b", "c")]);
+        let order: Vec<&str> = g.execution_order().unwrap().into_iter().map(|i| g.nodes[i].id.as_str()).collect();
+        assert_eq!(order, vec!["a", "b", "c"]);
+
+        assert!(graph(&["a", "b"], &[("a", "b"), ("b", "a")]).execution_order().is_err());
+    }
+
+    #[test]
+    fn test_parse_flat_and_postman_variables() {
+        let flat = parse_variables(r#"{"host": "localhost", "port": 8080}"#).unwrap();
+        assert_eq!(flat["port"], "8080");
+
+        let postman = parse_variables(
+            r#"{"name": "dev", "values": [{"key": "token", "value": "t", "enabled": true}, {"key": "off", "value": "x", "enabled": false}]}"#,
+        )
+        .unwrap();
+        assert_eq!(postman.len(), 1);
+        assert_eq!(postman["token"], "t");
+    }
+
+    #[test]
+    fn test_skip_nodes_downstream_of_failure() {
+        let g = graph(&["a", "b", "c"], &[("a", "b")]);
+        let options = FlowRunOptions {
+            run_id: "run_test".to_string(),
+            run_dir: Some(std::env::temp_dir().join(format!("itol-flow-{}", std::process::id())).join("run_test")),
+            ..Default::default()
+        };
+
+        let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+        let report = runtime.block_on(run_flow(&g, &options, &|_| {})).unwrap();
+        let statuses: Vec<NodeStatus> = report.nodes.iter().map(|n| n.status).collect();
+        // 알 수 없는 노드 타입은 에러, 그 후속 노드는 건너뜀
+        assert_eq!(statuses, vec![NodeStatus::Error, NodeStatus::Error, NodeStatus::Skipped]);
+        assert!(!report.success());
+        assert!(Path::new(&report.run_dir).join("junit.xml").exists());
+        assert!(Path::new(&report.run_dir).join("report.html").exists());
+        let _ = std::fs::remove_dir_all(Path::new(&report.run_dir).parent().unwrap());
+    }
+
+    #[test]
+    fn test_subflow_output_node_and_recursion() {
+        let mut g = graph(&["login", "token", "log"], &[("login", "token"), ("login", "log")]);
+        let order = g.execution_order().unwrap();
+        assert_eq!(output_node_id(&g, &order), Some("log"));
+        g.nodes[1].data = json!({ "isOutput": true });
+        assert_eq!(output_node_id(&g, &order), Some("token"));
+
+        // 자기 자신을 호출하는 서브플로우는 DB를 읽기 전에 에러
+        let mut g = graph(&["call"], &[]);
+        g.page_id = Some(5);
+        g.nodes[0].data = json!({ "type": "subflow", "pageId": 5, "inputs": [{ "key": "user", "value": "{{name}}" }] });
+        let options = FlowRunOptions {
+            run_id: "run_subflow".to_string(),
+            run_dir: Some(std::env::temp_dir().join(format!("itol-subflow-{}", std::process::id())).join("run_subflow")),
+            variables: HashMap::from([("name".to_string(), "kim".to_string())]),
+            ..Default::default()
+        };
+
+        let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+        let report = runtime.block_on(run_flow(&g, &options, &|_| {})).unwrap();
+        assert_eq!(report.nodes[0].node_type, "subflow");
+        assert_eq!(report.nodes[0].status, NodeStatus::Error);
+        assert_eq!(report.nodes[0].input.as_ref().unwrap()["inputs"]["user"], "kim");
+        assert!(report.nodes[0].error.as_deref().unwrap().contains("recursively"));
+        let _ = std::fs::remove_dir_all(Path::new(&report.run_dir).parent().unwrap());
+    }
+}
@@ -1,4 +1,4 @@
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 use std::fs::{self};
 use std::path::{Path, PathBuf};
 use std::process::{Command};
@@ -12,15 +12,6 @@ use std::sync::Mutex;
 use once_cell::sync::Lazy;
 use log::{debug, info, warn, error};
 
-#[derive(Debug, Serialize, Clone)]
-struct ExecutionLogEvent {
-    node_id: String,
-    node_name: String,
-    log_type: String, // "stdout", "stderr", "info", "error", "success"
-    message: String,
-    run_id: String,
-}
-
 #[derive(Debug, Deserialize)]
 pub struct ExecuteFileParams {
     project_path: String,
@@ -29,7 +20,33 @@ pub struct ExecuteFileParams {
     project_id: Option<i32>,
     page_id: i32,
     node_name: String,
-    run_id: String
+    run_id: String,
+    /// 실행 디렉터리(<...>/<run_id>)를 직접 지정 - 지정하면 book/page 조회 없이 저장
+    #[serde(default)]
+    run_dir: Option<String>,
+}
+
+impl ExecuteFileParams {
+    /// 플로우 러너에서 사용하는 생성자 (실행 디렉터리를 직접 지정)
+    pub fn with_run_dir(
+        project_path: String,
+        file_path: String,
+        param: String,
+        node_name: String,
+        run_id: String,
+        run_dir: String,
+    ) -> Self {
+        ExecuteFileParams {
+            project_path,
+            file_path,
+            param,
+            project_id: None,
+            page_id: 0,
+            node_name,
+            run_id,
+            run_dir: Some(run_dir),
+        }
+    }
 }
 
 // 파라미터 검증 함수 추가
@@ -77,6 +94,21 @@ async fn prepare_execution_context(params: &ExecuteFileParams) -> Result<(String
     info!("  - node_name: {}", params.node_name);
     info!("  - run_id: {}", params.run_id);
 
+    // 실행 디렉터리가 지정된 경우 그 부모를 기준으로 <run_id>/<node>.json 저장
+    if let Some(run_dir) = &params.run_dir {
+        let base = Path::new(run_dir)
+            .parent()
+            .ok_or_else(|| format!("Invalid run directory: {}", run_dir))?
+            .to_path_buf();
+        let json_path = save_request_json(
+            base,
+            params.node_name.clone(),
+            params.param.clone(),
+            params.run_id.clone()
+        )?;
+        return Ok((String::new(), String::new(), json_path));
+    }
+
     let project_name = match params.project_id {
         Some(id) => book::get_book_by_id(id)
             .await
@@ -701,7 +733,7 @@ fn find_typescript_files(project_path: &str) -> Result<Vec<String>, String> {
     if let Ok(entries) = fs::read_dir(project_dir) {
         for entry in entries.flatten() {
             let path = entry.path();
-            if path.is_file() && path.extension().map_or(false, |ext| ext == "ts") {
+            if path.is_file() && path.extension().is_some_and(|ext| ext == "ts") {
                 if let Some(path_str) = path.to_str() {
                     ts_files.push(path_str.to_string());
                 }
@@ -724,7 +756,7 @@ fn find_ts_files_recursive(dir: &Path, ts_files: &mut Vec<String>) -> Result<(),
     if let Ok(entries) = fs::read_dir(dir) {
         for entry in entries.flatten() {
             let path = entry.path();
-            if path.is_file() && path.extension().map_or(false, |ext| ext == "ts") {
+            if path.is_file() && path.extension().is_some_and(|ext| ext == "ts") {
                 if let Some(path_str) = path.to_str() {
                     ts_files.push(path_str.to_string());
                 }
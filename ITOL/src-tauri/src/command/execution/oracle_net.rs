@@ -0,0 +1,318 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// Oracle Net 접속 옵션 (TNS alias, 연결 디스크립터, 지갑, 관리자 권한)
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct OracleNetOptions {
+    /// tnsnames.ora에 정의된 alias
+    #[serde(rename = "tnsAlias")]
+    pub tns_alias: Option<String>,
+    /// tnsnames.ora가 있는 디렉터리 (프로필마다 다르게 지정 가능)
+    #[serde(rename = "tnsAdmin")]
+    pub tns_admin: Option<String>,
+    /// 전체 연결 디스크립터 "(DESCRIPTION=...)"
+    #[serde(rename = "connectDescriptor")]
+    pub connect_descriptor: Option<String>,
+    /// TCPS 접속에 사용할 지갑 디렉터리
+    #[serde(rename = "walletLocation")]
+    pub wallet_location: Option<String>,
+    /// 사용자/비밀번호 대신 외부 인증(지갑 자격 증명, OS 인증) 사용
+    #[serde(rename = "externalAuth")]
+    pub external_auth: Option<bool>,
+    /// sysdba | sysoper | sysasm | sysbackup | sysdg | syskm | sysrac
+    pub privilege: Option<String>,
+}
+
+impl OracleNetOptions {
+    /// host/port 대신 디스크립터나 alias로 접속하는지 여부
+    pub fn uses_named_target(&self) -> bool {
+        non_empty(&self.connect_descriptor).is_some() || non_empty(&self.tns_alias).is_some()
+    }
+}
+
+/// 접속에 필요한 값을 모두 정리한 Oracle 연결 대상
+#[derive(Debug, Clone)]
+pub struct OracleConnectTarget {
+    pub username: String,
+    pub password: String,
+    pub connect_string: String,
+    pub privilege: Option<oracle::Privilege>,
+    pub external_auth: bool,
+}
+
+impl OracleConnectTarget {
+    /// Oracle 연결 생성 (동기 방식이므로 blocking task 안에서 호출)
+    pub fn connect(&self) -> oracle::Result<oracle::Connection> {
+        let mut connector = oracle::Connector::new(
+            self.username.as_str(),
+            self.password.as_str(),
+            self.connect_string.as_str(),
+        );
+        if let Some(privilege) = self.privilege {
+            connector.privilege(privilege);
+        }
+        if self.external_auth {
+            connector.external_auth(true);
+        }
+        connector.connect()
+    }
+}
+
+fn non_empty(value: &Option<String>) -> Option<&str> {
+    value.as_deref().map(str::trim).filter(|v| !v.is_empty())
+}
+
+pub fn parse_privilege(value: Option<&str>) -> Result<Option<oracle::Privilege>, String> {
+    use oracle::Privilege;
+
+    let Some(value) = value.map(str::trim).filter(|v| !v.is_empty()) else {
+        return Ok(None);
+    };
+    match value.to_ascii_lowercase().as_str() {
+        "normal" => Ok(None),
+        "sysdba" => Ok(Some(Privilege::Sysdba)),
+        "sysoper" => Ok(Some(Privilege::Sysoper)),
+        "sysasm" => Ok(Some(Privilege::Sysasm)),
+        "sysbackup" => Ok(Some(Privilege::Sysbackup)),
+        "sysdg" => Ok(Some(Privilege::Sysdg)),
+        "syskm" => Ok(Some(Privilege::Syskm)),
+        "sysrac" => Ok(Some(Privilege::Sysrac)),
+        other => Err(format!("Unsupported Oracle privilege: {}", other)),
+    }
+}
+
+/// Easy Connect 문자열 생성 (service name 우선, 없으면 SID)
+pub fn easy_connect_string(
+    host: &str,
+    port: u16,
+    service_name: Option<&str>,
+    sid: Option<&str>,
+) -> Result<String, String> {
+    if let Some(service) = service_name {
+        Ok(format!("//{}:{}/{}", host, port, service))
+    } else if let Some(sid_val) = sid {
+        Ok(format!("{}:{}/{}", host, port, sid_val))
+    } else {
+        Err("Either service_name or sid must be provided for Oracle connection".to_string())
+    }
+}
+
+/// 접속 옵션에 따라 Oracle 연결 문자열 생성
+///
+/// 우선순위: 연결 디스크립터 > TNS alias > host/port
+pub fn build_connect_string(
+    host: Option<&str>,
+    port: u16,
+    service_name: Option<&str>,
+    sid: Option<&str>,
+    options: &OracleNetOptions,
+) -> Result<String, String> {
+    let wallet = non_empty(&options.wallet_location);
+
+    let descriptor = if let Some(descriptor) = non_empty(&options.connect_descriptor) {
+        descriptor.to_string()
+    } else if let Some(alias) = non_empty(&options.tns_alias) {
+        match non_empty(&options.tns_admin) {
+            Some(tns_admin) => {
+                let path = Path::new(tns_admin).join("tnsnames.ora");
+                let content = fs::read_to_string(&path)
+                    .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+                find_tns_alias(&content, alias)
+                    .ok_or_else(|| format!("TNS alias '{}' not found in {}", alias, path.display()))?
+            }
+            // TNS_ADMIN이 없으면 Oracle 클라이언트의 기본 설정으로 alias를 해석
+            None if wallet.is_none() => return Ok(alias.to_string()),
+            None => return Err("tnsAdmin is required to use walletLocation with tnsAlias".to_string()),
+        }
+    } else {
+        let host = host.ok_or("Oracle host is required")?;
+        if wallet.is_none() {
+            return easy_connect_string(host, port, service_name, sid);
+        }
+
+        // 지갑을 쓰는 경우 TCPS 디스크립터로 접속
+        let connect_data = match (service_name, sid) {
+            (Some(service), _) => format!("(SERVICE_NAME={})", service),
+            (None, Some(sid)) => format!("(SID={})", sid),
+            (None, None) => {
+                return Err("Either service_name or sid must be provided for Oracle connection".to_string())
+            }
+        };
+        format!(
+            "(DESCRIPTION=(ADDRESS=(PROTOCOL=TCPS)(HOST={})(PORT={}))(CONNECT_DATA={}))",
+            host, port, connect_data
+        )
+    };
+
+    match wallet {
+        Some(wallet) => add_wallet_directory(&descriptor, wallet),
+        None => Ok(descriptor),
+    }
+}
+
+/// tnsnames.ora 내용에서 alias에 해당하는 디스크립터를 찾음
+///
+/// "ALIAS1, ALIAS2 = (DESCRIPTION=...)" 형식과 도메인이 붙은 이름(ORCL.WORLD)을 지원한다.
+pub fn find_tns_alias(tnsnames: &str, alias: &str) -> Option<String> {
+    // '#' 이후는 주석
+    let text: Vec<char> = tnsnames
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or(""))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .chars()
+        .collect();
+
+    let mut i = 0;
+    while i < text.len() {
+        let names_start = i;
+        while i < text.len() && text[i] != '=' {
+            i += 1;
+        }
+        if i >= text.len() {
+            break;
+        }
+        let names: String = text[names_start..i].iter().collect();
+        i += 1;
+
+        while i < text.len() && text[i].is_whitespace() {
+            i += 1;
+        }
+        let value_start = i;
+        if i < text.len() && text[i] == '(' {
+            // 괄호 짝이 맞을 때까지가 값
+            let mut depth = 0;
+            while i < text.len() {
+                match text[i] {
+                    '(' => depth += 1,
+                    ')' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            i += 1;
+                            break;
+                        }
+                    }
+                    _ => {}
+                }
+                i += 1;
+            }
+        } else {
+            // IFILE 등 괄호 없는 값은 줄 끝까지
+            while i < text.len() && text[i] != '\n' {
+                i += 1;
+            }
+        }
+        let value: String = text[value_start..i].iter().collect();
+
+        let matches = names.split(',').map(str::trim).any(|name| {
+            name.eq_ignore_ascii_case(alias)
+                || name.split('.').next().is_some_and(|short| short.eq_ignore_ascii_case(alias))
+        });
+        if matches {
+            return Some(value.split_whitespace().collect::<Vec<_>>().join(" "));
+        }
+    }
+    None
+}
+
+/// 디스크립터에 지갑 디렉터리(SECURITY=(MY_WALLET_DIRECTORY=...)) 추가
+fn add_wallet_directory(descriptor: &str, wallet: &str) -> Result<String, String> {
+    let upper = descriptor.to_ascii_uppercase();
+    if upper.contains("MY_WALLET_DIRECTORY") {
+        return Ok(descriptor.to_string());
+    }
+
+    let compact: String = upper.chars().filter(|c| !c.is_whitespace()).collect();
+    if !compact.starts_with("(DESCRIPTION=") {
+        return Err(
+            "walletLocation can only be applied to a single (DESCRIPTION=...); add MY_WALLET_DIRECTORY to the descriptor instead"
+                .to_string(),
+        );
+    }
+
+    let end = descriptor.trim_end().len() - 1;
+    Ok(format!(
+        "{}(SECURITY=(MY_WALLET_DIRECTORY=\"{}\")){}",
+        &descriptor[..end],
+        wallet,
+        &descriptor[end..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TNSNAMES: &str = "
+# DBA managed
+ORCL.WORLD, ORCL_ALT =
+  (DESCRIPTION =
+    (ADDRESS = (PROTOCOL = TCP)(HOST = db1.corp)(PORT = 1521))
+    (CONNECT_DATA = (SERVICE_NAME = orcl.corp))  # primary
+  )
+
+REPORTING=(DESCRIPTION=(ADDRESS=(PROTOCOL=TCPS)(HOST=db2)(PORT=2484))(CONNECT_DATA=(SID=rpt)))
+";
+
+    #[test]
+    fn test_find_tns_alias() {
+        assert_eq!(
+            find_tns_alias(TNSNAMES, "orcl").as_deref(),
+            Some("(DESCRIPTION = (ADDRESS = (PROTOCOL = TCP)(HOST = db1.corp)(PORT = 1521)) (CONNECT_DATA = (SERVICE_NAME = orcl.corp)) )")
+        );
+        assert_eq!(find_tns_alias(TNSNAMES, "ORCL_ALT"), find_tns_alias(TNSNAMES, "orcl.world"));
+        assert_eq!(
+            find_tns_alias(TNSNAMES, "reporting").as_deref(),
+            Some("(DESCRIPTION=(ADDRESS=(PROTOCOL=TCPS)(HOST=db2)(PORT=2484))(CONNECT_DATA=(SID=rpt)))")
+        );
+        assert_eq!(find_tns_alias(TNSNAMES, "missing"), None);
+    }
+
+    #[test]
+    fn test_build_connect_string_priority_and_wallet() {
+        let easy = build_connect_string(Some("db"), 1521, Some("svc"), None, &OracleNetOptions::default());
+        assert_eq!(easy.unwrap(), "//db:1521/svc");
+
+        let options = OracleNetOptions {
+            connect_descriptor: Some("(DESCRIPTION=(ADDRESS=(HOST=a)(PORT=1))(CONNECT_DATA=(SERVICE_NAME=s)))".to_string()),
+            tns_alias: Some("ignored".to_string()),
+            wallet_location: Some("/wallets/app".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            build_connect_string(None, 1521, None, None, &options).unwrap(),
+            "(DESCRIPTION=(ADDRESS=(HOST=a)(PORT=1))(CONNECT_DATA=(SERVICE_NAME=s))(SECURITY=(MY_WALLET_DIRECTORY=\"/wallets/app\")))"
+        );
+
+        let alias_only = OracleNetOptions {
+            tns_alias: Some("PROD".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(build_connect_string(None, 1521, None, None, &alias_only).unwrap(), "PROD");
+
+        let tns_admin = std::env::temp_dir().join(format!("itol_tns_{}", std::process::id()));
+        fs::create_dir_all(&tns_admin).unwrap();
+        fs::write(tns_admin.join("tnsnames.ora"), TNSNAMES).unwrap();
+        let with_admin = OracleNetOptions {
+            tns_alias: Some("reporting".to_string()),
+            tns_admin: Some(tns_admin.to_string_lossy().to_string()),
+            ..Default::default()
+        };
+        assert!(build_connect_string(None, 1521, None, None, &with_admin).unwrap().contains("(SID=rpt)"));
+        let missing = OracleNetOptions {
+            tns_alias: Some("nope".to_string()),
+            ..with_admin
+        };
+        assert!(build_connect_string(None, 1521, None, None, &missing).is_err());
+        let _ = fs::remove_dir_all(&tns_admin);
+    }
+
+    #[test]
+    fn test_parse_privilege() {
+        assert!(matches!(parse_privilege(Some("SYSDBA")), Ok(Some(oracle::Privilege::Sysdba))));
+        assert!(matches!(parse_privilege(Some("sysoper")), Ok(Some(oracle::Privilege::Sysoper))));
+        assert!(matches!(parse_privilege(None), Ok(None)));
+        assert!(parse_privilege(Some("root")).is_err());
+    }
+}
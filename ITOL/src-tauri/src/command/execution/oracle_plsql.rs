@@ -0,0 +1,353 @@
+use oracle::sql_type::{OracleType, RefCursor, ToSql};
+use serde::{Deserialize, Serialize};
+
+use super::db_system::oracle_row_to_values;
+use super::oracle_net::OracleConnectTarget;
+
+/// PL/SQL 블록/프로시저 호출에 사용할 바인드 변수
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct OracleBind {
+    /// 바인드 이름 (":" 없이), procedure 모드에서는 파라미터 이름으로도 사용
+    pub name: String,
+    /// "in" | "out" | "inout" | "return" (함수 반환값), 기본값 "in"
+    pub direction: Option<String>,
+    /// "varchar2" | "number" | "integer" | "date" | "timestamp" | "clob" | "refcursor", 없으면 값으로 추론
+    #[serde(rename = "type")]
+    pub data_type: Option<String>,
+    pub value: Option<serde_json::Value>,
+    /// OUT VARCHAR2 버퍼 크기
+    pub size: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BindDirection {
+    In,
+    Out,
+    InOut,
+    Return,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum BindType {
+    Varchar2(u32),
+    Number,
+    Integer,
+    Date,
+    Timestamp,
+    Clob,
+    RefCursor,
+}
+
+impl BindType {
+    fn oracle_type(&self) -> OracleType {
+        match self {
+            BindType::Varchar2(size) => OracleType::Varchar2(*size),
+            BindType::Number => OracleType::Number(0, -127),
+            BindType::Integer => OracleType::Int64,
+            BindType::Date => OracleType::Date,
+            BindType::Timestamp => OracleType::Timestamp(6),
+            BindType::Clob => OracleType::CLOB,
+            BindType::RefCursor => OracleType::RefCursor,
+        }
+    }
+}
+
+/// 바인드 설정을 검증하고 방향/타입을 확정한 형태
+#[derive(Debug, Clone, PartialEq)]
+struct ResolvedBind {
+    name: String,
+    direction: BindDirection,
+    bind_type: BindType,
+    value: serde_json::Value,
+}
+
+fn resolve_bind(bind: &OracleBind) -> Result<ResolvedBind, String> {
+    let name = bind.name.trim().trim_start_matches(':').to_string();
+    if name.is_empty() {
+        return Err("Bind name is required".to_string());
+    }
+
+    let direction = match bind.direction.as_deref().map(str::to_ascii_lowercase).as_deref() {
+        None | Some("in") => BindDirection::In,
+        Some("out") => BindDirection::Out,
+        Some("inout") | Some("in out") | Some("in_out") => BindDirection::InOut,
+        Some("return") => BindDirection::Return,
+        Some(other) => return Err(format!("Unsupported bind direction for {}: {}", name, other)),
+    };
+
+    let value = bind.value.clone().unwrap_or(serde_json::Value::Null);
+    let size = bind.size.unwrap_or(4000);
+    let bind_type = match bind.data_type.as_deref().map(str::to_ascii_lowercase).as_deref() {
+        Some("varchar2") | Some("varchar") | Some("string") | Some("char") => BindType::Varchar2(size),
+        Some("number") | Some("float") => BindType::Number,
+        Some("integer") | Some("int") => BindType::Integer,
+        Some("date") => BindType::Date,
+        Some("timestamp") => BindType::Timestamp,
+        Some("clob") => BindType::Clob,
+        Some("refcursor") | Some("ref cursor") | Some("sys_refcursor") | Some("cursor") => BindType::RefCursor,
+        Some(other) => return Err(format!("Unsupported bind type for {}: {}", name, other)),
+        // 타입이 없으면 값으로 추론 (정수 > 실수 > 문자열)
+        None => match &value {
+            serde_json::Value::Number(n) if n.is_i64() => BindType::Integer,
+            serde_json::Value::Number(_) => BindType::Number,
+            serde_json::Value::Bool(_) => BindType::Integer,
+            _ => BindType::Varchar2(size),
+        },
+    };
+
+    if bind_type == BindType::RefCursor && !matches!(direction, BindDirection::Out | BindDirection::Return) {
+        return Err(format!("REF CURSOR bind {} must be an OUT parameter", name));
+    }
+
+    Ok(ResolvedBind { name, direction, bind_type, value })
+}
+
+/// procedure 모드: 프로시저/함수 이름과 바인드로 호출 블록 생성
+///
+/// 예) `BEGIN :result := pkg.fn(p_id => :p_id, p_cur => :p_cur); END;`
+pub fn build_call_block(procedure: &str, binds: &[OracleBind]) -> Result<String, String> {
+    let procedure = procedure.trim().trim_end_matches(';');
+    let valid_name = !procedure.is_empty()
+        && procedure.chars().all(|c| c.is_alphanumeric() || matches!(c, '_' | '$' | '#' | '.' | '"' | '@'));
+    if !valid_name {
+        return Err(format!("Invalid procedure name: {}", procedure));
+    }
+
+    let mut return_bind = None;
+    let mut args = Vec::new();
+    for bind in binds {
+        let resolved = resolve_bind(bind)?;
+        if resolved.direction == BindDirection::Return {
+            if return_bind.is_some() {
+                return Err("Only one return bind is allowed".to_string());
+            }
+            return_bind = Some(resolved.name);
+        } else {
+            args.push(format!("{0} => :{0}", resolved.name));
+        }
+    }
+
+    let call = if args.is_empty() {
+        procedure.to_string()
+    } else {
+        format!("{}({})", procedure, args.join(", "))
+    };
+    Ok(match return_bind {
+        Some(name) => format!("BEGIN :{} := {}; END;", name, call),
+        None => format!("BEGIN {}; END;", call),
+    })
+}
+
+/// IN 값을 바인드 타입에 맞는 Rust 값으로 변환
+enum InValue {
+    Null,
+    Text(String),
+    Int(i64),
+    Float(f64),
+}
+
+fn in_value(bind: &ResolvedBind) -> Result<InValue, String> {
+    use serde_json::Value;
+
+    Ok(match (&bind.bind_type, &bind.value) {
+        (_, Value::Null) => InValue::Null,
+        (BindType::Integer, Value::Bool(b)) => InValue::Int(*b as i64),
+        (BindType::Integer, Value::Number(n)) => {
+            InValue::Int(n.as_i64().ok_or_else(|| format!("Bind {} is not an integer", bind.name))?)
+        }
+        (BindType::Number, Value::Number(n)) => match n.as_i64() {
+            Some(i) => InValue::Int(i),
+            None => InValue::Float(n.as_f64().unwrap_or_default()),
+        },
+        (BindType::Integer | BindType::Number, Value::String(s)) => match s.trim().parse::<i64>() {
+            Ok(i) => InValue::Int(i),
+            Err(_) => InValue::Float(
+                s.trim()
+                    .parse::<f64>()
+                    .map_err(|_| format!("Bind {} is not a number: {}", bind.name, s))?,
+            ),
+        },
+        (_, Value::String(s)) => InValue::Text(s.clone()),
+        (_, other) => InValue::Text(other.to_string()),
+    })
+}
+
+/// OUT 바인드 값을 JSON 값으로 변환 (REF CURSOR는 행 배열, truncated 여부 반환)
+fn out_value(
+    stmt: &oracle::Statement,
+    bind: &ResolvedBind,
+    max_rows: usize,
+) -> Result<(serde_json::Value, bool), String> {
+    let name = bind.name.as_str();
+    let read_err = |e: oracle::Error| format!("Failed to read bind {}: {}", name, e);
+
+    let value = match bind.bind_type {
+        BindType::RefCursor => {
+            let Some(mut cursor) = stmt.bind_value::<_, Option<RefCursor>>(name).map_err(read_err)? else {
+                return Ok((serde_json::Value::Null, false));
+            };
+            let mut rows = Vec::new();
+            let mut truncated = false;
+            for row_result in cursor.query().map_err(read_err)? {
+                if rows.len() >= max_rows {
+                    truncated = true;
+                    break;
+                }
+                let row = row_result.map_err(|e| format!("Failed to fetch cursor {} row: {}", name, e))?;
+                rows.push(serde_json::Value::Object(oracle_row_to_values(&row).into_iter().collect()));
+            }
+            return Ok((serde_json::Value::Array(rows), truncated));
+        }
+        BindType::Integer => stmt
+            .bind_value::<_, Option<i64>>(name)
+            .map_err(read_err)?
+            .map(serde_json::Value::from),
+        BindType::Number => match stmt.bind_value::<_, Option<i64>>(name) {
+            Ok(v) => v.map(serde_json::Value::from),
+            Err(_) => stmt
+                .bind_value::<_, Option<f64>>(name)
+                .map_err(read_err)?
+                .map(|v| serde_json::json!(v)),
+        },
+        _ => stmt
+            .bind_value::<_, Option<String>>(name)
+            .map_err(read_err)?
+            .map(serde_json::Value::String),
+    };
+    Ok((value.unwrap_or(serde_json::Value::Null), false))
+}
+
+/// PL/SQL 블록 실행 후 OUT 바인드 값을 한 행의 JSON 결과로 반환
+///
+/// REF CURSOR는 해당 바인드 이름 아래에 행 배열로 들어가며 커서마다 max_rows까지만 읽는다.
+pub async fn execute_oracle_plsql(
+    target: OracleConnectTarget,
+    block: &str,
+    binds: &[OracleBind],
+    max_rows: Option<i32>,
+) -> Result<String, String> {
+    let resolved = binds.iter().map(resolve_bind).collect::<Result<Vec<_>, _>>()?;
+    let block = block.to_string();
+    let max_rows = max_rows.unwrap_or(1000).max(0) as usize;
+
+    tokio::task::spawn_blocking(move || {
+        let conn = target
+            .connect()
+            .map_err(|e| format!("Failed to connect to Oracle: {}", e))?;
+
+        println!("Connected to Oracle. Executing PL/SQL: {}", block);
+
+        let mut stmt = conn
+            .statement(&block)
+            .build()
+            .map_err(|e| format!("Failed to prepare PL/SQL: {}", e))?;
+
+        for bind in &resolved {
+            let oracle_type = bind.bind_type.oracle_type();
+            let bind_err = |e: oracle::Error| format!("Failed to bind {}: {}", bind.name, e);
+
+            if matches!(bind.direction, BindDirection::Out | BindDirection::Return) {
+                stmt.bind(bind.name.as_str(), &oracle_type).map_err(bind_err)?;
+                continue;
+            }
+
+            // IN OUT은 (값, 타입) 쌍으로 바인드해야 OUT 버퍼 크기가 보장됨
+            let value = in_value(bind)?;
+            let inout = bind.direction == BindDirection::InOut;
+            let result = match &value {
+                InValue::Null => stmt.bind(bind.name.as_str(), &oracle_type),
+                InValue::Text(v) => bind_in(&mut stmt, &bind.name, v, inout.then_some(&oracle_type)),
+                InValue::Int(v) => bind_in(&mut stmt, &bind.name, v, inout.then_some(&oracle_type)),
+                InValue::Float(v) => bind_in(&mut stmt, &bind.name, v, inout.then_some(&oracle_type)),
+            };
+            result.map_err(bind_err)?;
+        }
+
+        stmt.execute(&[])
+            .map_err(|e| format!("PL/SQL execution failed: {}", e))?;
+
+        let mut out = serde_json::Map::new();
+        let mut truncated = false;
+        for bind in resolved.iter().filter(|b| b.direction != BindDirection::In) {
+            let (value, cursor_truncated) = out_value(&stmt, bind, max_rows)?;
+            truncated |= cursor_truncated;
+            out.insert(bind.name.clone(), value);
+        }
+
+        conn.commit()
+            .map_err(|e| format!("Failed to commit: {}", e))?;
+
+        Ok(serde_json::json!({
+            "success": true,
+            "rowCount": 1,
+            "data": [serde_json::Value::Object(out)],
+            "truncated": truncated,
+        })
+        .to_string())
+    })
+    .await
+    .map_err(|e| format!("Oracle task failed: {}", e))?
+}
+
+fn bind_in<T: ToSql>(
+    stmt: &mut oracle::Statement,
+    name: &str,
+    value: &T,
+    inout_type: Option<&OracleType>,
+) -> oracle::Result<()> {
+    match inout_type {
+        Some(oracle_type) => stmt.bind(name, &(value, oracle_type)),
+        None => stmt.bind(name, value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bind(name: &str, direction: &str, data_type: Option<&str>, value: Option<serde_json::Value>) -> OracleBind {
+        OracleBind {
+            name: name.to_string(),
+            direction: Some(direction.to_string()),
+            data_type: data_type.map(str::to_string),
+            value,
+            size: None,
+        }
+    }
+
+    #[test]
+    fn test_build_call_block() {
+        let binds = vec![
+            bind("p_id", "in", None, Some(serde_json::json!(7))),
+            bind("p_total", "inout", Some("number"), Some(serde_json::json!(1.5))),
+            bind(":p_cur", "out", Some("sys_refcursor"), None),
+        ];
+        assert_eq!(
+            build_call_block("order_pkg.get_orders", &binds).unwrap(),
+            "BEGIN order_pkg.get_orders(p_id => :p_id, p_total => :p_total, p_cur => :p_cur); END;"
+        );
+
+        let function = vec![bind("result", "return", Some("varchar2"), None)];
+        assert_eq!(build_call_block("app_version;", &function).unwrap(), "BEGIN :result := app_version; END;");
+
+        assert!(build_call_block("x; DROP TABLE t", &[]).is_err());
+        let in_cursor = vec![bind("c", "in", Some("refcursor"), None)];
+        assert!(build_call_block("p", &in_cursor).is_err());
+    }
+
+    #[test]
+    fn test_resolve_bind_infers_type_and_converts_value() {
+        let int = resolve_bind(&bind("n", "in", None, Some(serde_json::json!(3)))).unwrap();
+        assert_eq!(int.bind_type, BindType::Integer);
+        assert!(matches!(in_value(&int), Ok(InValue::Int(3))));
+
+        let text = resolve_bind(&bind("s", "inout", None, Some(serde_json::json!("abc")))).unwrap();
+        assert_eq!(text.direction, BindDirection::InOut);
+        assert_eq!(text.bind_type, BindType::Varchar2(4000));
+
+        let number = resolve_bind(&bind("f", "in", Some("number"), Some(serde_json::json!("2.25")))).unwrap();
+        assert!(matches!(in_value(&number), Ok(InValue::Float(v)) if v == 2.25));
+
+        assert!(resolve_bind(&bind("x", "sideways", None, None)).is_err());
+    }
+}
@@ -1,4 +1,6 @@
 use dirs;
+use crate::command::database::book;
+use crate::command::database::page;
 use std::fs;
 use std::path::PathBuf;
 
@@ -8,8 +10,8 @@ pub fn get_local_request_json_path(project_name: String, page_name: String) -> R
     let request_save_path = local_data_dir
         .join("TTOL")
         .join("log")
-        .join(project_name.to_string())
-        .join(page_name.to_string());
+        .join(&project_name)
+        .join(&page_name);
     
     print!("Request save path: {}", request_save_path.display());
 
@@ -54,4 +56,25 @@ pub fn save_request_json(path: std::path::PathBuf, node_name: String, json: Stri
     
     // Return json file path
     Ok(file_name.display().to_string())
-}
\ No newline at end of file
+}
+
+/// 프로젝트/페이지 id로 실행 결과 디렉터리(<log>/<project>/<page>/<run_id>)를 찾아 생성
+pub async fn get_run_dir(project_id: Option<i32>, page_id: i32, run_id: &str) -> Result<PathBuf, String> {
+    let project_name = match project_id {
+        Some(id) => book::get_book_by_id(id)
+            .await
+            .map_err(|e| e.to_string())?
+            .title,
+        None => "root".to_owned(),
+    };
+
+    let page_name = page::get_page_by_id(page_id)
+        .await
+        .map_err(|e| e.to_string())?
+        .title;
+
+    let run_dir = get_local_request_json_path(project_name, page_name)?.join(run_id);
+    fs::create_dir_all(&run_dir).map_err(|e| e.to_string())?;
+
+    Ok(run_dir)
+}
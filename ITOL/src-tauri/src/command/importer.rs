@@ -0,0 +1,34 @@
+pub mod openapi;
+pub mod postman;
+
+use serde_json::{json, Value};
+
+use crate::command::database::bundle::BundleNode;
+
+/// JSON 값 → 문자열 필드 (flow_runner의 변수 변환과 같은 규칙)
+pub(crate) use crate::command::execution::flow_runner::value_to_string;
+
+/// ITOL KeyValuePair ({key, value, enabled, description?})
+pub(crate) fn key_value(key: &str, value: String, enabled: bool, description: Option<String>) -> Value {
+    let mut pair = json!({ "key": key, "value": value, "enabled": enabled });
+    if let Some(description) = description {
+        pair["description"] = Value::String(description);
+    }
+    pair
+}
+
+/// 가져온 API 노드 데이터 → apiNode 목록 (columns 개씩 격자 배치)
+pub(crate) fn api_nodes(requests: Vec<Value>, columns: usize) -> Vec<BundleNode> {
+    let columns = columns.max(1);
+    requests
+        .into_iter()
+        .enumerate()
+        .map(|(index, data)| BundleNode {
+            id: format!("api-node-{}", index + 1),
+            node_type: "apiNode".to_string(),
+            position_x: 250 + (index % columns) as i32 * 350,
+            position_y: 100 + (index / columns) as i32 * 200,
+            data,
+        })
+        .collect()
+}
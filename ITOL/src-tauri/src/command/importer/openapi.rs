@@ -0,0 +1,624 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use sqlx::{Pool, Sqlite};
+use std::cell::RefCell;
+use std::collections::BTreeSet;
+
+use crate::command::database::bundle::{
+    self, BookBundle, BundleBook, BundleImportResult, BundlePage, ConflictStrategy, BUNDLE_FORMAT, BUNDLE_VERSION,
+};
+use crate::command::database::get_db_pool;
+
+use super::{api_nodes, key_value, value_to_string};
+
+const HTTP_METHODS: [&str; 7] = ["get", "post", "put", "patch", "delete", "head", "options"];
+/// 태그가 없는 operation이 들어가는 페이지
+const DEFAULT_TAG: &str = "default";
+/// $ref / 예시 생성 재귀 한도 (순환 스키마 방지)
+const MAX_DEPTH: usize = 8;
+
+/// OpenAPI 문서 변환 결과
+#[derive(Debug, Clone)]
+pub struct ConvertedSpec {
+    pub bundle: BookBundle,
+    pub operations: usize,
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenApiImportResult {
+    #[serde(flatten)]
+    pub import: BundleImportResult,
+    pub operations: usize,
+    pub warnings: Vec<String>,
+}
+
+/// JSON 또는 YAML 문서 파싱
+pub fn parse_document(content: &str) -> Result<Value, String> {
+    match serde_json::from_str::<Value>(content) {
+        Ok(value) => Ok(value),
+        Err(_) => serde_yaml::from_str::<Value>(content)
+            .map_err(|e| format!("Invalid OpenAPI document (not JSON or YAML): {}", e)),
+    }
+}
+
+struct Converter<'a> {
+    spec: &'a Value,
+    warnings: Vec<String>,
+    /// 해석하지 못한 $ref (외부 파일/URL 또는 없는 경로) - 끝에 경고로 추가
+    unresolved_refs: RefCell<BTreeSet<String>>,
+}
+
+impl<'a> Converter<'a> {
+    /// "#/components/..." 로컬 $ref 해석
+    fn resolve<'v>(&self, value: &'v Value) -> &'v Value
+    where
+        'a: 'v,
+    {
+        let mut current = value;
+        for _ in 0..MAX_DEPTH {
+            let Some(reference) = current.get("$ref").and_then(Value::as_str) else {
+                return current;
+            };
+            let target = reference
+                .strip_prefix('#')
+                .and_then(|pointer| self.spec.pointer(pointer));
+            match target {
+                Some(target) => current = target,
+                None => {
+                    self.unresolved_refs.borrow_mut().insert(reference.to_string());
+                    return &Value::Null;
+                }
+            }
+        }
+        current
+    }
+
+    /// 스키마로부터 예시 값 생성 (example > default > enum > 타입별 기본값)
+    fn example_for(&self, schema: &Value, depth: usize) -> Value {
+        if depth > MAX_DEPTH {
+            return Value::Null;
+        }
+        let schema = self.resolve(schema);
+        if let Some(example) = schema.get("example") {
+            return example.clone();
+        }
+        if let Some(example) = schema.get("examples").and_then(Value::as_array).and_then(|e| e.first()) {
+            return example.clone();
+        }
+        if let Some(default) = schema.get("default") {
+            return default.clone();
+        }
+        if let Some(first) = schema.get("enum").and_then(Value::as_array).and_then(|e| e.first()) {
+            return first.clone();
+        }
+        if let Some(constant) = schema.get("const") {
+            return constant.clone();
+        }
+        if let Some(all_of) = schema.get("allOf").and_then(Value::as_array) {
+            let mut merged = Map::new();
+            for part in all_of {
+                if let Value::Object(fields) = self.example_for(part, depth + 1) {
+                    merged.extend(fields);
+                }
+            }
+            return Value::Object(merged);
+        }
+        for key in ["oneOf", "anyOf"] {
+            if let Some(first) = schema.get(key).and_then(Value::as_array).and_then(|e| e.first()) {
+                return self.example_for(first, depth + 1);
+            }
+        }
+
+        // 3.1에서는 type이 ["string", "null"] 형식일 수 있음
+        let schema_type = match schema.get("type") {
+            Some(Value::String(t)) => Some(t.as_str()),
+            Some(Value::Array(types)) => types.iter().filter_map(Value::as_str).find(|t| *t != "null"),
+            _ => None,
+        };
+        match schema_type {
+            Some("object") | None if schema.get("properties").is_some() => {
+                let properties = schema.get("properties").and_then(Value::as_object);
+                Value::Object(
+                    properties
+                        .into_iter()
+                        .flatten()
+                        .map(|(name, property)| (name.clone(), self.example_for(property, depth + 1)))
+                        .collect(),
+                )
+            }
+            Some("object") => json!({}),
+            Some("array") => match schema.get("items") {
+                Some(items) => json!([self.example_for(items, depth + 1)]),
+                None => json!([]),
+            },
+            Some("string") => json!(match schema.get("format").and_then(Value::as_str) {
+                Some("date-time") => "2024-01-01T00:00:00Z",
+                Some("date") => "2024-01-01",
+                Some("email") => "user@example.com",
+                Some("uuid") => "00000000-0000-0000-0000-000000000000",
+                Some("uri") | Some("url") => "https://example.com",
+                _ => "string",
+            }),
+            Some("integer") | Some("number") => json!(0),
+            Some("boolean") => json!(false),
+            _ => Value::Null,
+        }
+    }
+
+    /// 파라미터/미디어 타입 예시 (example > examples 첫 값 > 스키마)
+    fn example_of(&self, target: &Value) -> Value {
+        if let Some(example) = target.get("example") {
+            return example.clone();
+        }
+        if let Some(first) = target.get("examples").and_then(Value::as_object).and_then(|e| e.values().next()) {
+            if let Some(value) = self.resolve(first).get("value") {
+                return value.clone();
+            }
+        }
+        target.get("schema").map(|schema| self.example_for(schema, 0)).unwrap_or(Value::Null)
+    }
+
+    /// path 수준 + operation 수준 파라미터 병합 (name+in 이 같으면 operation 우선)
+    fn parameters(&self, path_item: &Value, operation: &Value) -> Vec<Value> {
+        let mut merged: Vec<Value> = Vec::new();
+        let sources = [path_item.get("parameters"), operation.get("parameters")];
+        for parameter in sources.into_iter().flatten().filter_map(Value::as_array).flatten() {
+            let parameter = self.resolve(parameter).clone();
+            let same = |p: &Value| p.get("name") == parameter.get("name") && p.get("in") == parameter.get("in");
+            match merged.iter_mut().find(|p| same(p)) {
+                Some(existing) => *existing = parameter,
+                None => merged.push(parameter),
+            }
+        }
+        merged
+    }
+
+    /// security 요구사항 → ITOL AuthConfig (쿼리로 보내는 API 키는 쿼리 파라미터로 반환)
+    fn convert_auth(&mut self, operation: &Value, name: &str) -> (Value, Option<Value>) {
+        let none = json!({ "type": "none" });
+        let requirements = operation.get("security").or_else(|| self.spec.get("security"));
+        let Some(scheme_name) = requirements
+            .and_then(Value::as_array)
+            .and_then(|r| r.first())
+            .and_then(Value::as_object)
+            .and_then(|r| r.keys().next())
+        else {
+            return (none, None);
+        };
+        let scheme = self.resolve(
+            self.spec
+                .pointer(&format!("/components/securitySchemes/{}", scheme_name))
+                .unwrap_or(&Value::Null),
+        );
+
+        let field = |key: &str| scheme.get(key).and_then(Value::as_str).unwrap_or_default();
+        match (field("type"), field("scheme").to_lowercase().as_str()) {
+            ("http", "bearer") | ("oauth2", _) | ("openIdConnect", _) => (json!({ "type": "bearer", "token": "" }), None),
+            ("http", "basic") => (json!({ "type": "basic", "username": "", "password": "" }), None),
+            ("apiKey", _) => match field("in") {
+                "header" => (json!({ "type": "api-key", "apiKey": "", "apiKeyHeader": field("name") }), None),
+                "query" => (none, Some(key_value(field("name"), String::new(), true, None))),
+                other => {
+                    self.warnings.push(format!("{}: API key in '{}' is not supported", name, other));
+                    (none, None)
+                }
+            },
+            (other, _) => {
+                self.warnings.push(format!("{}: security scheme '{}' ({}) is not supported", name, scheme_name, other));
+                (none, None)
+            }
+        }
+    }
+
+    /// requestBody → ITOL RequestBody (첫 번째로 지원되는 미디어 타입 사용)
+    fn convert_body(&mut self, operation: &Value, name: &str) -> (Value, Option<String>) {
+        let Some(content) = operation
+            .get("requestBody")
+            .map(|body| self.resolve(body))
+            .and_then(|body| body.get("content"))
+            .and_then(Value::as_object)
+        else {
+            return (json!({ "type": "none" }), None);
+        };
+
+        let find = |predicate: &dyn Fn(&str) -> bool| content.iter().find(|(media, _)| predicate(media));
+        if let Some((media, body)) = find(&|m| m == "application/json" || m.ends_with("+json")) {
+            let example = self.example_of(body);
+            let raw = serde_json::to_string_pretty(&example).unwrap_or_default();
+            return (json!({ "type": "json", "raw": raw }), Some(media.clone()));
+        }
+
+        for (media, body_type, field) in [
+            ("application/x-www-form-urlencoded", "x-www-form-urlencoded", "urlEncoded"),
+            ("multipart/form-data", "form-data", "formData"),
+        ] {
+            let Some(body) = content.get(media) else { continue };
+            let schema = self.resolve(body.get("schema").unwrap_or(&Value::Null));
+            let required: Vec<&str> = schema
+                .get("required")
+                .and_then(Value::as_array)
+                .map(|r| r.iter().filter_map(Value::as_str).collect())
+                .unwrap_or_default();
+            let fields: Vec<Value> = schema
+                .get("properties")
+                .and_then(Value::as_object)
+                .into_iter()
+                .flatten()
+                .map(|(key, property)| {
+                    let property = self.resolve(property);
+                    let is_file = property.get("format").and_then(Value::as_str) == Some("binary");
+                    let value = if is_file { String::new() } else { value_to_string(&self.example_for(property, 0)) };
+                    let mut pair = key_value(key, value, true, description_of(property));
+                    if body_type == "form-data" {
+                        pair["type"] = json!(if is_file { "file" } else { "text" });
+                    }
+                    pair["enabled"] = json!(required.is_empty() || required.contains(&key.as_str()));
+                    pair
+                })
+                .collect();
+            return (json!({ "type": body_type, field: fields }), Some(media.to_string()));
+        }
+
+        match content.iter().next() {
+            Some((media, body)) => {
+                let example = self.example_of(body);
+                let raw = if example.is_null() { String::new() } else { value_to_string(&example) };
+                if media.contains("octet-stream") {
+                    self.warnings.push(format!("{}: binary body ({}) must be filled in manually", name, media));
+                }
+                (json!({ "type": "raw", "raw": raw }), Some(media.clone()))
+            }
+            None => (json!({ "type": "none" }), None),
+        }
+    }
+
+    fn convert_operation(&mut self, base_url: &str, path: &str, method: &str, path_item: &Value, operation: &Value) -> Value {
+        let name = operation
+            .get("operationId")
+            .or_else(|| operation.get("summary"))
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("{} {}", method.to_uppercase(), path));
+
+        let mut path_params = Vec::new();
+        let mut query_params = Vec::new();
+        let mut headers = Vec::new();
+        for parameter in self.parameters(path_item, operation) {
+            let Some(key) = parameter.get("name").and_then(Value::as_str) else { continue };
+            let required = parameter.get("required").and_then(Value::as_bool).unwrap_or(false);
+            let example = self.example_of(&parameter);
+            let value = if example.is_null() { String::new() } else { value_to_string(&example) };
+            // 선택 파라미터는 비활성 상태로 추가
+            let pair = |enabled| key_value(key, value.clone(), enabled, description_of(&parameter));
+            match parameter.get("in").and_then(Value::as_str) {
+                Some("path") => path_params.push(pair(true)),
+                Some("query") => query_params.push(pair(required)),
+                Some("header") => headers.push(pair(required)),
+                other => self.warnings.push(format!(
+                    "{}: parameter '{}' in {} is not supported",
+                    name,
+                    key,
+                    other.unwrap_or("unknown")
+                )),
+            }
+        }
+
+        let (auth, auth_query) = self.convert_auth(operation, &name);
+        query_params.extend(auth_query);
+        let (body, media_type) = self.convert_body(operation, &name);
+        if let Some(media_type) = media_type {
+            headers.push(key_value("Content-Type", media_type, true, None));
+        }
+
+        let mut data = json!({
+            "type": "api",
+            "name": name,
+            "method": method.to_uppercase(),
+            "url": format!("{}{}", base_url, path),
+            "pathParams": path_params,
+            "queryParams": query_params,
+            "headers": headers,
+            "auth": auth,
+            "body": body,
+        });
+        let description = [operation.get("summary"), operation.get("description")]
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .filter(|text| *text != name)
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        if !description.is_empty() {
+            data["description"] = Value::String(description);
+        }
+        data
+    }
+}
+
+fn description_of(value: &Value) -> Option<String> {
+    value.get("description").and_then(Value::as_str).map(str::to_string)
+}
+
+/// servers[0].url 에서 서버 변수를 기본값으로 치환
+fn base_url(spec: &Value) -> String {
+    let Some(server) = spec.get("servers").and_then(Value::as_array).and_then(|s| s.first()) else {
+        return String::new();
+    };
+    let mut url = server.get("url").and_then(Value::as_str).unwrap_or_default().to_string();
+    for (name, variable) in server.get("variables").and_then(Value::as_object).into_iter().flatten() {
+        let default = variable.get("default").map(value_to_string).unwrap_or_default();
+        url = url.replace(&format!("{{{}}}", name), &default);
+    }
+    url.trim_end_matches('/').to_string()
+}
+
+/// OpenAPI 3.0/3.1 문서 → 북 번들 (태그별 페이지, operation별 apiNode)
+pub fn convert_spec(content: &str) -> Result<ConvertedSpec, String> {
+    let spec = parse_document(content)?;
+    let version = spec.get("openapi").map(value_to_string).unwrap_or_default();
+    if !version.starts_with("3.") {
+        return Err(match spec.get("swagger") {
+            Some(_) => "Swagger 2.0 documents are not supported; convert to OpenAPI 3 first".to_string(),
+            None => format!("Unsupported OpenAPI version: '{}'", version),
+        });
+    }
+
+    let base_url = base_url(&spec);
+    let mut converter = Converter {
+        spec: &spec,
+        warnings: Vec::new(),
+        unresolved_refs: RefCell::new(BTreeSet::new()),
+    };
+
+    // 페이지 순서: 최상위 tags 선언 순서, 그 다음 처음 등장한 순서
+    let mut pages: Vec<(String, Vec<Value>)> = spec
+        .get("tags")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|tag| tag.get("name").and_then(Value::as_str))
+        .map(|name| (name.to_string(), Vec::new()))
+        .collect();
+
+    let mut operations = 0;
+    for (path, path_item) in spec.get("paths").and_then(Value::as_object).into_iter().flatten() {
+        let path_item = converter.resolve(path_item);
+        for method in HTTP_METHODS {
+            let Some(operation) = path_item.get(method) else { continue };
+            let tag = operation
+                .get("tags")
+                .and_then(Value::as_array)
+                .and_then(|tags| tags.first())
+                .and_then(Value::as_str)
+                .unwrap_or(DEFAULT_TAG);
+            let data = converter.convert_operation(&base_url, path, method, path_item, operation);
+            operations += 1;
+            match pages.iter_mut().find(|(title, _)| title == tag) {
+                Some((_, requests)) => requests.push(data),
+                None => pages.push((tag.to_string(), vec![data])),
+            }
+        }
+        if path_item.get("trace").is_some() {
+            converter.warnings.push(format!("TRACE {} is not supported by API nodes", path));
+        }
+    }
+
+    for reference in converter.unresolved_refs.take() {
+        converter.warnings.push(if reference.starts_with('#') {
+            format!("$ref '{}' was not found; it was left empty", reference)
+        } else {
+            format!("External $ref '{}' is not supported; it was left empty", reference)
+        });
+    }
+
+    let title = spec
+        .pointer("/info/title")
+        .and_then(Value::as_str)
+        .unwrap_or("OpenAPI")
+        .to_string();
+    let pages = pages
+        .into_iter()
+        .filter(|(_, requests)| !requests.is_empty())
+        .enumerate()
+        .map(|(order, (title, requests))| BundlePage {
+            id: None,
+            title,
+            display_order: order as i32,
+            flow_data: None,
+            nodes: api_nodes(requests, 3),
+            edges: Vec::new(),
+        })
+        .collect();
+
+    Ok(ConvertedSpec {
+        bundle: BookBundle {
+            format: BUNDLE_FORMAT.to_string(),
+            version: BUNDLE_VERSION,
+            exported_at: None,
+            book: BundleBook { id: None, title, pages, books: Vec::new() },
+        },
+        operations,
+        warnings: converter.warnings,
+    })
+}
+
+pub async fn import_spec(
+    content: &str,
+    parent_id: Option<i32>,
+    strategy: ConflictStrategy,
+) -> Result<OpenApiImportResult, String> {
+    let pool = get_db_pool().await;
+    import_spec_with(&pool, content, parent_id, strategy).await
+}
+
+/// 가져온 북은 페이지별 첫 버전과 하나의 되돌리기 단위로 기록
+pub async fn import_spec_with(
+    pool: &Pool<Sqlite>,
+    content: &str,
+    parent_id: Option<i32>,
+    strategy: ConflictStrategy,
+) -> Result<OpenApiImportResult, String> {
+    let converted = convert_spec(content)?;
+    let map_err = |e: sqlx::Error| format!("Failed to import OpenAPI document: {}", e);
+    let mut tx = pool.begin().await.map_err(map_err)?;
+    let import = bundle::import_book_bundle_tx(&mut tx, &converted.bundle, parent_id, strategy).await?;
+    if let Some(book_id) = import.book_id {
+        bundle::record_created_book(&mut tx, book_id, "import_openapi", "Imported from OpenAPI")
+            .await
+            .map_err(map_err)?;
+    }
+    tx.commit().await.map_err(map_err)?;
+    Ok(OpenApiImportResult {
+        import,
+        operations: converted.operations,
+        warnings: converted.warnings,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::database::migration;
+
+    const SPEC: &str = r#"
+openapi: 3.1.0
+info:
+  title: Pet Store
+  version: 1.0.0
+servers:
+  - url: https://{env}.pets.test/v1/
+    variables:
+      env:
+        default: api
+security:
+  - token: []
+tags:
+  - name: pets
+paths:
+  /pets/{petId}:
+    parameters:
+      - name: petId
+        in: path
+        required: true
+        schema: { type: integer, example: 7 }
+    get:
+      operationId: getPet
+      tags: [pets]
+      parameters:
+        - name: fields
+          in: query
+          schema: { type: string }
+  /pets:
+    post:
+      summary: Create pet
+      tags: [pets]
+      requestBody:
+        content:
+          application/json:
+            schema:
+              $ref: '#/components/schemas/Pet'
+  /health:
+    get:
+      security: []
+      responses: {}
+components:
+  securitySchemes:
+    token:
+      type: http
+      scheme: bearer
+  schemas:
+    Pet:
+      type: object
+      properties:
+        name: { type: string, example: Rex }
+        tags:
+          type: array
+          items: { type: string }
+        owner:
+          allOf:
+            - properties:
+                email: { type: string, format: email }
+"#;
+
+    #[test]
+    fn test_convert_spec_pages_and_operations() {
+        let converted = convert_spec(SPEC).unwrap();
+        let book = &converted.bundle.book;
+        assert_eq!(book.title, "Pet Store");
+        assert_eq!(converted.operations, 3);
+        assert_eq!(book.pages.iter().map(|p| p.title.as_str()).collect::<Vec<_>>(), ["pets", "default"]);
+
+        // 문서에 적힌 paths 순서대로 (/pets/{petId}, /pets)
+        let get = &book.pages[0].nodes[0].data;
+        assert_eq!(get["name"], "getPet");
+        assert_eq!(get["url"], "https://api.pets.test/v1/pets/{petId}");
+        assert_eq!(get["pathParams"][0]["value"], "7");
+        assert_eq!(get["queryParams"][0]["enabled"], false);
+        assert_eq!(get["auth"]["type"], "bearer");
+
+        let create = &book.pages[0].nodes[1].data;
+        assert_eq!(create["method"], "POST");
+        let body: Value = serde_json::from_str(create["body"]["raw"].as_str().unwrap()).unwrap();
+        assert_eq!(body, json!({ "name": "Rex", "tags": ["string"], "owner": { "email": "user@example.com" } }));
+        assert_eq!(create["headers"][0]["value"], "application/json");
+
+        // security: [] 는 인증 없음
+        let health = &book.pages[1].nodes[0].data;
+        assert_eq!(health["auth"]["type"], "none");
+    }
+
+    #[test]
+    fn test_import_spec_records_versions_and_journal() {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let pool = migration::empty_test_pool().await;
+                let result = import_spec_with(&pool, SPEC, None, ConflictStrategy::Rename).await.unwrap();
+                assert_eq!((result.import.pages, result.import.nodes), (2, 3));
+
+                let versions: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM PageVersion WHERE message = 'Imported from OpenAPI'")
+                    .fetch_one(&pool)
+                    .await
+                    .unwrap();
+                assert_eq!(versions, 2);
+                let (scope, label): (String, String) = sqlx::query_as("SELECT scope, label FROM OperationLog")
+                    .fetch_one(&pool)
+                    .await
+                    .unwrap();
+                assert_eq!((scope, label), (format!("book:{}", result.import.book_id.unwrap()), "import_openapi".to_string()));
+            });
+    }
+
+    #[test]
+    fn test_convert_spec_warns_on_unresolved_refs() {
+        let spec = r##"{
+            "openapi": "3.0.3",
+            "info": { "title": "Refs" },
+            "paths": {
+                "/orders": {
+                    "post": {
+                        "requestBody": { "content": { "application/json": { "schema": { "$ref": "common.yaml#/Order" } } } },
+                        "responses": {}
+                    },
+                    "get": {
+                        "parameters": [{ "$ref": "#/components/parameters/Missing" }],
+                        "responses": {}
+                    }
+                }
+            }
+        }"##;
+        let converted = convert_spec(spec).unwrap();
+        assert_eq!(converted.operations, 2);
+        assert!(converted.warnings.iter().any(|w| w.contains("External $ref 'common.yaml#/Order'")));
+        assert!(converted.warnings.iter().any(|w| w.contains("'#/components/parameters/Missing' was not found")));
+    }
+
+    #[test]
+    fn test_convert_spec_rejects_swagger_2() {
+        assert!(convert_spec(r#"{"swagger": "2.0", "paths": {}}"#).is_err());
+    }
+}
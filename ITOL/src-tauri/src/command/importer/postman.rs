@@ -0,0 +1,624 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sqlx::{Pool, Sqlite};
+use std::collections::HashMap;
+
+use crate::command::database::bundle::{
+    self, BookBundle, BundleBook, BundleEdge, BundleImportResult, BundlePage, ConflictStrategy, BUNDLE_FORMAT,
+    BUNDLE_VERSION,
+};
+use crate::command::database::environment;
+use crate::command::database::get_db_pool;
+use crate::command::execution::flow_runner;
+
+use super::{api_nodes, key_value, value_to_string};
+
+/// ApiNodeData에서 지원하는 메서드
+const SUPPORTED_METHODS: [&str; 7] = ["GET", "POST", "PUT", "PATCH", "DELETE", "HEAD", "OPTIONS"];
+
+/// Postman 컬렉션 변환 결과
+#[derive(Debug, Clone)]
+pub struct ConvertedCollection {
+    pub bundle: BookBundle,
+    /// 컬렉션 변수 (collection.variable)
+    pub variables: HashMap<String, String>,
+    pub requests: usize,
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostmanImportResult {
+    #[serde(flatten)]
+    pub import: BundleImportResult,
+    pub requests: usize,
+    /// 컬렉션 변수를 저장한 환경 id
+    pub environment_id: Option<i32>,
+    /// 저장한 환경 이름 (같은 이름이 있어 바뀌었을 수 있음)
+    pub environment_name: Option<String>,
+    pub warnings: Vec<String>,
+}
+
+fn name_of(item: &Value, fallback: &str) -> String {
+    item.get("name")
+        .and_then(Value::as_str)
+        .filter(|s| !s.is_empty())
+        .unwrap_or(fallback)
+        .to_string()
+}
+
+/// description은 문자열 또는 {content} 형식
+fn description_of(value: Option<&Value>) -> Option<String> {
+    match value? {
+        Value::String(s) if !s.is_empty() => Some(s.clone()),
+        Value::Object(map) => map.get("content").and_then(Value::as_str).map(str::to_string),
+        _ => None,
+    }
+}
+
+/// [{key, value, disabled, description}] → ITOL KeyValuePair 목록
+fn key_values(items: Option<&Value>) -> Vec<Value> {
+    items
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|item| {
+            let key = item.get("key").and_then(Value::as_str).filter(|k| !k.is_empty())?;
+            Some(key_value(
+                key,
+                value_to_string(item.get("value").unwrap_or(&Value::Null)),
+                !item.get("disabled").and_then(Value::as_bool).unwrap_or(false),
+                description_of(item.get("description")),
+            ))
+        })
+        .collect()
+}
+
+/// "a=1&b=2" 쿼리 문자열 분리 (인코딩은 그대로 유지)
+fn split_query(raw: &str) -> (String, Vec<Value>) {
+    let raw = raw.split('#').next().unwrap_or(raw);
+    match raw.split_once('?') {
+        Some((base, query)) => (
+            base.to_string(),
+            query
+                .split('&')
+                .filter(|pair| !pair.is_empty())
+                .map(|pair| {
+                    let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+                    key_value(key, value.to_string(), true, None)
+                })
+                .collect(),
+        ),
+        None => (raw.to_string(), Vec::new()),
+    }
+}
+
+/// 경로의 ":id" 세그먼트를 ITOL 형식 "{id}" 로 변경
+fn to_path_template(base: &str) -> (String, Vec<String>) {
+    let path_start = match base.find("://") {
+        Some(i) => base[i + 3..].find('/').map(|j| i + 3 + j),
+        None => base.find('/'),
+    };
+    let Some(start) = path_start else {
+        return (base.to_string(), Vec::new());
+    };
+
+    let (head, path) = base.split_at(start);
+    let mut names = Vec::new();
+    let segments: Vec<String> = path
+        .split('/')
+        .map(|segment| match segment.strip_prefix(':').filter(|name| !name.is_empty()) {
+            Some(name) => {
+                names.push(name.to_string());
+                format!("{{{}}}", name)
+            }
+            None => segment.to_string(),
+        })
+        .collect();
+    (format!("{}{}", head, segments.join("/")), names)
+}
+
+/// url (문자열 또는 {raw, host, path, query, variable}) → (기본 URL, 쿼리, 경로 파라미터)
+fn convert_url(url: Option<&Value>) -> (String, Vec<Value>, Vec<Value>) {
+    let (base, query, variables) = match url {
+        Some(Value::String(raw)) => {
+            let (base, query) = split_query(raw);
+            (base, query, Vec::new())
+        }
+        Some(Value::Object(map)) => {
+            let base = match map.get("raw").and_then(Value::as_str) {
+                Some(raw) => split_query(raw).0,
+                None => {
+                    let join = |field: &str, sep: &str| {
+                        map.get(field)
+                            .and_then(Value::as_array)
+                            .map(|parts| parts.iter().map(value_to_string).collect::<Vec<_>>().join(sep))
+                            .unwrap_or_default()
+                    };
+                    let protocol = map.get("protocol").and_then(Value::as_str);
+                    format!(
+                        "{}{}/{}",
+                        protocol.map(|p| format!("{}://", p)).unwrap_or_default(),
+                        join("host", "."),
+                        join("path", "/")
+                    )
+                }
+            };
+            (base, key_values(map.get("query")), key_values(map.get("variable")))
+        }
+        _ => (String::new(), Vec::new(), Vec::new()),
+    };
+
+    let (url, names) = to_path_template(&base);
+    let path_params = names
+        .iter()
+        .map(|name| {
+            variables
+                .iter()
+                .find(|v| v["key"] == name.as_str())
+                .cloned()
+                .unwrap_or_else(|| key_value(name, String::new(), true, None))
+        })
+        .collect();
+    (url, query, path_params)
+}
+
+/// 요청에 auth가 없거나 "inherit"이면 상위 폴더/컬렉션 auth 사용
+fn effective_auth(own: Option<&Value>, inherited: &Value) -> Value {
+    match own {
+        Some(auth) if !auth.is_null() && auth.get("type").and_then(Value::as_str) != Some("inherit") => auth.clone(),
+        _ => inherited.clone(),
+    }
+}
+
+/// auth.<kind> 는 v2.1에서 [{key, value}], v2.0에서 {key: value}
+fn auth_param(auth: &Value, kind: &str, key: &str) -> Option<String> {
+    match auth.get(kind)? {
+        Value::Array(items) => items
+            .iter()
+            .find(|item| item.get("key").and_then(Value::as_str) == Some(key))
+            .and_then(|item| item.get("value"))
+            .map(value_to_string),
+        Value::Object(map) => map.get(key).map(value_to_string),
+        _ => None,
+    }
+}
+
+struct Converter {
+    requests: usize,
+    warnings: Vec<String>,
+}
+
+impl Converter {
+    /// Postman auth → ITOL AuthConfig (쿼리로 보내는 API 키는 쿼리 파라미터로 반환)
+    fn convert_auth(&mut self, auth: &Value, request: &str) -> (Value, Option<Value>) {
+        let none = json!({ "type": "none" });
+        let param = |kind: &str, key: &str| auth_param(auth, kind, key).unwrap_or_default();
+        match auth.get("type").and_then(Value::as_str) {
+            None | Some("noauth") => (none, None),
+            Some("bearer") => (json!({ "type": "bearer", "token": param("bearer", "token") }), None),
+            Some("basic") => (
+                json!({
+                    "type": "basic",
+                    "username": param("basic", "username"),
+                    "password": param("basic", "password"),
+                }),
+                None,
+            ),
+            Some("apikey") => {
+                let key = auth_param(auth, "apikey", "key").unwrap_or_else(|| "X-API-Key".to_string());
+                let value = param("apikey", "value");
+                if auth_param(auth, "apikey", "in").as_deref() == Some("query") {
+                    (none, Some(key_value(&key, value, true, None)))
+                } else {
+                    (json!({ "type": "api-key", "apiKey": value, "apiKeyHeader": key }), None)
+                }
+            }
+            Some("oauth2") => match auth_param(auth, "oauth2", "accessToken") {
+                Some(token) => (json!({ "type": "bearer", "token": token }), None),
+                None => {
+                    self.warnings.push(format!("{}: OAuth 2.0 without an access token was not imported", request));
+                    (none, None)
+                }
+            },
+            Some(other) => {
+                self.warnings.push(format!("{}: auth type '{}' is not supported", request, other));
+                (none, None)
+            }
+        }
+    }
+
+    /// Postman body → ITOL RequestBody
+    fn convert_body(&mut self, body: Option<&Value>, request: &str) -> Value {
+        let Some(body) = body.filter(|b| !b.is_null()) else {
+            return json!({ "type": "none" });
+        };
+        match body.get("mode").and_then(Value::as_str) {
+            Some("raw") => {
+                let raw = body.get("raw").map(value_to_string).unwrap_or_default();
+                let language = body.pointer("/options/raw/language").and_then(Value::as_str);
+                let is_json = match language {
+                    Some(language) => language == "json",
+                    None => serde_json::from_str::<Value>(&raw).is_ok_and(|v| v.is_object() || v.is_array()),
+                };
+                json!({ "type": if is_json { "json" } else { "raw" }, "raw": raw })
+            }
+            Some("urlencoded") => json!({
+                "type": "x-www-form-urlencoded",
+                "urlEncoded": key_values(body.get("urlencoded")),
+            }),
+            Some("formdata") => {
+                let form_data: Vec<Value> = body
+                    .get("formdata")
+                    .and_then(Value::as_array)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|item| {
+                        let key = item.get("key").and_then(Value::as_str)?;
+                        let is_file = item.get("type").and_then(Value::as_str) == Some("file");
+                        let value = if is_file { item.get("src") } else { item.get("value") };
+                        let mut pair = key_value(
+                            key,
+                            value_to_string(value.unwrap_or(&Value::Null)),
+                            !item.get("disabled").and_then(Value::as_bool).unwrap_or(false),
+                            None,
+                        );
+                        pair["type"] = json!(if is_file { "file" } else { "text" });
+                        Some(pair)
+                    })
+                    .collect();
+                json!({ "type": "form-data", "formData": form_data })
+            }
+            Some("graphql") => {
+                let graphql = body.get("graphql").cloned().unwrap_or(Value::Null);
+                let variables = graphql
+                    .get("variables")
+                    .and_then(Value::as_str)
+                    .and_then(|v| serde_json::from_str::<Value>(v).ok())
+                    .unwrap_or(Value::Null);
+                let raw = json!({ "query": graphql.get("query").cloned().unwrap_or(Value::Null), "variables": variables });
+                json!({ "type": "json", "raw": serde_json::to_string_pretty(&raw).unwrap_or_default() })
+            }
+            Some(other) => {
+                self.warnings.push(format!("{}: body mode '{}' is not supported", request, other));
+                json!({ "type": "none" })
+            }
+            None => json!({ "type": "none" }),
+        }
+    }
+
+    /// 요청 아이템 → apiNode 데이터 (ApiNodeData)
+    fn convert_request(&mut self, item: &Value, inherited_auth: &Value) -> Value {
+        self.requests += 1;
+        let name = name_of(item, "Request");
+        // request가 URL 문자열만 있는 경우도 허용
+        let request = match item.get("request") {
+            Some(Value::String(url)) => json!({ "method": "GET", "url": url }),
+            Some(request) => request.clone(),
+            None => Value::Null,
+        };
+
+        let method = request.get("method").and_then(Value::as_str).unwrap_or("GET").to_uppercase();
+        if !SUPPORTED_METHODS.contains(&method.as_str()) {
+            self.warnings.push(format!("{}: method {} is not supported by API nodes", name, method));
+        }
+        let (url, mut query_params, path_params) = convert_url(request.get("url"));
+        let (auth, auth_query) = self.convert_auth(&effective_auth(request.get("auth"), inherited_auth), &name);
+        query_params.extend(auth_query);
+        let body = self.convert_body(request.get("body"), &name);
+
+        let mut data = json!({
+            "type": "api",
+            "name": name,
+            "method": method,
+            "url": url,
+            "pathParams": path_params,
+            "queryParams": query_params,
+            "headers": key_values(request.get("header")),
+            "auth": auth,
+            "body": body,
+        });
+        if let Some(description) = description_of(request.get("description")) {
+            data["description"] = Value::String(description);
+        }
+
+        // pre-request/test 스크립트는 원문 그대로 보관
+        for event in item.get("event").and_then(Value::as_array).into_iter().flatten() {
+            let field = match event.get("listen").and_then(Value::as_str) {
+                Some("prerequest") => "preRequestScript",
+                Some("test") => "testScript",
+                _ => continue,
+            };
+            let script = match event.pointer("/script/exec") {
+                Some(Value::Array(lines)) => lines.iter().map(value_to_string).collect::<Vec<_>>().join("\n"),
+                Some(other) => value_to_string(other),
+                None => continue,
+            };
+            if !script.trim().is_empty() {
+                data[field] = Value::String(script);
+            }
+        }
+        data
+    }
+
+    /// 폴더 → Book, 폴더 바로 아래 요청들 → 같은 이름의 Page (요청 순서대로 연결)
+    fn convert_folder(&mut self, title: String, items: &[Value], auth: &Value) -> BundleBook {
+        let mut requests = Vec::new();
+        let mut books = Vec::new();
+        for item in items {
+            if let Some(children) = item.get("item").and_then(Value::as_array) {
+                let folder_auth = effective_auth(item.get("auth"), auth);
+                books.push(self.convert_folder(name_of(item, "Folder"), children, &folder_auth));
+            } else {
+                requests.push(self.convert_request(item, auth));
+            }
+        }
+
+        let pages = if requests.is_empty() {
+            Vec::new()
+        } else {
+            let nodes = api_nodes(requests, 1);
+            let edges = nodes
+                .windows(2)
+                .map(|pair| BundleEdge {
+                    source: pair[0].id.clone(),
+                    target: pair[1].id.clone(),
+                    source_handle: None,
+                    target_handle: None,
+                })
+                .collect();
+            vec![BundlePage {
+                id: None,
+                title: title.clone(),
+                display_order: 0,
+                flow_data: None,
+                nodes,
+                edges,
+            }]
+        };
+
+        BundleBook { id: None, title, pages, books }
+    }
+}
+
+/// Postman v2.0/v2.1 컬렉션 JSON → 북 번들
+pub fn convert_collection(content: &str) -> Result<ConvertedCollection, String> {
+    let collection: Value = serde_json::from_str(content)
+        .map_err(|e| format!("Invalid Postman collection JSON: {}", e))?;
+    let items = collection
+        .get("item")
+        .and_then(Value::as_array)
+        .ok_or("Not a Postman v2 collection (missing 'item')")?;
+    if let Some(schema) = collection.pointer("/info/schema").and_then(Value::as_str) {
+        if !schema.contains("v2.") {
+            return Err(format!("Unsupported Postman collection schema: {}", schema));
+        }
+    }
+
+    let title = collection
+        .pointer("/info/name")
+        .and_then(Value::as_str)
+        .unwrap_or("Postman Collection")
+        .to_string();
+    let mut converter = Converter { requests: 0, warnings: Vec::new() };
+    let auth = collection.get("auth").cloned().unwrap_or(Value::Null);
+    let book = converter.convert_folder(title, items, &auth);
+
+    let variables = collection
+        .get("variable")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter(|v| !v.get("disabled").and_then(Value::as_bool).unwrap_or(false))
+        .filter_map(|v| Some((v.get("key")?.as_str()?.to_string(), value_to_string(v.get("value").unwrap_or(&Value::Null)))))
+        .collect();
+
+    Ok(ConvertedCollection {
+        bundle: BookBundle {
+            format: BUNDLE_FORMAT.to_string(),
+            version: BUNDLE_VERSION,
+            exported_at: None,
+            book,
+        },
+        variables,
+        requests: converter.requests,
+        warnings: converter.warnings,
+    })
+}
+
+/// Postman 환경 JSON → (이름, 변수)
+pub fn parse_environment(content: &str) -> Result<(String, HashMap<String, String>), String> {
+    let value: Value = serde_json::from_str(content)
+        .map_err(|e| format!("Invalid Postman environment JSON: {}", e))?;
+    if !value.get("values").is_some_and(Value::is_array) {
+        return Err("Not a Postman environment (missing 'values')".to_string());
+    }
+    let name = value
+        .get("name")
+        .and_then(Value::as_str)
+        .unwrap_or("Postman Environment")
+        .to_string();
+    Ok((name, flow_runner::parse_variables(content)?))
+}
+
+/// 컬렉션을 북/페이지/apiNode로 가져오고, 컬렉션 변수는 같은 이름의 환경으로 저장
+///
+/// 환경 이름이 겹치면 북과 같은 strategy로 이름 변경/건너뜀/교체하며, 북과 환경을 한 트랜잭션으로 저장한다.
+pub async fn import_collection(
+    content: &str,
+    parent_id: Option<i32>,
+    strategy: ConflictStrategy,
+) -> Result<PostmanImportResult, String> {
+    let pool = get_db_pool().await;
+    import_collection_with(&pool, content, parent_id, strategy).await
+}
+
+pub async fn import_collection_with(
+    pool: &Pool<Sqlite>,
+    content: &str,
+    parent_id: Option<i32>,
+    strategy: ConflictStrategy,
+) -> Result<PostmanImportResult, String> {
+    let mut converted = convert_collection(content)?;
+    let map_err = |e: sqlx::Error| format!("Failed to import collection: {}", e);
+    let mut tx = pool.begin().await.map_err(map_err)?;
+    let import = bundle::import_book_bundle_tx(&mut tx, &converted.bundle, parent_id, strategy).await?;
+
+    let environment = if converted.variables.is_empty() || import.skipped {
+        None
+    } else {
+        let saved = environment::insert_environment_tx(&mut tx, &import.title, &converted.variables, strategy)
+            .await
+            .map_err(|e| format!("Failed to save collection variables: {}", e))?;
+        match &saved {
+            None => converted.warnings.push(format!(
+                "Environment '{}' already exists; collection variables were not saved",
+                import.title
+            )),
+            Some((_, name)) if *name != import.title => converted.warnings.push(format!(
+                "Environment '{}' already exists; collection variables were saved as '{}'",
+                import.title, name
+            )),
+            Some(_) => {}
+        }
+        saved
+    };
+    tx.commit().await.map_err(map_err)?;
+
+    let (environment_id, environment_name) = environment.unzip();
+    Ok(PostmanImportResult {
+        import,
+        requests: converted.requests,
+        environment_id,
+        environment_name,
+        warnings: converted.warnings,
+    })
+}
+
+/// Postman 환경을 ITOL 환경으로 저장 (같은 이름이 있으면 변수 교체)
+pub async fn import_environment(content: &str) -> Result<i32, String> {
+    let (name, variables) = parse_environment(content)?;
+    environment::save_environment(name, variables)
+        .await
+        .map_err(|e| format!("Failed to save environment: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COLLECTION: &str = r#"{
+        "info": { "name": "Shop", "schema": "https://schema.getpostman.com/json/collection/v2.1.0/collection.json" },
+        "auth": { "type": "bearer", "bearer": [{ "key": "token", "value": "{{token}}" }] },
+        "variable": [{ "key": "baseUrl", "value": "https://shop.test" }],
+        "item": [
+            { "name": "Health", "request": "{{baseUrl}}/health?verbose=1" },
+            {
+                "name": "Orders",
+                "auth": { "type": "apikey", "apikey": [{ "key": "key", "value": "api_key" }, { "key": "value", "value": "secret" }, { "key": "in", "value": "query" }] },
+                "item": [{
+                    "name": "Get order",
+                    "request": {
+                        "method": "get",
+                        "header": [{ "key": "Accept", "value": "application/json" }, { "key": "X-Debug", "value": "1", "disabled": true }],
+                        "url": {
+                            "raw": "{{baseUrl}}/orders/:orderId?expand=items",
+                            "query": [{ "key": "expand", "value": "items" }],
+                            "variable": [{ "key": "orderId", "value": "42" }]
+                        }
+                    },
+                    "event": [{ "listen": "test", "script": { "exec": ["pm.test('ok');", ""] } }]
+                }, {
+                    "name": "Create order",
+                    "request": {
+                        "method": "POST",
+                        "auth": { "type": "basic", "basic": [{ "key": "username", "value": "u" }, { "key": "password", "value": "p" }] },
+                        "url": "{{baseUrl}}/orders",
+                        "body": { "mode": "raw", "raw": "{\"qty\": 1}", "options": { "raw": { "language": "json" } } }
+                    }
+                }]
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn test_convert_collection_folders_and_requests() {
+        let converted = convert_collection(COLLECTION).unwrap();
+        let book = &converted.bundle.book;
+        assert_eq!(converted.requests, 3);
+        assert_eq!(converted.variables["baseUrl"], "https://shop.test");
+        assert_eq!(book.title, "Shop");
+
+        // 최상위 요청: 컬렉션 bearer 상속, 쿼리 분리
+        let health = &book.pages[0].nodes[0].data;
+        assert_eq!(health["url"], "{{baseUrl}}/health");
+        assert_eq!(health["queryParams"][0]["key"], "verbose");
+        assert_eq!(health["auth"], json!({ "type": "bearer", "token": "{{token}}" }));
+
+        // 폴더 → 하위 북, 요청은 한 페이지에 순서대로 연결
+        let orders = &book.books[0];
+        assert_eq!(orders.title, "Orders");
+        assert_eq!(orders.pages[0].edges.len(), 1);
+        let get = &orders.pages[0].nodes[0].data;
+        assert_eq!(get["method"], "GET");
+        assert_eq!(get["url"], "{{baseUrl}}/orders/{orderId}");
+        assert_eq!(get["pathParams"][0]["value"], "42");
+        assert_eq!(get["headers"][1]["enabled"], false);
+        // 쿼리로 보내는 API 키는 쿼리 파라미터로
+        assert_eq!(get["queryParams"][1]["key"], "api_key");
+        assert_eq!(get["queryParams"][1]["value"], "secret");
+        assert_eq!(get["auth"]["type"], "none");
+        assert_eq!(get["testScript"], "pm.test('ok');\n");
+
+        let create = &orders.pages[0].nodes[1].data;
+        assert_eq!(create["auth"]["type"], "basic");
+        assert_eq!(create["body"], json!({ "type": "json", "raw": "{\"qty\": 1}" }));
+    }
+
+    #[test]
+    fn test_parse_environment() {
+        let (name, variables) = parse_environment(
+            r#"{"name": "Staging", "values": [{"key": "token", "value": "abc", "enabled": true}, {"key": "old", "value": "x", "enabled": false}]}"#,
+        )
+        .unwrap();
+        assert_eq!(name, "Staging");
+        assert_eq!(variables.len(), 1);
+        assert!(parse_environment(r#"{"name": "x"}"#).is_err());
+    }
+
+    #[test]
+    fn test_import_collection_keeps_existing_environment() {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let pool = crate::command::database::migration::empty_test_pool().await;
+                let existing: HashMap<String, String> = [("baseUrl".to_string(), "http://localhost".to_string())].into();
+                environment::save_environment_with(&pool, "Shop".to_string(), existing.clone())
+                    .await
+                    .unwrap();
+
+                // 같은 이름의 환경이 있으면 북과 같은 규칙으로 이름을 바꿔 저장
+                let result = import_collection_with(&pool, COLLECTION, None, ConflictStrategy::Rename)
+                    .await
+                    .unwrap();
+                assert_eq!(result.import.title, "Shop");
+                assert_eq!(result.environment_name.as_deref(), Some("Shop (2)"));
+                assert!(result.warnings.iter().any(|w| w.contains("saved as 'Shop (2)'")));
+                let kept = environment::get_environment_by_name_with(&pool, "Shop").await.unwrap();
+                assert_eq!(kept.variables, existing);
+                let saved = environment::get_environment_by_name_with(&pool, "Shop (2)").await.unwrap();
+                assert_eq!(saved.variables["baseUrl"], "https://shop.test");
+
+                // Replace는 북과 환경을 모두 교체
+                let replaced = import_collection_with(&pool, COLLECTION, None, ConflictStrategy::Replace)
+                    .await
+                    .unwrap();
+                assert_eq!(replaced.environment_name.as_deref(), Some("Shop"));
+                let kept = environment::get_environment_by_name_with(&pool, "Shop").await.unwrap();
+                assert_eq!(kept.variables["baseUrl"], "https://shop.test");
+                assert_eq!(environment::get_all_environments_with(&pool).await.unwrap().len(), 2);
+            });
+    }
+}
@@ -1,7 +1,72 @@
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use sha2::{Digest, Sha256};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use std::fs;
 
+/// 버전을 지정하지 않았을 때 다운로드하는 기본 버전
+const DEFAULT_VERSION: &str = "21.13.0.0.0";
+
+/// 연결이 끊겼을 때 이어받기를 시도하는 최대 횟수
+const MAX_DOWNLOAD_ATTEMPTS: u32 = 3;
+
+/// 진행률 이벤트 최소 간격
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(200);
+
+/// 웹뷰로 보내는 설치 진행률 이벤트 이름
+pub const INSTALL_PROGRESS_EVENT: &str = "oracle-install-progress";
+
+/// 다운로드/압축 해제 진행률
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct InstallProgress {
+    /// "download" | "extract"
+    pub phase: String,
+    /// 지금까지 처리한 바이트 (이어받기한 경우 이전에 받은 바이트 포함)
+    pub bytes: u64,
+    /// 전체 바이트 (서버가 크기를 알려주지 않으면 None)
+    pub total: Option<u64>,
+    /// 초당 처리 바이트
+    #[serde(rename = "bytesPerSecond")]
+    pub bytes_per_second: f64,
+}
+
+pub type ProgressCallback = Arc<dyn Fn(InstallProgress) + Send + Sync>;
+
+/// 진행률을 일정 간격으로만 전달하는 도우미
+struct ProgressReporter<'a> {
+    phase: &'static str,
+    callback: &'a ProgressCallback,
+    started: Instant,
+    /// 속도 계산에서 제외할 시작 바이트 (이어받기 이전 분량)
+    base: u64,
+    last_emit: Option<Instant>,
+}
+
+impl<'a> ProgressReporter<'a> {
+    fn new(phase: &'static str, callback: &'a ProgressCallback, base: u64) -> Self {
+        ProgressReporter { phase, callback, started: Instant::now(), base, last_emit: None }
+    }
+
+    fn report(&mut self, bytes: u64, total: Option<u64>, force: bool) {
+        let now = Instant::now();
+        if !force && self.last_emit.is_some_and(|last| now.duration_since(last) < PROGRESS_INTERVAL) {
+            return;
+        }
+        self.last_emit = Some(now);
+
+        let elapsed = now.duration_since(self.started).as_secs_f64();
+        let bytes_per_second = if elapsed > 0.0 { bytes.saturating_sub(self.base) as f64 / elapsed } else { 0.0 };
+        (self.callback)(InstallProgress {
+            phase: self.phase.to_string(),
+            bytes,
+            total,
+            bytes_per_second,
+        });
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct OracleInstallStatus {
     pub installed: bool,
@@ -9,21 +74,63 @@ pub struct OracleInstallStatus {
     path: Option<String>,
 }
 
+/// 앱 데이터 디렉토리에 설치된 Instant Client 한 버전
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct OracleClientInstall {
+    pub version: String,
+    /// 라이브러리가 들어 있는 instantclient 디렉토리
+    pub path: String,
+    pub active: bool,
+}
+
+/// 활성 버전 변경 결과
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OracleClientActivation {
+    #[serde(flatten)]
+    pub install: OracleClientInstall,
+    /// 이미 다른 Instant Client가 로드되어 있어 앱을 다시 시작해야 적용됨
+    pub restart_required: bool,
+}
+
+/// 이 프로세스에서 ODPI-C가 로드한 Instant Client 디렉토리
+static LOADED_CLIENT_DIR: std::sync::OnceLock<String> = std::sync::OnceLock::new();
+
+/// 다운로드 설치 옵션 (모두 생략하면 기본 버전을 Oracle 사이트에서 받음)
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct OracleDownloadOptions {
+    /// 예: "21.13.0.0.0", "19.22.0.0.0"
+    pub version: Option<String>,
+    /// 사내 미러 등 직접 지정한 다운로드 URL
+    pub url: Option<String>,
+    /// 압축 파일의 SHA-256 (16진수)
+    pub sha256: Option<String>,
+}
+
+/// 로컬 ZIP 설치 파라미터 (인터넷이 없는 환경용)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OracleArchiveInstallParams {
+    #[serde(rename = "zipPath")]
+    pub zip_path: String,
+    pub sha256: Option<String>,
+    /// 설치 후 활성 버전으로 지정할지 여부 (기본값 true)
+    pub activate: Option<bool>,
+}
+
 /// Oracle Instant Client 설치 확인
 pub async fn check_oracle_installed() -> Result<OracleInstallStatus, String> {
     // 앱 데이터 디렉토리 확인
     let app_oracle_path = get_app_oracle_path()?;
-    
-    if app_oracle_path.exists() {
-        // 버전 파일 확인
-        let version = read_version_file(&app_oracle_path);
+
+    let installs = list_installs(&app_oracle_path)?;
+    if let Some(install) = installs.iter().find(|i| i.active).or(installs.first()) {
         return Ok(OracleInstallStatus {
             installed: true,
-            version,
-            path: Some(app_oracle_path.to_string_lossy().to_string()),
+            version: Some(install.version.clone()),
+            path: Some(install.path.clone()),
         });
     }
-    
+
     // 시스템 PATH 확인
     if check_system_oracle() {
         return Ok(OracleInstallStatus {
@@ -32,7 +139,7 @@ pub async fn check_oracle_installed() -> Result<OracleInstallStatus, String> {
             path: None,
         });
     }
-    
+
     Ok(OracleInstallStatus {
         installed: false,
         version: None,
@@ -41,120 +148,373 @@ pub async fn check_oracle_installed() -> Result<OracleInstallStatus, String> {
 }
 
 /// Oracle Instant Client 자동 다운로드 및 설치
-pub async fn install_oracle_client() -> Result<String, String> {
+pub async fn install_oracle_client(
+    options: OracleDownloadOptions,
+    on_progress: ProgressCallback,
+) -> Result<String, String> {
     println!("Starting Oracle Instant Client installation...");
-    
+
     let app_oracle_path = get_app_oracle_path()?;
-    
+
     // 디렉토리 생성
     fs::create_dir_all(&app_oracle_path)
         .map_err(|e| format!("Failed to create directory: {}", e))?;
-    
-    // OS별 다운로드 URL
-    let (download_url, file_name) = get_download_info()?;
-    
+
+    // 버전별 다운로드 URL (직접 지정한 URL이 우선)
+    let version = options.version.as_deref().unwrap_or(DEFAULT_VERSION);
+    let (download_url, file_name) = match &options.url {
+        Some(url) => (url.clone(), format!("instantclient-basic-{}.zip", version)),
+        None => get_download_info(version)?,
+    };
+
     println!("Downloading from: {}", download_url);
-    
+
     // 다운로드
     let zip_path = app_oracle_path.join(&file_name);
-    download_file(&download_url, &zip_path).await?;
-    
+    download_file(&download_url, &zip_path, &on_progress).await?;
+
     println!("Download complete. Extracting...");
-    
-    // 압축 해제
-    extract_zip(&zip_path, &app_oracle_path)?;
-    
-    // 버전 정보 저장
-    save_version_file(&app_oracle_path, "21.13.0.0")?;
-    
+
+    let archive_path = zip_path.clone();
+    let result = tokio::task::spawn_blocking(move || {
+        install_from_archive(&app_oracle_path, &archive_path, options.sha256.as_deref(), true, &on_progress)
+    })
+    .await
+    .map_err(|e| format!("Install task failed: {}", e))
+    .and_then(|result| result);
+
     // ZIP 파일 삭제
     let _ = fs::remove_file(&zip_path);
-    
-    // 환경 변수 설정 (현재 프로세스)
-    setup_environment_variables(&app_oracle_path)?;
-    
-    println!("Oracle Instant Client installed successfully!");
-    
-    Ok(format!("Installed to: {}", app_oracle_path.display()))
+
+    let install = result?;
+    println!("Oracle Instant Client {} installed successfully!", install.version);
+
+    Ok(format!("Installed to: {}", install.path))
+}
+
+/// 로컬 ZIP 파일로 Oracle Instant Client 설치
+pub async fn install_oracle_client_from_zip(
+    params: OracleArchiveInstallParams,
+    on_progress: ProgressCallback,
+) -> Result<OracleClientInstall, String> {
+    let app_oracle_path = get_app_oracle_path()?;
+    fs::create_dir_all(&app_oracle_path)
+        .map_err(|e| format!("Failed to create directory: {}", e))?;
+
+    let zip_path = PathBuf::from(&params.zip_path);
+    let activate = params.activate.unwrap_or(true);
+    tokio::task::spawn_blocking(move || {
+        install_from_archive(&app_oracle_path, &zip_path, params.sha256.as_deref(), activate, &on_progress)
+    })
+    .await
+    .map_err(|e| format!("Install task failed: {}", e))?
+}
+
+/// 설치된 Instant Client 버전 목록
+pub async fn list_oracle_clients() -> Result<Vec<OracleClientInstall>, String> {
+    list_installs(&get_app_oracle_path()?)
+}
+
+/// 여러 버전 중 사용할 버전 지정
+///
+/// ODPI-C는 프로세스에서 Instant Client를 한 번만 로드하므로, 이미 다른 버전이
+/// 로드되어 있으면 선택만 저장되고 앱을 다시 시작해야 적용된다 (restart_required).
+pub async fn set_active_oracle_client(version: String) -> Result<OracleClientActivation, String> {
+    let app_oracle_path = get_app_oracle_path()?;
+    let install = activate_install(&app_oracle_path, &version)?;
+    let restart_required = !load_oracle_client(&install)?;
+    Ok(OracleClientActivation { install, restart_required })
+}
+
+/// ODPI-C가 아직 초기화되지 않았으면 install을 로드, install이 로드된 클라이언트인지 반환
+fn load_oracle_client(install: &OracleClientInstall) -> Result<bool, String> {
+    let loaded_here = oracle::InitParams::new()
+        .oracle_client_lib_dir(&install.path)
+        .and_then(|params| params.init())
+        .map_err(|e| format!("Failed to load Oracle Instant Client {}: {}", install.version, e))?;
+    if loaded_here {
+        let _ = LOADED_CLIENT_DIR.set(install.path.clone());
+    }
+    Ok(LOADED_CLIENT_DIR.get() == Some(&install.path))
+}
+
+/// 앱 시작 시 저장된 활성 Instant Client를 적용
+///
+/// ODPI-C는 첫 접속 때 라이브러리를 한 번만 로드하므로 Oracle 접속 전에 호출해야 한다.
+pub fn apply_active_oracle_client() -> Result<Option<OracleClientInstall>, String> {
+    let Some(install) = apply_active_install(&get_app_oracle_path()?)? else {
+        return Ok(None);
+    };
+    load_oracle_client(&install)?;
+    Ok(Some(install))
+}
+
+/// 특정 버전 삭제 (활성 버전이면 활성 지정도 해제)
+pub async fn uninstall_oracle_client(version: String) -> Result<(), String> {
+    let app_oracle_path = get_app_oracle_path()?;
+    remove_install(&app_oracle_path, &version)
 }
 
 /// 앱 데이터 디렉토리 경로 가져오기
 fn get_app_oracle_path() -> Result<PathBuf, String> {
     let data_dir = dirs::data_local_dir()
         .ok_or("Failed to get local data directory")?;
-    
+
     Ok(data_dir.join("ITOL").join("oracle_instant_client"))
 }
 
 /// OS별 다운로드 정보
-fn get_download_info() -> Result<(String, String), String> {
-    #[cfg(target_os = "windows")]
-    {
-        Ok((
-            "https://download.oracle.com/otn_software/nt/instantclient/2113000/instantclient-basic-windows.x64-21.13.0.0.0dbru.zip".to_string(),
-            "instantclient-basic-windows.zip".to_string(),
-        ))
+///
+/// Oracle 사이트의 19c/21c RU 경로 규칙(예: 2113000/...-21.13.0.0.0dbru.zip)을 따르며,
+/// 규칙이 다른 버전은 url을 직접 지정해야 한다.
+fn get_download_info(version: &str) -> Result<(String, String), String> {
+    let parts: Vec<&str> = version.split('.').collect();
+    let valid = parts.len() == 5 && parts.iter().all(|p| !p.is_empty() && p.chars().all(|c| c.is_ascii_digit()));
+    if !valid || !matches!(parts[0], "19" | "21") {
+        return Err(format!(
+            "No download URL is known for Oracle Instant Client {}; specify url or install from a local zip",
+            version
+        ));
     }
-    
+    let release_dir = parts.concat();
+
+    #[cfg(target_os = "windows")]
+    let (os_dir, platform, os_name) = ("nt", "windows.x64", "windows");
+
     #[cfg(target_os = "linux")]
-    {
-        Ok((
-            "https://download.oracle.com/otn_software/linux/instantclient/2113000/instantclient-basic-linux.x64-21.13.0.0.0dbru.zip".to_string(),
-            "instantclient-basic-linux.zip".to_string(),
-        ))
-    }
-    
+    let (os_dir, platform, os_name) = ("linux", "linux.x64", "linux");
+
     #[cfg(target_os = "macos")]
-    {
-        Ok((
-            "https://download.oracle.com/otn_software/mac/instantclient/2113000/instantclient-basic-macos.x64-21.13.0.0.0dbru.zip".to_string(),
-            "instantclient-basic-macos.zip".to_string(),
-        ))
-    }
-    
+    let (os_dir, platform, os_name) = ("mac", "macos.x64", "macos");
+
     #[cfg(not(any(target_os = "windows", target_os = "linux", target_os = "macos")))]
-    {
-        Err("Unsupported operating system".to_string())
-    }
+    return Err("Unsupported operating system".to_string());
+
+    #[cfg(any(target_os = "windows", target_os = "linux", target_os = "macos"))]
+    Ok((
+        format!(
+            "https://download.oracle.com/otn_software/{}/instantclient/{}/instantclient-basic-{}-{}dbru.zip",
+            os_dir, release_dir, platform, version
+        ),
+        format!("instantclient-basic-{}-{}.zip", os_name, version),
+    ))
+}
+
+/// 다운로드 시도 실패 (Retry면 받은 부분부터 다시 요청)
+enum DownloadError {
+    Retry(String),
+    Fatal(String),
 }
 
 /// 파일 다운로드
-async fn download_file(url: &str, dest: &PathBuf) -> Result<(), String> {
-    let response = reqwest::get(url)
+///
+/// `<dest>.part`에 받다가 연결이 끊기면 Range 요청으로 이어받고,
+/// 끝내 실패하면 받던 파일을 지운다. 완료되면 dest로 이름을 바꾼다.
+async fn download_file(url: &str, dest: &Path, on_progress: &ProgressCallback) -> Result<(), String> {
+    let part_path = partial_path(dest);
+    let client = reqwest::Client::new();
+
+    let mut attempt = 1;
+    let result = loop {
+        match download_attempt(&client, url, &part_path, on_progress).await {
+            Ok(()) => break Ok(()),
+            Err(DownloadError::Retry(e)) if attempt < MAX_DOWNLOAD_ATTEMPTS => {
+                log::warn!("Download interrupted ({}), resuming (attempt {})", e, attempt + 1);
+                attempt += 1;
+                tokio::time::sleep(Duration::from_millis(500 * attempt as u64)).await;
+            }
+            Err(DownloadError::Retry(e)) | Err(DownloadError::Fatal(e)) => break Err(e),
+        }
+    };
+
+    if let Err(e) = result {
+        let _ = fs::remove_file(&part_path);
+        return Err(e);
+    }
+
+    fs::rename(&part_path, dest)
+        .map_err(|e| format!("Failed to write file: {}", e))
+}
+
+fn partial_path(dest: &Path) -> PathBuf {
+    let mut name = dest.file_name().unwrap_or_default().to_os_string();
+    name.push(".part");
+    dest.with_file_name(name)
+}
+
+async fn download_attempt(
+    client: &reqwest::Client,
+    url: &str,
+    part_path: &Path,
+    on_progress: &ProgressCallback,
+) -> Result<(), DownloadError> {
+    use reqwest::header::{CONTENT_RANGE, RANGE};
+    use reqwest::StatusCode;
+
+    let resume_from = fs::metadata(part_path).map(|m| m.len()).unwrap_or(0);
+    let mut request = client.get(url);
+    if resume_from > 0 {
+        request = request.header(RANGE, format!("bytes={}-", resume_from));
+    }
+
+    let mut response = request
+        .send()
         .await
-        .map_err(|e| format!("Download failed: {}", e))?;
-    
-    if !response.status().is_success() {
-        return Err(format!("Download failed with status: {}", response.status()));
+        .map_err(|e| DownloadError::Retry(format!("Download failed: {}", e)))?;
+    let status = response.status();
+
+    if status == StatusCode::RANGE_NOT_SATISFIABLE {
+        // 받던 파일이 서버 파일과 맞지 않으면 처음부터 다시
+        let _ = fs::remove_file(part_path);
+        return Err(DownloadError::Retry(format!("Download failed with status: {}", status)));
     }
-    
-    let bytes = response.bytes()
+    if !status.is_success() {
+        return Err(DownloadError::Fatal(format!("Download failed with status: {}", status)));
+    }
+
+    // 서버가 Range를 무시하고 200으로 응답하면 처음부터 받음
+    let expected_range = format!("bytes {}-", resume_from);
+    let resumed = status == StatusCode::PARTIAL_CONTENT
+        && response
+            .headers()
+            .get(CONTENT_RANGE)
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|v| v.starts_with(&expected_range));
+    let mut downloaded = if resumed { resume_from } else { 0 };
+    let total = response.content_length().map(|len| len + downloaded);
+
+    let file = if resumed {
+        fs::OpenOptions::new().append(true).open(part_path)
+    } else {
+        fs::File::create(part_path)
+    };
+    let mut file = file.map_err(|e| DownloadError::Fatal(format!("Failed to write file: {}", e)))?;
+
+    let mut reporter = ProgressReporter::new("download", on_progress, downloaded);
+    reporter.report(downloaded, total, true);
+
+    while let Some(chunk) = response
+        .chunk()
         .await
-        .map_err(|e| format!("Failed to read response: {}", e))?;
-    
-    fs::write(dest, bytes)
-        .map_err(|e| format!("Failed to write file: {}", e))?;
-    
+        .map_err(|e| DownloadError::Retry(format!("Failed to read response: {}", e)))?
+    {
+        file.write_all(&chunk)
+            .map_err(|e| DownloadError::Fatal(format!("Failed to write file: {}", e)))?;
+        downloaded += chunk.len() as u64;
+        reporter.report(downloaded, total, false);
+    }
+    file.flush()
+        .map_err(|e| DownloadError::Fatal(format!("Failed to write file: {}", e)))?;
+    reporter.report(downloaded, total, true);
+
+    match total {
+        Some(total) if downloaded < total => Err(DownloadError::Retry(format!(
+            "Connection closed after {} of {} bytes",
+            downloaded, total
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// 파일의 SHA-256 계산 (16진수 소문자)
+fn sha256_file(path: &Path) -> Result<String, String> {
+    let mut file = fs::File::open(path)
+        .map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut file, &mut hasher)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    Ok(hasher.finalize().iter().map(|b| format!("{:02x}", b)).collect())
+}
+
+/// 압축 파일 체크섬 확인 (기대값이 없으면 생략)
+fn verify_checksum(path: &Path, expected: Option<&str>) -> Result<(), String> {
+    let Some(expected) = expected.map(str::trim).filter(|v| !v.is_empty()) else {
+        return Ok(());
+    };
+    let actual = sha256_file(path)?;
+    if !actual.eq_ignore_ascii_case(expected) {
+        return Err(format!("Checksum mismatch: expected {}, got {}", expected, actual));
+    }
     Ok(())
 }
 
+/// 압축 파일을 검증/해제하고 실제 버전 디렉토리(<root>/<version>)로 옮김
+///
+/// 임시 디렉토리에 먼저 풀기 때문에 실패해도 기존 설치에는 영향이 없다.
+fn install_from_archive(
+    root: &Path,
+    zip_path: &Path,
+    sha256: Option<&str>,
+    activate: bool,
+    on_progress: &ProgressCallback,
+) -> Result<OracleClientInstall, String> {
+    if !zip_path.is_file() {
+        return Err(format!("Archive not found: {}", zip_path.display()));
+    }
+    verify_checksum(zip_path, sha256)?;
+
+    let staging = root.join(format!(".staging-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&staging);
+    let result = (|| {
+        extract_zip(zip_path, &staging, on_progress)?;
+
+        let client_dir = find_client_dir(&staging)
+            .ok_or("Archive does not contain an Oracle Instant Client library")?;
+        let version = detect_client_version(&client_dir)
+            .ok_or("Failed to detect the Oracle Instant Client version from the archive")?;
+
+        let version_dir = root.join(&version);
+        if version_dir.exists() {
+            return Err(format!("Oracle Instant Client {} is already installed", version));
+        }
+        let relative = client_dir
+            .strip_prefix(&staging)
+            .map_err(|e| format!("Invalid archive layout: {}", e))?
+            .to_path_buf();
+        fs::rename(&staging, &version_dir)
+            .map_err(|e| format!("Failed to move installation: {}", e))?;
+        Ok((version, version_dir.join(relative)))
+    })();
+    let _ = fs::remove_dir_all(&staging);
+    let (version, client_dir) = result?;
+
+    if activate || read_active_version(root).is_none() {
+        activate_install(root, &version)
+    } else {
+        Ok(OracleClientInstall {
+            version,
+            path: client_dir.to_string_lossy().to_string(),
+            active: false,
+        })
+    }
+}
+
 /// ZIP 파일 압축 해제
-fn extract_zip(zip_path: &PathBuf, target_dir: &PathBuf) -> Result<(), String> {
+fn extract_zip(zip_path: &Path, target_dir: &Path, on_progress: &ProgressCallback) -> Result<(), String> {
     let file = fs::File::open(zip_path)
         .map_err(|e| format!("Failed to open zip: {}", e))?;
-    
+
     let mut archive = zip::ZipArchive::new(file)
         .map_err(|e| format!("Failed to read zip: {}", e))?;
-    
+
+    // 압축 해제 진행률은 풀린 파일 크기 기준
+    let mut total = 0;
+    for i in 0..archive.len() {
+        total += archive.by_index(i).map(|f| f.size()).unwrap_or(0);
+    }
+    let mut extracted = 0;
+    let mut reporter = ProgressReporter::new("extract", on_progress, 0);
+    reporter.report(0, Some(total), true);
+
     for i in 0..archive.len() {
         let mut file = archive.by_index(i)
             .map_err(|e| format!("Failed to read file in zip: {}", e))?;
-        
+
         let outpath = match file.enclosed_name() {
             Some(path) => target_dir.join(path),
             None => continue,
         };
-        
+
         if file.name().ends_with('/') {
             fs::create_dir_all(&outpath)
                 .map_err(|e| format!("Failed to create directory: {}", e))?;
@@ -167,10 +527,11 @@ fn extract_zip(zip_path: &PathBuf, target_dir: &PathBuf) -> Result<(), String> {
             }
             let mut outfile = fs::File::create(&outpath)
                 .map_err(|e| format!("Failed to create file: {}", e))?;
-            std::io::copy(&mut file, &mut outfile)
+            extracted += std::io::copy(&mut file, &mut outfile)
                 .map_err(|e| format!("Failed to extract file: {}", e))?;
+            reporter.report(extracted, Some(total), false);
         }
-        
+
         // Unix 권한 설정
         #[cfg(unix)]
         {
@@ -181,75 +542,415 @@ fn extract_zip(zip_path: &PathBuf, target_dir: &PathBuf) -> Result<(), String> {
             }
         }
     }
-    
+    reporter.report(extracted, Some(total), true);
+
+    Ok(())
+}
+
+/// OCI 클라이언트 라이브러리 파일인지 확인 (oci.dll, libclntsh.so*, libclntsh.dylib*)
+fn is_client_library(name: &str) -> bool {
+    let lower = name.to_ascii_lowercase();
+    lower == "oci.dll" || lower.starts_with("libclntsh.so") || lower.starts_with("libclntsh.dylib")
+}
+
+/// 클라이언트 라이브러리가 들어 있는 디렉토리 탐색 (최대 2단계 하위까지)
+fn find_client_dir(dir: &Path) -> Option<PathBuf> {
+    fn walk(dir: &Path, depth: usize) -> Option<PathBuf> {
+        let entries: Vec<_> = fs::read_dir(dir).ok()?.flatten().collect();
+        if entries.iter().any(|e| is_client_library(&e.file_name().to_string_lossy())) {
+            return Some(dir.to_path_buf());
+        }
+        if depth == 0 {
+            return None;
+        }
+        let mut dirs: Vec<PathBuf> = entries.iter().map(|e| e.path()).filter(|p| p.is_dir()).collect();
+        dirs.sort();
+        dirs.into_iter().find_map(|d| walk(&d, depth - 1))
+    }
+    walk(dir, 2)
+}
+
+/// 설치된 파일로 실제 버전 확인
+///
+/// 1) BASIC_README / BASIC_LITE_README의 "Client Shared Library 64-bit - 21.13.0.0.0"
+/// 2) 디렉토리 이름 instantclient_21_13
+/// 3) 라이브러리 파일 이름 libclntsh.so.21.1 (주 버전만 알 수 있음)
+fn detect_client_version(client_dir: &Path) -> Option<String> {
+    for readme in ["BASIC_README", "BASIC_LITE_README"] {
+        let Ok(content) = fs::read_to_string(client_dir.join(readme)) else {
+            continue;
+        };
+        let version = content
+            .lines()
+            .filter(|line| line.contains("Client Shared Library"))
+            .filter_map(|line| line.rsplit('-').next())
+            .map(str::trim)
+            .find(|v| is_version_string(v));
+        if let Some(version) = version {
+            return Some(version.to_string());
+        }
+    }
+
+    let dir_name = client_dir.file_name()?.to_string_lossy().to_string();
+    if let Some(rest) = dir_name.strip_prefix("instantclient_") {
+        let version = rest.replace('_', ".");
+        if is_version_string(&version) {
+            return Some(version);
+        }
+    }
+
+    fs::read_dir(client_dir).ok()?.flatten().find_map(|entry| {
+        let name = entry.file_name().to_string_lossy().to_string();
+        let version = name
+            .strip_prefix("libclntsh.so.")
+            .or_else(|| name.strip_prefix("libclntsh.dylib."))?;
+        is_version_string(version).then(|| version.to_string())
+    })
+}
+
+fn is_version_string(value: &str) -> bool {
+    value.contains('.') && value.split('.').all(|p| !p.is_empty() && p.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// 활성 버전 파일 경로
+fn active_file(root: &Path) -> PathBuf {
+    root.join("active_version.txt")
+}
+
+fn read_active_version(root: &Path) -> Option<String> {
+    fs::read_to_string(active_file(root))
+        .ok()
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+/// <root>/<version> 디렉토리들을 설치 목록으로 변환
+fn list_installs(root: &Path) -> Result<Vec<OracleClientInstall>, String> {
+    if !root.exists() {
+        return Ok(Vec::new());
+    }
+    migrate_legacy_install(root);
+    let active = read_active_version(root);
+
+    let mut installs = Vec::new();
+    let entries = fs::read_dir(root)
+        .map_err(|e| format!("Failed to read {}: {}", root.display(), e))?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy().to_string();
+        if !path.is_dir() || name.starts_with('.') {
+            continue;
+        }
+        let Some(client_dir) = find_client_dir(&path) else {
+            continue;
+        };
+        installs.push(OracleClientInstall {
+            active: active.as_deref() == Some(name.as_str()),
+            version: name,
+            path: client_dir.to_string_lossy().to_string(),
+        });
+    }
+    installs.sort_by(|a, b| compare_versions(&b.version, &a.version));
+    Ok(installs)
+}
+
+/// 예전 단일 설치(<root>/instantclient_21_13 + version.txt)를 버전 디렉토리 구조로 옮김
+fn migrate_legacy_install(root: &Path) {
+    let legacy_version_file = root.join("version.txt");
+    if !legacy_version_file.exists() {
+        return;
+    }
+
+    let legacy_dirs = fs::read_dir(root)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|e| e.path())
+                .filter(|p| p.file_name().is_some_and(|n| n.to_string_lossy().starts_with("instantclient_")))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    for client_dir in legacy_dirs {
+        let Some(version) = detect_client_version(&client_dir) else {
+            continue;
+        };
+        let version_dir = root.join(&version);
+        if version_dir.exists() || fs::create_dir_all(&version_dir).is_err() {
+            continue;
+        }
+        if let Some(name) = client_dir.file_name() {
+            if fs::rename(&client_dir, version_dir.join(name)).is_ok() && read_active_version(root).is_none() {
+                let _ = fs::write(active_file(root), &version);
+            }
+        }
+    }
+    let _ = fs::remove_file(legacy_version_file);
+}
+
+/// 버전 문자열을 숫자 단위로 비교
+fn compare_versions(a: &str, b: &str) -> std::cmp::Ordering {
+    let parse = |v: &str| v.split('.').map(|p| p.parse::<u32>().unwrap_or(0)).collect::<Vec<_>>();
+    parse(a).cmp(&parse(b))
+}
+
+fn activate_install(root: &Path, version: &str) -> Result<OracleClientInstall, String> {
+    let install = list_installs(root)?
+        .into_iter()
+        .find(|i| i.version == version)
+        .ok_or_else(|| format!("Oracle Instant Client {} is not installed", version))?;
+
+    fs::write(active_file(root), version)
+        .map_err(|e| format!("Failed to write active version: {}", e))?;
+
+    // 환경 변수 설정 (현재 프로세스)
+    setup_environment_variables(Path::new(&install.path))?;
+
+    Ok(OracleClientInstall { active: true, ..install })
+}
+
+/// 활성 버전(없으면 최신 버전)의 환경 변수를 다시 설정 (재시작 후 첫 Oracle 접속 전에 호출)
+fn apply_active_install(root: &Path) -> Result<Option<OracleClientInstall>, String> {
+    let installs = list_installs(root)?;
+    let Some(install) = installs.iter().find(|i| i.active).or(installs.first()) else {
+        return Ok(None);
+    };
+    setup_environment_variables(Path::new(&install.path))?;
+    Ok(Some(install.clone()))
+}
+
+fn remove_install(root: &Path, version: &str) -> Result<(), String> {
+    let is_installed = list_installs(root)?.iter().any(|i| i.version == version);
+    if !is_installed {
+        return Err(format!("Oracle Instant Client {} is not installed", version));
+    }
+
+    fs::remove_dir_all(root.join(version))
+        .map_err(|e| format!("Failed to remove Oracle Instant Client {}: {}", version, e))?;
+
+    if read_active_version(root).as_deref() == Some(version) {
+        let _ = fs::remove_file(active_file(root));
+    }
     Ok(())
 }
 
 /// 환경 변수 설정
-fn setup_environment_variables(oracle_path: &PathBuf) -> Result<(), String> {
-    let instant_client_dir = oracle_path.join("instantclient_21_13");
-    
+fn setup_environment_variables(instant_client_dir: &Path) -> Result<(), String> {
+    std::env::set_var("OCI_LIB_DIR", instant_client_dir);
+
     #[cfg(target_os = "windows")]
     {
         let path = std::env::var("PATH").unwrap_or_default();
         let new_path = format!("{};{}", instant_client_dir.display(), path);
         std::env::set_var("PATH", new_path);
     }
-    
+
     #[cfg(target_os = "linux")]
     {
         let ld_path = std::env::var("LD_LIBRARY_PATH").unwrap_or_default();
         let new_ld_path = format!("{}:{}", instant_client_dir.display(), ld_path);
         std::env::set_var("LD_LIBRARY_PATH", new_ld_path);
     }
-    
+
     #[cfg(target_os = "macos")]
     {
         let dyld_path = std::env::var("DYLD_LIBRARY_PATH").unwrap_or_default();
         let new_dyld_path = format!("{}:{}", instant_client_dir.display(), dyld_path);
         std::env::set_var("DYLD_LIBRARY_PATH", new_dyld_path);
     }
-    
-    Ok(())
-}
 
-/// 버전 파일 저장
-fn save_version_file(oracle_path: &PathBuf, version: &str) -> Result<(), String> {
-    let version_file = oracle_path.join("version.txt");
-    fs::write(version_file, version)
-        .map_err(|e| format!("Failed to write version file: {}", e))?;
     Ok(())
 }
 
-/// 버전 파일 읽기
-fn read_version_file(oracle_path: &PathBuf) -> Option<String> {
-    let version_file = oracle_path.join("version.txt");
-    fs::read_to_string(version_file).ok()
-}
-
 /// 시스템 PATH에서 Oracle 확인
 fn check_system_oracle() -> bool {
     #[cfg(target_os = "windows")]
     {
         if let Ok(path) = std::env::var("PATH") {
-            return path.to_lowercase().contains("instantclient") || 
+            return path.to_lowercase().contains("instantclient") ||
                    path.to_lowercase().contains("oracle");
         }
     }
-    
+
     #[cfg(target_os = "linux")]
     {
         if let Ok(ld_path) = std::env::var("LD_LIBRARY_PATH") {
             return ld_path.contains("instantclient") || ld_path.contains("oracle");
         }
     }
-    
+
     #[cfg(target_os = "macos")]
     {
         if let Ok(dyld_path) = std::env::var("DYLD_LIBRARY_PATH") {
             return dyld_path.contains("instantclient") || dyld_path.contains("oracle");
         }
     }
-    
+
     false
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    fn no_progress() -> ProgressCallback {
+        Arc::new(|_| {})
+    }
+
+    fn write_client_zip(path: &Path, dir: &str, readme_version: Option<&str>) {
+        let mut zip = zip::ZipWriter::new(fs::File::create(path).unwrap());
+        let options = zip::write::FileOptions::default();
+        zip.start_file(format!("{}/libclntsh.so.21.1", dir), options).unwrap();
+        zip.write_all(b"lib").unwrap();
+        if let Some(version) = readme_version {
+            zip.start_file(format!("{}/BASIC_README", dir), options).unwrap();
+            write!(zip, "Basic Package Information\nClient Shared Library 64-bit - {}\n", version).unwrap();
+        }
+        zip.finish().unwrap();
+    }
+
+    #[test]
+    fn test_install_side_by_side_and_uninstall() {
+        let root = std::env::temp_dir().join(format!("itol_oracle_install_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+
+        let zip_21 = root.join("ic21.zip");
+        write_client_zip(&zip_21, "instantclient_21_13", Some("21.13.0.0.0"));
+        let zip_19 = root.join("ic19.zip");
+        write_client_zip(&zip_19, "instantclient_19_22", None);
+
+        // 체크섬이 다르면 설치하지 않음
+        assert!(install_from_archive(&root, &zip_21, Some("00"), true, &no_progress()).is_err());
+        assert!(list_installs(&root).unwrap().is_empty());
+
+        let checksum = sha256_file(&zip_21).unwrap();
+        let installed = install_from_archive(&root, &zip_21, Some(&checksum.to_uppercase()), true, &no_progress()).unwrap();
+        assert_eq!(installed.version, "21.13.0.0.0");
+        assert!(installed.active);
+        assert!(installed.path.ends_with("instantclient_21_13"));
+
+        // README가 없으면 디렉토리 이름으로 버전 판단, 기존 활성 버전 유지
+        let older = install_from_archive(&root, &zip_19, None, false, &no_progress()).unwrap();
+        assert_eq!(older.version, "19.22");
+        assert!(!older.active);
+        assert!(install_from_archive(&root, &zip_19, None, false, &no_progress()).is_err());
+
+        let versions: Vec<_> = list_installs(&root).unwrap().into_iter().map(|i| (i.version, i.active)).collect();
+        assert_eq!(versions, vec![("21.13.0.0.0".to_string(), true), ("19.22".to_string(), false)]);
+
+        // 재시작 후에도 마지막으로 지정한 활성 버전을 다시 적용
+        activate_install(&root, "19.22").unwrap();
+        activate_install(&root, "21.13.0.0.0").unwrap();
+        std::env::remove_var("OCI_LIB_DIR");
+        let applied = apply_active_install(&root).unwrap().unwrap();
+        assert_eq!(applied.version, "21.13.0.0.0");
+        assert_eq!(std::env::var("OCI_LIB_DIR").unwrap(), applied.path);
+
+        remove_install(&root, "21.13.0.0.0").unwrap();
+        assert_eq!(read_active_version(&root), None);
+        assert!(remove_install(&root, "21.13.0.0.0").is_err());
+        assert_eq!(list_installs(&root).unwrap().len(), 1);
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_download_info_for_version() {
+        let (url, _) = get_download_info("19.22.0.0.0").unwrap();
+        assert!(url.contains("/instantclient/1922000/"));
+        assert!(url.ends_with("-19.22.0.0.0dbru.zip"));
+        assert!(get_download_info("23.4.0.24.05").is_err());
+        assert!(get_download_info("latest").is_err());
+    }
+
+    /// Range 요청을 지원하고 첫 응답은 중간에 연결을 끊는 테스트용 HTTP 서버
+    async fn start_flaky_http_server(payload: Vec<u8>) -> (u16, Arc<Mutex<Vec<Option<String>>>>) {
+        let listener = tokio::net::TcpListener::bind(("127.0.0.1", 0)).await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let ranges = Arc::new(Mutex::new(Vec::new()));
+        let seen = ranges.clone();
+        tokio::spawn(async move {
+            let mut first = true;
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let mut request = Vec::new();
+                let mut buf = [0u8; 1024];
+                while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                    let n = socket.read(&mut buf).await.unwrap();
+                    if n == 0 {
+                        break;
+                    }
+                    request.extend_from_slice(&buf[..n]);
+                }
+                let request = String::from_utf8_lossy(&request).to_string();
+                let range = request
+                    .lines()
+                    .find_map(|l| l.to_ascii_lowercase().strip_prefix("range: bytes=").map(str::to_string))
+                    .map(|r| r.trim_end_matches('-').to_string());
+                seen.lock().unwrap().push(range.clone());
+
+                if request.starts_with("GET /missing") {
+                    let _ = socket.write_all(b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n").await;
+                    continue;
+                }
+
+                let start: usize = range.and_then(|r| r.parse().ok()).unwrap_or(0);
+                let body = &payload[start..];
+                let header = if start > 0 {
+                    format!(
+                        "HTTP/1.1 206 Partial Content\r\nContent-Length: {}\r\nContent-Range: bytes {}-{}/{}\r\nConnection: close\r\n\r\n",
+                        body.len(), start, payload.len() - 1, payload.len()
+                    )
+                } else {
+                    format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n", body.len())
+                };
+                socket.write_all(header.as_bytes()).await.unwrap();
+                // 첫 요청은 절반만 보내고 연결 종료
+                let sent = if first { body.len() / 2 } else { body.len() };
+                first = false;
+                socket.write_all(&body[..sent]).await.unwrap();
+                let _ = socket.shutdown().await;
+            }
+        });
+        (port, ranges)
+    }
+
+    #[test]
+    fn test_download_resumes_with_range_and_cleans_up_on_failure() {
+        let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+        runtime.block_on(async {
+            let payload: Vec<u8> = (0..100_000u32).map(|i| (i % 251) as u8).collect();
+            let (port, ranges) = start_flaky_http_server(payload.clone()).await;
+
+            let dir = std::env::temp_dir().join(format!("itol_oracle_download_{}", std::process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            fs::create_dir_all(&dir).unwrap();
+
+            let events = Arc::new(Mutex::new(Vec::new()));
+            let recorded = events.clone();
+            let on_progress: ProgressCallback = Arc::new(move |p| recorded.lock().unwrap().push(p));
+
+            let dest = dir.join("client.zip");
+            download_file(&format!("http://127.0.0.1:{}/client.zip", port), &dest, &on_progress)
+                .await
+                .unwrap();
+            assert_eq!(fs::read(&dest).unwrap(), payload);
+            assert!(!partial_path(&dest).exists());
+            assert_eq!(*ranges.lock().unwrap(), vec![None, Some("50000".to_string())]);
+
+            let last = events.lock().unwrap().last().cloned().unwrap();
+            assert_eq!(last.phase, "download");
+            assert_eq!((last.bytes, last.total), (100_000, Some(100_000)));
+
+            let missing = dir.join("missing.zip");
+            let result = download_file(&format!("http://127.0.0.1:{}/missing", port), &missing, &on_progress).await;
+            assert!(result.is_err());
+            assert!(!missing.exists());
+            assert!(!partial_path(&missing).exists());
+
+            let _ = fs::remove_dir_all(&dir);
+        });
+    }
+}
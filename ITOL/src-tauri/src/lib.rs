@@ -1,5 +1,12 @@
 mod command;
 
+use tauri_plugin_dialog::{DialogExt, MessageDialogKind};
+
+// 헤드리스 실행기(itol-cli)에서 사용하는 공개 API
+pub use command::database::{create_sqlite, environment};
+pub use command::execution::{flow_report, flow_runner};
+pub use command::oracle_installer;
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     // 로깅 초기화 (개발 중에만)
@@ -7,10 +14,32 @@ pub fn run() {
     env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("debug")).init();
 
     tauri::Builder::default()
-        .setup(|_app| {
+        .setup(|app| {
+            // 저장된 Oracle Instant Client 버전을 첫 접속 전에 적용
+            if let Err(e) = command::oracle_installer::apply_active_oracle_client() {
+                log::error!("Failed to apply Oracle Instant Client: {}", e);
+            }
+            // 마이그레이션이 끝난 뒤에 명령을 받도록 setup에서 기다림
+            if let Err(e) = tauri::async_runtime::block_on(command::database::create_sqlite()) {
+                log::error!("Failed to prepare database: {}", e);
+                // 절반만 마이그레이션된 스키마로 계속 실행하지 않고 알린 뒤 종료
+                let handle = app.handle().clone();
+                app.dialog()
+                    .message(format!("Failed to prepare the database.\n\n{}", e))
+                    .title("ITOL")
+                    .kind(MessageDialogKind::Error)
+                    .show(move |_| handle.exit(1));
+                return Ok(());
+            }
             // 앱 시작 후 비동기 초기화 실행
             tauri::async_runtime::spawn(async move {
-                command::database::create_sqlite().await;
+                // 보관 기간이 지난 휴지통 항목 정리
+                let retention = command::database::trash::DEFAULT_RETENTION_DAYS;
+                if let Err(e) = command::database::trash::purge(Some(retention)).await {
+                    log::error!("Failed to purge trash: {}", e);
+                }
+                // 앱이 실행되는 동안 주기적으로 자동 백업
+                command::database::backup::run_backup_scheduler().await;
             });
             Ok(())
         })
@@ -39,9 +68,30 @@ pub fn run() {
             command::execute_ts_command,
             command::execute_api_command,
             command::execute_db_command,
+            command::run_subflow_command,
             command::test_db_connection_command,
+            command::list_db_schemas_command,
+            command::list_db_tables_command,
+            command::list_db_columns_command,
+            command::list_db_indexes_command,
+            command::list_db_foreign_keys_command,
+            command::get_connection_profiles_command,
+            command::create_connection_profile_command,
+            command::update_connection_profile_command,
+            command::delete_connection_profile_command,
+            command::test_connection_profile_command,
+            command::export_book_bundle_command,
+            command::import_book_bundle_command,
+            command::check_database_integrity_command,
+            command::import_postman_collection_command,
+            command::import_postman_environment_command,
+            command::import_openapi_command,
             command::check_oracle_installed,
             command::install_oracle_client,
+            command::install_oracle_client_from_zip_command,
+            command::list_oracle_clients_command,
+            command::set_active_oracle_client_command,
+            command::uninstall_oracle_client_command,
             command::add_project_command,
             command::remove_project_command,
             command::get_projects_command,
@@ -63,6 +113,26 @@ pub fn run() {
             command::update_page_command,
             command::delete_page_command,
             command::reorder_pages_command,
+            command::create_backup_command,
+            command::list_backups_command,
+            command::restore_backup_command,
+            command::get_trash_command,
+            command::restore_book_command,
+            command::restore_page_command,
+            command::purge_trash_command,
+            command::clone_book_command,
+            command::clone_page_command,
+            command::copy_nodes_command,
+            command::search_command,
+            command::undo_command,
+            command::redo_command,
+            command::get_undo_state_command,
+            command::save_page_graph_command,
+            command::list_page_versions_command,
+            command::get_page_version_command,
+            command::create_page_checkpoint_command,
+            command::diff_page_versions_command,
+            command::restore_page_version_command,
             command::debug_get_all_pages_command,
             command::get_nodes_by_page_id_command,
             command::create_node_command,
@@ -7,13 +7,15 @@ import { DagServiceInstance } from "./features/dag/services/dag.service";
 import FileNode from "@/entities/language/ui/file-node";
 import ApiNode from "@/entities/api/ui/api-node";
 import DbNode from "@/entities/db/ui/db-node";
-import { useCallback, useMemo, useState, useEffect } from "react";
+import SubflowNode from "@/entities/subflow/ui/subflow-node";
+import { useCallback, useMemo, useState, useEffect, useRef } from "react";
 import WindowHeader from "./shared/components/window-header";
 import { ExecutionLogPanel } from "./shared/components/execution-log-panel";
 import { NodeResultPanel } from "./shared/components/node-result-panel";
 import { ApiNodeEditor } from "./shared/components/api-node-editor";
 import { DbNodeEditor } from "./shared/components/db-node-editor";
-import type { ApiNodeData, DbNodeData } from "./shared/components/settings-modal/types";
+import { SubflowNodeEditor } from "./shared/components/subflow-node-editor";
+import type { ApiNodeData, DbNodeData, SubflowNodeData } from "./shared/components/settings-modal/types";
 import { FileExplorer, type FileItem } from "./shared/components/file-explorer";
 import { TabBar, type Tab } from "./shared/components/tab-bar";
 import { ContextMenu } from "./shared/components/context-menu";
@@ -36,6 +38,35 @@ interface Page {
 	flow_data: string | null;
 }
 
+/**
+ * 페이지 그래프 전체(노드/엣지/flow_data)를 한 트랜잭션으로 저장
+ * (없어진 노드/엣지는 삭제되고 되돌리기 기록과 버전이 함께 남음)
+ */
+function savePageGraph(pageId: number, nodes: Node[], edges: any[]) {
+	return invoke('save_page_graph_command', {
+		pageId,
+		flowData: JSON.stringify({ nodes, edges }),
+		nodes: nodes.map(node => ({
+			id: node.id,
+			data: JSON.stringify(node.data),
+			node_type: node.type || 'languageNode',
+			position_x: Math.round(node.position.x),
+			position_y: Math.round(node.position.y)
+		})),
+		edges: edges.map(edge => {
+			// DB에서 불러온 엣지는 "edge-<id>", 새 엣지는 source/target으로 매칭
+			const dbId = /^edge-(\d+)$/.exec(edge.id ?? '');
+			return {
+				id: dbId ? Number.parseInt(dbId[1]) : null,
+				source: edge.source,
+				target: edge.target,
+				source_handle: edge.sourceHandle ?? null,
+				target_handle: edge.targetHandle ?? null
+			};
+		})
+	});
+}
+
 export default function App() {
 	return (
 		<ReactFlowProvider>
@@ -55,6 +86,8 @@ function FlowCanvas() {
 	const [tabs, setTabs] = useState<Tab[]>([]);
 	const [activeTabId, setActiveTabId] = useState<string>("");
 	const [currentPageId, setCurrentPageId] = useState<number | null>(null);
+	// nodes/edges가 어느 페이지에서 불러온 것인지 (불러오는 중에는 null → 저장하지 않음)
+	const loadedPageIdRef = useRef<number | null>(null);
 	const [selectedFileId, setSelectedFileId] = useState<string>();
 	const [fileItems, setFileItems] = useState<FileItem[]>([]);
 	const [books, setBooks] = useState<Book[]>([]);
@@ -70,84 +103,21 @@ function FlowCanvas() {
 	// Node creation dialog state (File node only)
 	const [nodeCreationDialogOpen, setNodeCreationDialogOpen] = useState(false);
 
-	// Custom node change handler to track deletions
-	const onNodesChange = useCallback((changes: any[]) => {
-		// Handle deletions
-		const removedNodes = changes.filter(change => change.type === 'remove');
-		if (removedNodes.length > 0 && currentPageId) {
-			removedNodes.forEach(change => {
-				console.log('[onNodesChange] Deleting node from DB:', change.id);
-				invoke('delete_node_command', {
-					id: change.id,
-					fkPageId: currentPageId
-				}).then(() => {
-					console.log('[onNodesChange] Successfully deleted node from DB:', change.id);
-				}).catch(error => {
-					console.error('[onNodesChange] Failed to delete node from DB:', error);
-				});
-			});
-		}
-		
-		// Apply changes to ReactFlow
-		onNodesChangeInternal(changes);
-	}, [currentPageId, onNodesChangeInternal]);
-
-	// Custom edge change handler to track deletions
-	const onEdgesChange = useCallback((changes: any[]) => {
-		// Handle deletions
-		const removedEdges = changes.filter(change => change.type === 'remove');
-		if (removedEdges.length > 0 && currentPageId) {
-			removedEdges.forEach(change => {
-				// Extract edge ID from "edge-123" format
-				const edgeId = change.id.replace('edge-', '');
-				console.log('[onEdgesChange] Deleting edge from DB:', edgeId);
-				invoke('delete_edge_command', {
-					id: Number.parseInt(edgeId),
-					fkPageId: currentPageId
-				}).then(() => {
-					console.log('[onEdgesChange] Successfully deleted edge from DB:', edgeId);
-				}).catch(error => {
-					console.error('[onEdgesChange] Failed to delete edge from DB:', error);
-				});
-			});
-		}
-		
-		// Apply changes to ReactFlow
-		onEdgesChangeInternal(changes);
-	}, [currentPageId, onEdgesChangeInternal]);
+	// 삭제를 포함한 변경은 아래 그래프 저장에서 함께 반영됨
+	const onNodesChange = onNodesChangeInternal;
+	const onEdgesChange = onEdgesChangeInternal;
 
-	// 노드 변경 시 DB 업데이트 (debounce 적용)
+	// 노드/엣지 변경 시 페이지 그래프 저장 (debounce 적용)
 	useEffect(() => {
-		if (!currentPageId) return;
+		if (!currentPageId || loadedPageIdRef.current !== currentPageId) return;
 		
 		const timer = setTimeout(() => {
-			// 노드 위치 변경을 DB에 반영
-			nodes.forEach(node => {
-				invoke('update_node_command', {
-					id: node.id,
-					fkPageId: currentPageId,
-					data: JSON.stringify(node.data),
-					nodeType: node.type || 'languageNode',
-					positionX: Math.round(node.position.x),
-					positionY: Math.round(node.position.y)
-				}).catch(error => {
-					// 새 노드인 경우 create 시도
-					if (error.toString().includes('no rows')) {
-						invoke('create_node_command', {
-							id: node.id,
-							fkPageId: currentPageId,
-							data: JSON.stringify(node.data),
-							nodeType: node.type || 'languageNode',
-							positionX: Math.round(node.position.x),
-							positionY: Math.round(node.position.y)
-						}).catch(err => console.error('Failed to create node:', err));
-					}
-				});
-			});
+			savePageGraph(currentPageId, nodes, edges)
+				.catch(error => console.error('Failed to save page graph:', error));
 		}, 500); // 500ms debounce
 		
 		return () => clearTimeout(timer);
-	}, [nodes, currentPageId]);
+	}, [nodes, edges, currentPageId]);
 	
 	// Load books and pages from database
 	useEffect(() => {
@@ -213,7 +183,8 @@ function FlowCanvas() {
 						setEdges(flowEdges);
 						
 						// Register all nodes and edges in DAG service
-						DagServiceInstance.setNodesAndEdges(flowNodes, flowEdges);
+						DagServiceInstance.setNodesAndEdges(flowNodes, flowEdges, firstPage.id);
+						loadedPageIdRef.current = firstPage.id;
 						
 						console.log('[Initial Load] Loaded from DB:', { nodes: flowNodes.length, edges: flowEdges.length });
 					} catch (error) {
@@ -308,22 +279,15 @@ function FlowCanvas() {
 		if (!activeTabId || !activeTabId.startsWith("page-")) return;
 		
 		const pageId = Number.parseInt(activeTabId.replace("page-", ""));
-		const flowData = JSON.stringify({ nodes, edges });
+		// 아직 불러오는 중인 페이지에는 이전 페이지의 그래프를 저장하지 않음
+		if (loadedPageIdRef.current !== pageId) return;
 		
 		try {
-			const currentPage = pages.find(p => p.id === pageId);
-			if (currentPage) {
-				await invoke("update_page_command", {
-					id: pageId,
-					fkBookId: currentPage.fk_book_id,
-					title: currentPage.title,
-					flowData: flowData
-				});
-			}
+			await savePageGraph(pageId, nodes, edges);
 		} catch (error) {
 			console.error("Failed to save flow:", error);
 		}
-	}, [activeTabId, nodes, edges, pages]);
+	}, [activeTabId, nodes, edges]);
 
 	// API Node Editor state
 	const [apiEditorOpen, setApiEditorOpen] = useState(false);
@@ -333,6 +297,10 @@ function FlowCanvas() {
 	const [dbEditorOpen, setDbEditorOpen] = useState(false);
 	const [editingDbNode, setEditingDbNode] = useState<{ nodeId: string; data: DbNodeData } | null>(null);
 
+	// Sub-flow Node Editor state
+	const [subflowEditorOpen, setSubflowEditorOpen] = useState(false);
+	const [editingSubflowNode, setEditingSubflowNode] = useState<{ nodeId: string; data: SubflowNodeData } | null>(null);
+
 	// Settings Modal state
 	const [settingsOpen, setSettingsOpen] = useState(false);
 
@@ -475,6 +443,7 @@ function FlowCanvas() {
 			// Clear nodes and edges for new page
 			setNodes([]);
 			setEdges([]);
+			loadedPageIdRef.current = newPageId;
 		} catch (error) {
 			console.error("Failed to create page:", error);
 			alert("Failed to create page. Please try again.");
@@ -762,6 +731,7 @@ function FlowCanvas() {
 			
 			// Load the page's data from DB
 			const pageId = Number.parseInt(item.id.replace("page-", ""));
+			loadedPageIdRef.current = null;
 			try {
 				const dbNodes = await invoke<any[]>('get_nodes_by_page_id_command', { pageId });
 				const dbEdges = await invoke<any[]>('get_edges_by_page_id_command', { pageId });
@@ -794,7 +764,7 @@ function FlowCanvas() {
 				setEdges(flowEdges);
 				
 				// Register all nodes and edges in DAG service
-				DagServiceInstance.setNodesAndEdges(flowNodes, flowEdges);
+				DagServiceInstance.setNodesAndEdges(flowNodes, flowEdges, pageId);
 				
 				console.log('[File Double Click] Loaded from DB:', { pageId, nodes: flowNodes.length, edges: flowEdges.length });
 			} catch (error) {
@@ -819,6 +789,7 @@ function FlowCanvas() {
 			
 			// Update current page ID
 			setCurrentPageId(pageId);
+			loadedPageIdRef.current = pageId;
 			
 			setActiveTabId(item.id);
 		}
@@ -832,6 +803,7 @@ function FlowCanvas() {
 			
 			// Update current page ID
 			const pageId = Number.parseInt(tabId.replace("page-", ""));
+			loadedPageIdRef.current = null;
 			setCurrentPageId(pageId);
 			
 			// Load nodes and edges from DB
@@ -867,7 +839,7 @@ function FlowCanvas() {
 				setEdges(flowEdges);
 				
 				// Register all nodes and edges in DAG service
-				DagServiceInstance.setNodesAndEdges(flowNodes, flowEdges);
+				DagServiceInstance.setNodesAndEdges(flowNodes, flowEdges, pageId);
 				
 				console.log('[Tab Switch] Loaded from DB:', { pageId, nodes: flowNodes.length, edges: flowEdges.length });
 			} catch (error) {
@@ -890,51 +862,28 @@ function FlowCanvas() {
 				}
 			}
 			
+			loadedPageIdRef.current = pageId;
 			setActiveTabId(tabId);
 		}
 	}, [activeTabId, pages, saveCurrentFlow, setNodes, setEdges]);
 
 	const handleTabClose = useCallback((tabId: string) => {
-		// Save before closing
-		if (activeTabId === tabId) {
+		const newTabs = tabs.filter(tab => tab.id !== tabId);
+		setTabs(newTabs);
+		if (activeTabId !== tabId) return;
+		
+		// If closing active tab, switch to another tab (handleTabClick saves and loads from DB)
+		if (newTabs.length > 0) {
+			handleTabClick(newTabs[newTabs.length - 1].id);
+		} else {
 			saveCurrentFlow();
+			loadedPageIdRef.current = null;
+			setCurrentPageId(null);
+			setActiveTabId("");
+			setNodes([]);
+			setEdges([]);
 		}
-		
-		setTabs(prev => {
-			const newTabs = prev.filter(tab => tab.id !== tabId);
-			
-			// If closing active tab, switch to another tab
-			if (activeTabId === tabId && newTabs.length > 0) {
-				const newActiveId = newTabs[newTabs.length - 1].id;
-				
-				// Load the new active tab's flow data
-				const pageId = Number.parseInt(newActiveId.replace("page-", ""));
-				const page = pages.find(p => p.id === pageId);
-				if (page && page.flow_data) {
-					try {
-						const flowData = JSON.parse(page.flow_data);
-						setNodes(flowData.nodes || []);
-						setEdges(flowData.edges || []);
-					} catch (e) {
-						console.error("Failed to parse flow data:", e);
-						setNodes([]);
-						setEdges([]);
-					}
-				} else {
-					setNodes([]);
-					setEdges([]);
-				}
-				
-				setActiveTabId(newActiveId);
-			} else if (newTabs.length === 0) {
-				setActiveTabId("");
-				setNodes([]);
-				setEdges([]);
-			}
-			
-			return newTabs;
-		});
-	}, [activeTabId, pages, saveCurrentFlow, setNodes, setEdges]);
+	}, [tabs, activeTabId, handleTabClick, saveCurrentFlow, setNodes, setEdges]);
 
 	// 새로운 노드 생성 함수
 	const createNewNode = useCallback((position: { x: number; y: number }, sourceNodeId?: string) => {
@@ -1008,27 +957,10 @@ function FlowCanvas() {
 		// DagService에도 동기화
 		DagServiceInstance.addNode(newNode);
 		
-		// DB에 저장 (백그라운드)
-		console.log('[createFileNode] Saving to DB:', { newNodeId, currentPageId, nodeType: newNode.type });
-		if (currentPageId) {
-			invoke('create_node_command', {
-				id: newNodeId,
-				fkPageId: currentPageId,
-				data: JSON.stringify(newNode.data),
-				nodeType: newNode.type,
-				positionX: Math.round(position.x),
-				positionY: Math.round(position.y)
-			}).then(() => {
-				console.log('[createFileNode] Successfully saved to DB:', newNodeId);
-			}).catch(error => {
-				console.error('[createFileNode] Failed to save node to DB:', error);
-			});
-		} else {
-			console.warn('[createFileNode] No currentPageId, skipping DB save');
-		}
+		// DB 저장은 페이지 그래프 저장(debounce)에서 처리
 		
 		return newNodeId;
-	}, [setNodes, currentPageId]);
+	}, [setNodes]);
 
 	// API 노드 생성 함수
 	const createApiNode = useCallback((apiData: ApiNodeData) => {
@@ -1044,35 +976,16 @@ function FlowCanvas() {
 		setNodes((nds: any) => [...nds, newNode]);
 		DagServiceInstance.addNode(newNode as any);
 		
-		// DB에 저장 (백그라운드)
-		console.log('[createApiNode] Saving to DB:', { newNodeId, currentPageId });
-		if (currentPageId) {
-			invoke('create_node_command', {
-				id: newNodeId,
-				fkPageId: currentPageId,
-				data: JSON.stringify(apiData),
-				nodeType: 'apiNode',
-				positionX: Math.round(position.x),
-				positionY: Math.round(position.y)
-			}).then(() => {
-				console.log('[createApiNode] Successfully saved to DB:', newNodeId);
-			}).catch(error => {
-				console.error('[createApiNode] Failed to save API node to DB:', error);
-			});
-		} else {
-			console.warn('[createApiNode] No currentPageId, skipping DB save');
-		}
+		// DB 저장은 페이지 그래프 저장(debounce)에서 처리
 		
 		return newNodeId;
-	}, [setNodes, currentPageId]);
+	}, [setNodes]);
 
 	// API 노드 업데이트 함수
 	const updateApiNode = useCallback((nodeId: string, apiData: ApiNodeData) => {
-		let nodePosition = { x: 0, y: 0 };
 		setNodes((nds: any) => 
 			nds.map((node: any) => {
 				if (node.id === nodeId) {
-					nodePosition = node.position;
 					return { ...node, data: apiData };
 				}
 				return node;
@@ -1085,19 +998,7 @@ function FlowCanvas() {
 			data: apiData
 		};
 		DagServiceInstance.updateNode(updatedNode as any);
-		
-		// DB에 저장 (백그라운드)
-		if (currentPageId) {
-			invoke('update_node_command', {
-				id: nodeId,
-				fkPageId: currentPageId,
-				data: JSON.stringify(apiData),
-				nodeType: 'apiNode',
-				positionX: Math.round(nodePosition.x),
-				positionY: Math.round(nodePosition.y)
-			}).catch(error => console.error('Failed to update API node in DB:', error));
-		}
-	}, [setNodes, currentPageId]);
+	}, [setNodes]);
 
 	// DB 노드 생성 함수
 	const createDbNode = useCallback((dbData: DbNodeData) => {
@@ -1113,35 +1014,16 @@ function FlowCanvas() {
 		setNodes((nds: any) => [...nds, newNode]);
 		DagServiceInstance.addNode(newNode as any);
 		
-		// DB에 저장 (백그라운드)
-		console.log('[createDbNode] Saving to DB:', { newNodeId, currentPageId });
-		if (currentPageId) {
-			invoke('create_node_command', {
-				id: newNodeId,
-				fkPageId: currentPageId,
-				data: JSON.stringify(dbData),
-				nodeType: 'dbNode',
-				positionX: Math.round(position.x),
-				positionY: Math.round(position.y)
-			}).then(() => {
-				console.log('[createDbNode] Successfully saved to DB:', newNodeId);
-			}).catch(error => {
-				console.error('[createDbNode] Failed to save DB node to DB:', error);
-			});
-		} else {
-			console.warn('[createDbNode] No currentPageId, skipping DB save');
-		}
+		// DB 저장은 페이지 그래프 저장(debounce)에서 처리
 		
 		return newNodeId;
-	}, [setNodes, currentPageId]);
+	}, [setNodes]);
 
 	// DB 노드 업데이트 함수
 	const updateDbNode = useCallback((nodeId: string, dbData: DbNodeData) => {
-		let nodePosition = { x: 0, y: 0 };
 		setNodes((nds: any) => 
 			nds.map((node: any) => {
 				if (node.id === nodeId) {
-					nodePosition = node.position;
 					return { ...node, data: dbData };
 				}
 				return node;
@@ -1154,19 +1036,45 @@ function FlowCanvas() {
 			data: dbData
 		};
 		DagServiceInstance.updateNode(updatedNode as any);
+	}, [setNodes]);
+
+	// 서브플로우 노드 생성 함수
+	const createSubflowNode = useCallback((subflowData: SubflowNodeData) => {
+		const newNodeId = `subflow-node-${Date.now()}`;
+		const position = { x: Math.random() * 400 + 100, y: Math.random() * 400 + 100 };
+		const newNode: any = {
+			id: newNodeId,
+			type: 'subflowNode',
+			position,
+			data: subflowData
+		};
 		
-		// DB에 저장 (백그라운드)
-		if (currentPageId) {
-			invoke('update_node_command', {
-				id: nodeId,
-				fkPageId: currentPageId,
-				data: JSON.stringify(dbData),
-				nodeType: 'dbNode',
-				positionX: Math.round(nodePosition.x),
-				positionY: Math.round(nodePosition.y)
-			}).catch(error => console.error('Failed to update DB node in DB:', error));
-		}
-	}, [setNodes, currentPageId]);
+		setNodes((nds: any) => [...nds, newNode]);
+		DagServiceInstance.addNode(newNode as any);
+		
+		// DB 저장은 페이지 그래프 저장(debounce)에서 처리
+		
+		return newNodeId;
+	}, [setNodes]);
+
+	// 서브플로우 노드 업데이트 함수
+	const updateSubflowNode = useCallback((nodeId: string, subflowData: SubflowNodeData) => {
+		setNodes((nds: any) => 
+			nds.map((node: any) => {
+				if (node.id === nodeId) {
+					return { ...node, data: subflowData };
+				}
+				return node;
+			})
+		);
+		// DagService에도 업데이트 반영
+		const updatedNode = {
+			id: nodeId,
+			type: 'subflowNode',
+			data: subflowData
+		};
+		DagServiceInstance.updateNode(updatedNode as any);
+	}, [setNodes]);
 
 	// 노드 더블클릭 핸들러
 	const onNodeDoubleClick = useCallback((_event: React.MouseEvent, node: Node) => {
@@ -1176,6 +1084,9 @@ function FlowCanvas() {
 		} else if (node.type === 'dbNode') {
 			setEditingDbNode({ nodeId: node.id, data: node.data as unknown as DbNodeData });
 			setDbEditorOpen(true);
+		} else if (node.type === 'subflowNode') {
+			setEditingSubflowNode({ nodeId: node.id, data: node.data as unknown as SubflowNodeData });
+			setSubflowEditorOpen(true);
 		}
 	}, []);
 
@@ -1216,11 +1127,30 @@ function FlowCanvas() {
 		setEditingDbNode(null);
 	}, []);
 
+	// Sub-flow Editor 저장 핸들러
+	const handleSubflowEditorSave = useCallback((data: SubflowNodeData) => {
+		if (editingSubflowNode) {
+			// 편집 모드
+			updateSubflowNode(editingSubflowNode.nodeId, data);
+		} else {
+			// 생성 모드
+			createSubflowNode(data);
+		}
+		setSubflowEditorOpen(false);
+		setEditingSubflowNode(null);
+	}, [editingSubflowNode, updateSubflowNode, createSubflowNode]);
+
+	const handleSubflowEditorClose = useCallback(() => {
+		setSubflowEditorOpen(false);
+		setEditingSubflowNode(null);
+	}, []);
+
 	const nodeTypes = useMemo(
 		() => ({
 			languageNode: (nodeProps: any) => <FileNode {...nodeProps} setNodes={setNodes} setEdges={setEdges} />,
 			apiNode: (nodeProps: any) => <ApiNode {...nodeProps} setNodes={setNodes} setEdges={setEdges} />,
-			dbNode: (nodeProps: any) => <DbNode {...nodeProps} setNodes={setNodes} setEdges={setEdges} />
+			dbNode: (nodeProps: any) => <DbNode {...nodeProps} setNodes={setNodes} setEdges={setEdges} />,
+			subflowNode: (nodeProps: any) => <SubflowNode {...nodeProps} setNodes={setNodes} setEdges={setEdges} />
 		}),
 		[setNodes, setEdges]
 	);
@@ -1240,17 +1170,7 @@ function FlowCanvas() {
 			try {
 			  DagServiceInstance.setEdgeData(newEdges);
 			  console.log("Edge connection successful:", connection);
-			  
-			  // DB에 저장 (백그라운드)
-			  if (currentPageId && connection.source && connection.target) {
-				invoke('create_edge_command', {
-					fkPageId: currentPageId,
-					source: connection.source,
-					target: connection.target,
-					sourceHandle: connection.sourceHandle || null,
-					targetHandle: connection.targetHandle || null
-				}).catch(error => console.error('Failed to save edge to DB:', error));
-			  }
+			  // DB 저장은 페이지 그래프 저장(debounce)에서 처리
 			} catch (error) {
 			  console.error("Failed to add edge:", error);
 			  // 순환 참조 등의 오류가 발생하면 엣지 추가를 취소
@@ -1260,7 +1180,7 @@ function FlowCanvas() {
 			return newEdges;
 		  });
 		},
-		[setEdges, currentPageId]
+		[setEdges]
 	  );
 
 	// 핸들을 빈 공간에 놓았을 때 새 노드 생성
@@ -1390,6 +1310,11 @@ function FlowCanvas() {
 													setDbEditorOpen(true);
 													setFlowContextMenu(null);
 												}}
+												onSubflowNode={() => {
+													setEditingSubflowNode(null);
+													setSubflowEditorOpen(true);
+													setFlowContextMenu(null);
+												}}
 												onClose={() => setFlowContextMenu(null)}
 											/>
 										)}
@@ -1435,6 +1360,14 @@ function FlowCanvas() {
 				mode={editingDbNode ? 'edit' : 'create'}
 				nodeId={editingDbNode?.nodeId}
 			/>
+			<SubflowNodeEditor
+				isOpen={subflowEditorOpen}
+				onClose={handleSubflowEditorClose}
+				initialData={editingSubflowNode?.data}
+				onSave={handleSubflowEditorSave}
+				mode={editingSubflowNode ? 'edit' : 'create'}
+				currentPageId={currentPageId}
+			/>
 			<NodeCreationDialog
 				isOpen={nodeCreationDialogOpen}
 				onClose={() => setNodeCreationDialogOpen(false)}
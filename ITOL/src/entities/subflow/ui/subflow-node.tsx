@@ -0,0 +1,164 @@
+import { Button } from "@/shared/components/ui/button";
+import { cn } from "@/shared/lib/utils";
+import { Handle, Position, Node, useReactFlow } from "@xyflow/react";
+import { Workflow, Play, CheckCircle2, XCircle, Clock, ChevronDown, ChevronUp, Trash2 } from "lucide-react";
+import { useState } from "react";
+import { Badge } from "@/shared/components/ui/badge";
+import type { SubflowNodeData } from "@/shared/components/settings-modal/types";
+import { useNodeStore } from "@/shared/store/use-node-store";
+import { DagServiceInstance } from "@/features/dag/services/dag.service";
+
+interface SubflowNodeProps {
+  data: SubflowNodeData;
+  id: string;
+  setNodes?: React.Dispatch<React.SetStateAction<Node<any>[]>>;
+  setEdges?: React.Dispatch<React.SetStateAction<any[]>>;
+}
+
+function SubflowNode(props: SubflowNodeProps) {
+  const { data, id } = props;
+  const [isCollapsed, setIsCollapsed] = useState(false);
+  const { nodeResults, removeNodeResult } = useNodeStore();
+  const { deleteElements } = useReactFlow();
+
+  const currentResult = nodeResults[id];
+  const isRunning = currentResult?.status === 'running';
+  const lastResult = currentResult?.status === 'success' ? 'success' : currentResult?.status === 'error' ? 'error' : null;
+  const enabledInputs = data.inputs.filter(input => input.enabled && input.key);
+
+  const handleRun = async () => {
+    // DAG 서비스를 통해 실행하여 순차 실행 로직을 사용
+    await DagServiceInstance.runNode(id);
+  };
+
+  const handleDelete = () => {
+    deleteElements({ nodes: [{ id }] });
+    DagServiceInstance.removeNode(id);
+    removeNodeResult(id);
+  };
+
+  return (
+    <div
+      className={cn(
+        "rounded-lg border-2 bg-white shadow-lg min-w-[260px] transition-all",
+        isRunning && "border-blue-400 shadow-blue-200",
+        lastResult === 'success' && "border-green-400 shadow-green-200",
+        lastResult === 'error' && "border-red-400 shadow-red-200",
+        !isRunning && !lastResult && "border-gray-300"
+      )}
+    >
+      <Handle
+        type="target"
+        position={Position.Left}
+        className="w-3 h-3 bg-purple-500"
+      />
+
+      {/* Header */}
+      <div className="flex items-center justify-between p-3 border-b bg-gradient-to-r from-purple-50 to-fuchsia-50">
+        <div className="flex items-center gap-2 flex-1">
+          <Workflow className="h-5 w-5 text-purple-600" />
+          <div className="flex-1">
+            <div className="font-semibold text-sm text-gray-800">{data.name}</div>
+            <div className="flex items-center gap-1 mt-1">
+              <Badge variant="secondary" className="text-xs bg-purple-500 hover:bg-purple-600">
+                Sub-flow
+              </Badge>
+              {isRunning && (
+                <Clock className="h-3 w-3 text-blue-500 animate-spin" />
+              )}
+              {lastResult === 'success' && (
+                <CheckCircle2 className="h-3 w-3 text-green-500" />
+              )}
+              {lastResult === 'error' && (
+                <XCircle className="h-3 w-3 text-red-500" />
+              )}
+            </div>
+          </div>
+        </div>
+        <div className="flex items-center gap-1">
+          <Button
+            size="sm"
+            variant="ghost"
+            className="p-1 h-6 w-6 hover:bg-red-100 hover:text-red-600"
+            onClick={(e) => {
+              e.stopPropagation();
+              handleDelete();
+            }}
+            title="Delete node"
+          >
+            <Trash2 className="h-3.5 w-3.5" />
+          </Button>
+          <Button
+            size="sm"
+            variant="ghost"
+            onClick={() => setIsCollapsed(!isCollapsed)}
+            className="p-1 h-6 w-6"
+          >
+            {isCollapsed ? <ChevronDown className="h-4 w-4" /> : <ChevronUp className="h-4 w-4" />}
+          </Button>
+        </div>
+      </div>
+
+      {/* Body */}
+      {!isCollapsed && (
+        <div className="p-3 space-y-2">
+          <div className="text-xs space-y-1">
+            <div className="text-gray-500">Page:</div>
+            <div className="font-mono text-gray-700">
+              {data.pageId !== null ? `#${data.pageId}` : 'No page selected'}
+            </div>
+          </div>
+
+          {enabledInputs.length > 0 && (
+            <div className="text-xs space-y-1">
+              <div className="text-gray-500">Inputs:</div>
+              {enabledInputs.map(input => (
+                <div key={input.key} className="font-mono text-gray-700 truncate" title={input.value}>
+                  {input.key} = {input.value}
+                </div>
+              ))}
+            </div>
+          )}
+
+          {data.outputNodeId && (
+            <div className="text-xs text-gray-500">Output: {data.outputNodeId}</div>
+          )}
+
+          {/* Status Message */}
+          {currentResult && (
+            <div className={cn(
+              "text-xs p-2 rounded",
+              currentResult.status === 'success' && "bg-green-50 text-green-700",
+              currentResult.status === 'error' && "bg-red-50 text-red-700",
+              currentResult.status === 'running' && "bg-blue-50 text-blue-700"
+            )}>
+              {currentResult.status === 'running' && 'Running sub-flow...'}
+              {currentResult.status === 'success' && 'Sub-flow completed successfully'}
+              {currentResult.status === 'error' && currentResult.error}
+            </div>
+          )}
+
+          <div className="flex gap-2 pt-2">
+            <Button
+              size="sm"
+              onClick={handleRun}
+              disabled={isRunning}
+              className="flex-1"
+            >
+              <Play className="h-3 w-3 mr-1" />
+              {isRunning ? 'Running...' : 'Run'}
+            </Button>
+          </div>
+        </div>
+      )}
+
+      <Handle
+        type="source"
+        position={Position.Right}
+        className="w-3 h-3 bg-purple-500"
+      />
+    </div>
+  );
+}
+
+export default SubflowNode;
@@ -9,7 +9,8 @@ import { resolveAllParameters, getAvailableNodeReferences, getAvailableNodeRefer
 import { useLogStore } from "@/shared/store/use-log-store";
 import { ApiExecutionService } from "./api-execution.service";
 import { DbExecutionService } from "./db-execution.service";
-import type { ApiNodeData, DbNodeData } from "@/shared/components/settings-modal/types";
+import { SubflowExecutionService } from "./subflow-execution.service";
+import type { ApiNodeData, DbNodeData, SubflowNodeData } from "@/shared/components/settings-modal/types";
 
 /**
  * DagService is a singleton service that manages the Directed Acyclic Graph (DAG) data.
@@ -25,6 +26,9 @@ class DagService {
   private indgreeMap = new Map<string, number>();
   private nextNodeQueue: string[] = [];
 
+  // 현재 그래프가 속한 페이지 (실행 결과 디렉터리 결정에 사용)
+  private pageId: number | null = null;
+
   private graphNodeData: Node<any>[] = [];
   private graphEdgeData: Edge[] = [
 
@@ -141,8 +145,9 @@ class DagService {
     }
   }
 
-  public setNodesAndEdges(nodes: Node<any>[], edges: Edge[]): void {
+  public setNodesAndEdges(nodes: Node<any>[], edges: Edge[], pageId: number | null = null): void {
     console.log('[setNodesAndEdges] Clearing existing data...');
+    this.pageId = pageId;
     // Clear existing data
     this.graphNodeData = [];
     this.graphEdgeData = [];
@@ -268,7 +273,7 @@ class DagService {
       if ((node.data as any).type === 'db') {
         console.log(`Running DB node with id: ${nodeId}`);
         try {
-          await DbExecutionService.executeDbNode(nodeId, node.data as DbNodeData);
+          await DbExecutionService.executeDbNode(nodeId, node.data as DbNodeData, this.pageId);
           
           // 현재 노드 실행 완료 후 다음 노드들을 실행 (중복 제거)
           const nextNodeIds = [...new Set(this.getNextNodeIds(nodeId))];
@@ -284,6 +289,26 @@ class DagService {
         return;
       }
       
+      // 서브플로우 노드 타입 체크
+      if ((node.data as any).type === 'subflow') {
+        console.log(`Running sub-flow node with id: ${nodeId}`);
+        try {
+          await SubflowExecutionService.executeSubflowNode(nodeId, node.data as SubflowNodeData, this.pageId);
+          
+          // 현재 노드 실행 완료 후 다음 노드들을 실행 (중복 제거)
+          const nextNodeIds = [...new Set(this.getNextNodeIds(nodeId))];
+          console.log(`Next nodes to execute: ${nextNodeIds}`);
+          
+          for (const nextNodeId of nextNodeIds) {
+            console.log(`Triggering next node with id: ${nextNodeId}`);
+            await DagServiceInstance.runNode(nextNodeId);
+          }
+        } catch (error) {
+          console.error(`Sub-flow node ${nodeId} execution failed:`, error);
+        }
+        return;
+      }
+      
       // File 노드 실행 로직
       if (!node.data.filePath || !node.data.fileName || !node.data.fileExtension) {
         console.error(`Invalid file node data for node ${nodeId}`);
@@ -7,7 +7,7 @@ import { extractValueFromPath } from '../utils/node-reference.utils';
 /**
  * {{nodeId.field}} 형식의 참조를 실제 값으로 치환합니다.
  */
-function resolveReferences(value: string): string {
+export function resolveReferences(value: string): string {
   if (!value) return value;
   
   const referencePattern = /\{\{([^}]+)\}\}/g;
@@ -50,9 +50,10 @@ export class DbExecutionService {
    * DB 노드를 실행합니다.
    * @param nodeId 노드 ID
    * @param data DB 노드 데이터
+   * @param pageId 노드가 속한 페이지 ID (결과 파일을 저장할 실행 디렉터리 결정)
    * @returns 실행 결과
    */
-  static async executeDbNode(nodeId: string, data: DbNodeData): Promise<any> {
+  static async executeDbNode(nodeId: string, data: DbNodeData, pageId: number | null): Promise<any> {
     const startTime = Date.now();
     
     // Set running state
@@ -78,6 +79,9 @@ export class DbExecutionService {
       if (!resolvedQuery || resolvedQuery.trim() === '') {
         throw new Error('Query is empty');
       }
+      if (data.outputFormat && pageId === null) {
+        throw new Error('Result files are saved per page. Open the page before running this node.');
+      }
 
       useLogStore.getState().addLog({
         nodeId: nodeId,
@@ -95,8 +99,15 @@ export class DbExecutionService {
           timeout: data.timeout ? Math.floor(data.timeout / 1000) : 30,
           max_rows: data.maxRows || 1000,
           project_id: null,
-          page_id: 1,
-          run_id: runId
+          page_id: pageId ?? 0,
+          run_id: runId,
+          node_name: data.name,
+          output_format: data.outputFormat ?? null,
+          mode: data.oracleMode ?? null,
+          binds: data.binds?.map(bind => ({
+            ...bind,
+            value: typeof bind.value === 'string' ? resolveReferences(bind.value) : bind.value ?? null
+          })) ?? null
         }
       });
 
@@ -0,0 +1,122 @@
+import type { SubflowNodeData } from "@/shared/components/settings-modal/types";
+import { useNodeStore } from "@/shared/store/use-node-store";
+import { useLogStore } from "@/shared/store/use-log-store";
+import { invoke } from '@tauri-apps/api/core';
+import { resolveReferences } from './db-execution.service';
+
+/** run_subflow_command가 반환하는 노드 실행 결과 */
+interface SubflowRunResult {
+  status: 'passed' | 'failed' | 'error' | 'skipped';
+  durationMs: number;
+  output: { page: string; runDir: string; outputNodeId: string | null; result: any } | null;
+  error: string | null;
+  logs: string[];
+}
+
+/**
+ * 서브플로우 노드를 실행하는 서비스
+ * 다른 페이지를 백엔드 플로우 실행기로 실행하고, 그 페이지의 출력 노드 결과를 이 노드의 결과로 사용합니다.
+ */
+export class SubflowExecutionService {
+
+  /**
+   * 서브플로우 노드를 실행합니다.
+   * @param nodeId 노드 ID
+   * @param data 서브플로우 노드 데이터
+   * @param pageId 노드가 속한 페이지 ID (순환 호출 확인과 실행 디렉터리 결정)
+   * @returns 호출된 페이지의 출력 노드 결과
+   */
+  static async executeSubflowNode(nodeId: string, data: SubflowNodeData, pageId: number | null): Promise<any> {
+    const startTime = Date.now();
+
+    useNodeStore.getState().setNodeResult(nodeId, {
+      nodeId: nodeId,
+      nodeName: data.name,
+      status: 'running',
+      data: null,
+      error: null,
+    });
+
+    try {
+      if (data.pageId === null) {
+        throw new Error('Sub-flow page is required');
+      }
+      if (pageId === null) {
+        throw new Error('Open the page before running this node.');
+      }
+
+      useLogStore.getState().addLog({
+        nodeId: nodeId,
+        nodeName: data.name,
+        type: 'info',
+        message: `🔁 Starting sub-flow: page ${data.pageId}`,
+      });
+
+      // 입력 값의 {{nodeId.path}} 참조는 이 페이지의 실행 결과로 먼저 치환
+      const result = await invoke<SubflowRunResult>('run_subflow_command', {
+        node: {
+          id: nodeId,
+          type: 'subflowNode',
+          data: {
+            ...data,
+            inputs: data.inputs.map(input => ({ ...input, value: resolveReferences(input.value) })),
+          },
+        },
+        callerPageId: pageId,
+        runId: `run_${Date.now()}`,
+      });
+
+      result.logs.forEach(log => useLogStore.getState().addLog({
+        nodeId: nodeId,
+        nodeName: data.name,
+        type: 'info',
+        message: log,
+      }));
+      if (result.status !== 'passed') {
+        throw new Error(result.error || `Sub-flow ${result.status}`);
+      }
+
+      const executionTime = Date.now() - startTime;
+      useLogStore.getState().addLog({
+        nodeId: nodeId,
+        nodeName: data.name,
+        type: 'success',
+        message: `✅ Sub-flow '${result.output?.page}' completed (${executionTime}ms)`,
+      });
+
+      useNodeStore.getState().setNodeResult(nodeId, {
+        nodeId: nodeId,
+        nodeName: data.name,
+        status: 'success',
+        data: result.output?.result ?? null,
+        error: null,
+        executionTime,
+        stdout: JSON.stringify(result.output, null, 2),
+      });
+
+      return result.output?.result ?? null;
+
+    } catch (error: any) {
+      const executionTime = Date.now() - startTime;
+
+      useLogStore.getState().addLog({
+        nodeId: nodeId,
+        nodeName: data.name,
+        type: 'error',
+        message: `❌ Sub-flow failed: ${error.message || error}`,
+      });
+
+      useNodeStore.getState().setNodeResult(nodeId, {
+        nodeId: nodeId,
+        nodeName: data.name,
+        status: 'error',
+        data: null,
+        error: error.message || error.toString() || 'Unknown error occurred',
+        executionTime,
+        stderr: error.stack || error.message || error.toString(),
+      });
+
+      throw error;
+    }
+  }
+}
@@ -353,7 +353,7 @@ export const ConnectionManagementSection: React.FC = () => {
 
               <div className="flex items-center space-x-2">
                 <Switch
-                  checked={formData.connection.sslMode || false}
+                  checked={!!formData.connection.sslMode && formData.connection.sslMode !== 'disable'}
                   onCheckedChange={(checked) => updateConnection('sslMode', checked)}
                 />
                 <Label>Enable SSL</Label>
@@ -1,4 +1,4 @@
-import { FileText, Database, Zap } from 'lucide-react';
+import { FileText, Database, Zap, Workflow } from 'lucide-react';
 import { createPortal } from 'react-dom';
 import { useEffect } from 'react';
 
@@ -8,6 +8,7 @@ interface ContextMenuProps {
   onFileNode: () => void;
   onApiNode: () => void;
   onDbNode: () => void;
+  onSubflowNode: () => void;
   onClose: () => void;
 }
 
@@ -17,6 +18,7 @@ export function ContextMenu({
   onFileNode,
   onApiNode,
   onDbNode,
+  onSubflowNode,
   onClose,
 }: ContextMenuProps) {
   useEffect(() => {
@@ -129,6 +131,31 @@ export function ContextMenu({
           <Database style={{ width: '16px', height: '16px' }} />
           DB Node
         </button>
+
+        <button
+          type="button"
+          style={{
+            width: '100%',
+            display: 'flex',
+            alignItems: 'center',
+            gap: '8px',
+            padding: '10px 16px',
+            fontSize: '14px',
+            textAlign: 'left',
+            border: 'none',
+            backgroundColor: 'transparent',
+            cursor: 'pointer',
+          }}
+          onMouseEnter={(e) => e.currentTarget.style.backgroundColor = '#f3f4f6'}
+          onMouseLeave={(e) => e.currentTarget.style.backgroundColor = 'transparent'}
+          onClick={() => {
+            onSubflowNode();
+            onClose();
+          }}
+        >
+          <Workflow style={{ width: '16px', height: '16px' }} />
+          Sub-flow Node
+        </button>
       </div>
     </>,
     document.body
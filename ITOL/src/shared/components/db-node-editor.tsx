@@ -446,7 +446,7 @@ export const DbNodeEditor: React.FC<DbNodeEditorProps> = ({
 
                   <div className="flex items-center space-x-2">
                     <Switch
-                      checked={data.connection.sslMode || false}
+                      checked={!!data.connection.sslMode && data.connection.sslMode !== 'disable'}
                       onCheckedChange={(checked: any) => updateConnection('sslMode', checked)}
                     />
                     <Label>Enable SSL</Label>
@@ -1,10 +1,13 @@
 import React, { useState, useEffect } from 'react';
 import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import { open } from '@tauri-apps/plugin-dialog';
 import { Dialog, DialogContent, DialogHeader, DialogTitle, DialogFooter } from './ui/dialog';
 import { Button } from './ui/button';
 import { Alert, AlertDescription } from './ui/alert';
 import { Progress } from './ui/progress';
-import { CheckCircle2, Download, AlertCircle, ExternalLink } from 'lucide-react';
+import { Input } from './ui/input';
+import { CheckCircle2, Download, AlertCircle, ExternalLink, FolderOpen, Trash2 } from 'lucide-react';
 
 interface OracleInstallStatus {
   installed: boolean;
@@ -12,6 +15,23 @@ interface OracleInstallStatus {
   path: string | null;
 }
 
+interface InstallProgress {
+  phase: 'download' | 'extract';
+  bytes: number;
+  total: number | null;
+  bytesPerSecond: number;
+}
+
+interface OracleClientInstall {
+  version: string;
+  path: string;
+  active: boolean;
+}
+
+interface OracleClientActivation extends OracleClientInstall {
+  restartRequired: boolean;
+}
+
 interface OracleInstallerDialogProps {
   isOpen: boolean;
   onClose: () => void;
@@ -26,8 +46,12 @@ export const OracleInstallerDialog: React.FC<OracleInstallerDialogProps> = ({
   const [status, setStatus] = useState<OracleInstallStatus | null>(null);
   const [isInstalling, setIsInstalling] = useState(false);
   const [installProgress, setInstallProgress] = useState(0);
+  const [progressDetail, setProgressDetail] = useState<InstallProgress | null>(null);
   const [error, setError] = useState<string | null>(null);
   const [installComplete, setInstallComplete] = useState(false);
+  const [installs, setInstalls] = useState<OracleClientInstall[]>([]);
+  const [archiveChecksum, setArchiveChecksum] = useState('');
+  const [restartVersion, setRestartVersion] = useState<string | null>(null);
 
   useEffect(() => {
     if (isOpen) {
@@ -35,10 +59,27 @@ export const OracleInstallerDialog: React.FC<OracleInstallerDialogProps> = ({
     }
   }, [isOpen]);
 
+  // 백엔드 진행률 이벤트 (다운로드 0~70%, 압축 해제 70~100%)
+  useEffect(() => {
+    if (!isOpen) return;
+    const unlisten = listen<InstallProgress>('oracle-install-progress', (event) => {
+      const progress = event.payload;
+      const ratio = progress.total ? progress.bytes / progress.total : 0;
+      setProgressDetail(progress);
+      setInstallProgress(progress.phase === 'download' ? Math.round(ratio * 70) : 70 + Math.round(ratio * 30));
+    });
+    return () => {
+      unlisten.then(fn => fn());
+    };
+  }, [isOpen]);
+
+  const formatBytes = (bytes: number) => `${(bytes / 1024 / 1024).toFixed(1)} MB`;
+
   const checkInstallation = async () => {
     try {
       const result = await invoke<OracleInstallStatus>('check_oracle_installed');
       setStatus(result);
+      setInstalls(await invoke<OracleClientInstall[]>('list_oracle_clients_command'));
     } catch (err: any) {
       console.error('Failed to check Oracle installation:', err);
       setError(err.message || 'Failed to check installation');
@@ -48,23 +89,12 @@ export const OracleInstallerDialog: React.FC<OracleInstallerDialogProps> = ({
   const handleInstall = async () => {
     setIsInstalling(true);
     setError(null);
-    setInstallProgress(10);
+    setInstallProgress(0);
+    setProgressDetail(null);
 
     try {
-      // 다운로드 시뮬레이션 (실제로는 백엔드에서 진행)
-      const progressInterval = setInterval(() => {
-        setInstallProgress(prev => {
-          if (prev >= 90) {
-            clearInterval(progressInterval);
-            return 90;
-          }
-          return prev + 10;
-        });
-      }, 1000);
-
       await invoke<string>('install_oracle_client');
-      
-      clearInterval(progressInterval);
+
       setInstallProgress(100);
       setInstallComplete(true);
       
@@ -82,6 +112,50 @@ export const OracleInstallerDialog: React.FC<OracleInstallerDialogProps> = ({
     }
   };
 
+  const handleInstallFromZip = async () => {
+    const selected = await open({
+      multiple: false,
+      filters: [{ name: 'Oracle Instant Client', extensions: ['zip'] }]
+    });
+    if (!selected || typeof selected !== 'string') return;
+
+    setIsInstalling(true);
+    setError(null);
+    try {
+      await invoke<OracleClientInstall>('install_oracle_client_from_zip_command', {
+        params: { zipPath: selected, sha256: archiveChecksum.trim() || null, activate: true }
+      });
+      setInstallComplete(true);
+      await checkInstallation();
+      onInstallComplete?.();
+    } catch (err: any) {
+      console.error('Installation from archive failed:', err);
+      setError(typeof err === 'string' ? err : err.message || 'Installation failed');
+    } finally {
+      setIsInstalling(false);
+    }
+  };
+
+  const handleActivate = async (version: string) => {
+    try {
+      const activation = await invoke<OracleClientActivation>('set_active_oracle_client_command', { version });
+      // 이미 로드된 Instant Client는 바꿀 수 없으므로 재시작 후 적용됨
+      setRestartVersion(activation.restartRequired ? activation.version : null);
+      await checkInstallation();
+    } catch (err: any) {
+      setError(typeof err === 'string' ? err : err.message || 'Failed to activate version');
+    }
+  };
+
+  const handleUninstall = async (version: string) => {
+    try {
+      await invoke('uninstall_oracle_client_command', { version });
+      await checkInstallation();
+    } catch (err: any) {
+      setError(typeof err === 'string' ? err : err.message || 'Failed to uninstall');
+    }
+  };
+
   const handleManualInstall = () => {
     // ORACLE_SETUP.md 페이지로 이동하거나 외부 링크 열기
     window.open('https://www.oracle.com/database/technologies/instant-client/downloads.html', '_blank');
@@ -122,6 +196,31 @@ export const OracleInstallerDialog: React.FC<OracleInstallerDialogProps> = ({
             </Alert>
           )}
 
+          {/* 설치된 버전 목록 */}
+          {installs.length > 0 && (
+            <div className="border rounded-lg divide-y">
+              {installs.map(install => (
+                <div key={install.version} className="flex items-center justify-between p-2 text-sm">
+                  <div>
+                    <span className="font-medium">{install.version}</span>
+                    {install.active && <span className="ml-2 text-xs text-green-700">(active)</span>}
+                    <p className="text-xs text-gray-500">{install.path}</p>
+                  </div>
+                  <div className="flex gap-2">
+                    {!install.active && (
+                      <Button size="sm" variant="outline" onClick={() => handleActivate(install.version)}>
+                        Use
+                      </Button>
+                    )}
+                    <Button size="sm" variant="outline" onClick={() => handleUninstall(install.version)}>
+                      <Trash2 className="h-4 w-4" />
+                    </Button>
+                  </div>
+                </div>
+              ))}
+            </div>
+          )}
+
           {/* 설명 */}
           {!status?.installed && (
             <div className="bg-blue-50 border border-blue-200 rounded-lg p-4">
@@ -152,10 +251,18 @@ export const OracleInstallerDialog: React.FC<OracleInstallerDialogProps> = ({
                   <div className="space-y-2 mb-3">
                     <Progress value={installProgress} className="w-full" />
                     <p className="text-xs text-gray-500">
-                      {installProgress < 30 && 'Downloading Oracle Instant Client...'}
-                      {installProgress >= 30 && installProgress < 80 && 'Extracting files...'}
-                      {installProgress >= 80 && installProgress < 100 && 'Setting up environment...'}
-                      {installProgress === 100 && 'Installation complete!'}
+                      {installProgress === 100
+                        ? 'Installation complete!'
+                        : progressDetail?.phase === 'extract'
+                          ? 'Extracting files...'
+                          : 'Downloading Oracle Instant Client...'}
+                      {progressDetail && installProgress < 100 && (
+                        <>
+                          {' '}{formatBytes(progressDetail.bytes)}
+                          {progressDetail.total ? ` / ${formatBytes(progressDetail.total)}` : ''}
+                          {` (${formatBytes(progressDetail.bytesPerSecond)}/s)`}
+                        </>
+                      )}
                     </p>
                   </div>
                 )}
@@ -169,10 +276,35 @@ export const OracleInstallerDialog: React.FC<OracleInstallerDialogProps> = ({
                 </Button>
               </div>
 
+              <div className="border rounded-lg p-4">
+                <h4 className="font-semibold mb-2 flex items-center gap-2">
+                  <FolderOpen className="h-4 w-4" />
+                  Option 2: Install from Local Archive (Offline)
+                </h4>
+                <p className="text-sm text-gray-600 mb-3">
+                  Select an Instant Client Basic zip that was downloaded on another machine.
+                  The version is detected from the library files.
+                </p>
+                <Input
+                  value={archiveChecksum}
+                  onChange={(e) => setArchiveChecksum(e.target.value)}
+                  placeholder="SHA-256 checksum (optional)"
+                  className="mb-3"
+                />
+                <Button
+                  onClick={handleInstallFromZip}
+                  disabled={isInstalling}
+                  variant="outline"
+                  className="w-full"
+                >
+                  Select Zip File
+                </Button>
+              </div>
+
               <div className="border rounded-lg p-4">
                 <h4 className="font-semibold mb-2 flex items-center gap-2">
                   <ExternalLink className="h-4 w-4" />
-                  Option 2: Manual Installation
+                  Option 3: Manual Installation
                 </h4>
                 <p className="text-sm text-gray-600 mb-3">
                   Download and install Oracle Instant Client manually from Oracle's official website.
@@ -200,6 +332,16 @@ export const OracleInstallerDialog: React.FC<OracleInstallerDialogProps> = ({
             </Alert>
           )}
 
+          {/* 활성 버전 변경은 재시작 후 적용 */}
+          {restartVersion && (
+            <Alert className="border-yellow-500 bg-yellow-50">
+              <AlertCircle className="h-4 w-4 text-yellow-600" />
+              <AlertDescription className="text-yellow-800">
+                Oracle Instant Client {restartVersion} will be used after the application is restarted.
+              </AlertDescription>
+            </Alert>
+          )}
+
           {/* 에러 */}
           {error && (
             <Alert className="border-red-500 bg-red-50">
@@ -116,10 +116,32 @@ export interface DatabaseConnection {
   // Oracle specific
   serviceName?: string;
   sid?: string;
-  
+  tnsAlias?: string; // Alias from tnsnames.ora
+  tnsAdmin?: string; // Directory containing tnsnames.ora
+  connectDescriptor?: string; // Full "(DESCRIPTION=...)" descriptor
+  walletLocation?: string; // Wallet directory for TCPS
+  externalAuth?: boolean; // Use wallet/OS credentials instead of username/password
+  privilege?: 'sysdba' | 'sysoper' | 'sysasm' | 'sysbackup' | 'sysdg' | 'syskm' | 'sysrac';
+
   // PostgreSQL specific
   schema?: string;
-  sslMode?: boolean;
+  sslMode?: boolean | 'disable' | 'allow' | 'prefer' | 'require' | 'verify-ca' | 'verify-full';
+  sslRootCert?: string; // CA certificate path
+  sslClientCert?: string; // Client certificate path
+  sslClientKey?: string; // Client key path
+
+  // SSH tunnel via bastion host (PostgreSQL, Oracle, MySQL)
+  sshTunnel?: SshTunnelConfig;
+}
+
+export interface SshTunnelConfig {
+  host: string;
+  port?: number;
+  username: string;
+  password?: string;
+  privateKeyPath?: string;
+  passphrase?: string;
+  hostKeyFingerprint?: string; // e.g. "SHA256:...", omitted = must be in ~/.ssh/known_hosts
 }
 
 export interface SavedQuery {
@@ -174,6 +196,31 @@ export interface DbNodeData {
   // Execution settings
   timeout?: number; // in milliseconds
   maxRows?: number; // Limit result rows
+  outputFormat?: 'ndjson' | 'csv'; // Save full result to run directory (Oracle query mode only)
+
+  // Oracle PL/SQL execution
+  oracleMode?: OracleExecutionMode; // 'procedure' uses query as the procedure/function name
+  binds?: OracleBind[];
+}
+
+export type OracleExecutionMode = 'query' | 'plsql' | 'procedure';
+
+export interface OracleBind {
+  name: string;
+  direction?: 'in' | 'out' | 'inout' | 'return';
+  type?: 'varchar2' | 'number' | 'integer' | 'date' | 'timestamp' | 'clob' | 'refcursor';
+  value?: string | number | boolean | null;
+  size?: number; // OUT VARCHAR2 buffer size
+}
+
+// Sub-flow node: runs another page like a function
+export interface SubflowNodeData {
+  type: 'subflow';
+  name: string;
+  description?: string;
+  pageId: number | null;
+  inputs: KeyValuePair[]; // Passed as {{key}} variables to the called page
+  outputNodeId?: string; // Node of the called page whose result is returned (default: last node)
 }
 
 export interface FileNodeData {
@@ -0,0 +1,244 @@
+import React, { useState, useEffect } from 'react';
+import { Dialog, DialogContent, DialogHeader, DialogTitle } from './ui/dialog';
+import { Button } from './ui/button';
+import { Input } from './ui/input';
+import { Label } from './ui/label';
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from './ui/select';
+import { Switch } from './ui/switch';
+import { Workflow, Save, Plus, Trash2 } from 'lucide-react';
+import type { SubflowNodeData, KeyValuePair } from './settings-modal/types';
+import { invoke } from '@tauri-apps/api/core';
+
+interface SubflowNodeEditorProps {
+  isOpen: boolean;
+  onClose: () => void;
+  initialData?: SubflowNodeData;
+  onSave: (data: SubflowNodeData) => void;
+  mode: 'create' | 'edit';
+  currentPageId: number | null; // 자기 자신은 호출할 수 없으므로 목록에서 제외
+}
+
+interface PageOption {
+  id: number;
+  label: string;
+}
+
+interface NodeOption {
+  id: string;
+  label: string;
+}
+
+// Radix Select는 빈 문자열 값을 허용하지 않으므로 "마지막 노드" 선택을 별도 값으로 표시
+const LAST_NODE = '__last__';
+
+const defaultSubflowNodeData: SubflowNodeData = {
+  type: 'subflow',
+  name: 'New Sub-flow',
+  description: '',
+  pageId: null,
+  inputs: [],
+};
+
+export const SubflowNodeEditor: React.FC<SubflowNodeEditorProps> = ({
+  isOpen,
+  onClose,
+  initialData,
+  onSave,
+  mode,
+  currentPageId,
+}) => {
+  const [data, setData] = useState<SubflowNodeData>(initialData || defaultSubflowNodeData);
+  const [pages, setPages] = useState<PageOption[]>([]);
+  const [outputNodes, setOutputNodes] = useState<NodeOption[]>([]);
+
+  useEffect(() => {
+    setData(initialData || defaultSubflowNodeData);
+  }, [initialData]);
+
+  // 호출할 수 있는 페이지 목록 ("북 / 페이지")
+  useEffect(() => {
+    if (!isOpen) return;
+    const loadPages = async () => {
+      try {
+        const books = await invoke<{ id: number; title: string }[]>('get_all_books_command');
+        const options: PageOption[] = [];
+        for (const book of books) {
+          const bookPages = await invoke<{ id: number; title: string }[]>('get_pages_by_book_id_command', { bookId: book.id });
+          bookPages
+            .filter(page => page.id !== currentPageId)
+            .forEach(page => options.push({ id: page.id, label: `${book.title} / ${page.title}` }));
+        }
+        setPages(options);
+      } catch (error) {
+        console.error('Failed to load pages:', error);
+      }
+    };
+    loadPages();
+  }, [isOpen, currentPageId]);
+
+  // 선택한 페이지의 노드 (출력 노드 선택용)
+  useEffect(() => {
+    if (!isOpen || data.pageId === null) {
+      setOutputNodes([]);
+      return;
+    }
+    invoke<{ id: string; data: string }[]>('get_nodes_by_page_id_command', { pageId: data.pageId })
+      .then(nodes => setOutputNodes(nodes.map(node => {
+        let name = node.id;
+        try {
+          const parsed = JSON.parse(node.data);
+          name = parsed.name || parsed.fileName || node.id;
+        } catch {
+          // data가 JSON이 아니면 id로 표시
+        }
+        return { id: node.id, label: name === node.id ? node.id : `${name} (${node.id})` };
+      })))
+      .catch(error => console.error('Failed to load sub-flow nodes:', error));
+  }, [isOpen, data.pageId]);
+
+  const updateData = <K extends keyof SubflowNodeData>(field: K, value: SubflowNodeData[K]) => {
+    setData(prev => ({ ...prev, [field]: value }));
+  };
+
+  const updateInput = (index: number, field: keyof KeyValuePair, value: string | boolean) => {
+    const updated = [...data.inputs];
+    updated[index] = { ...updated[index], [field]: value };
+    updateData('inputs', updated);
+  };
+
+  const handleSave = () => {
+    onSave(data);
+    onClose();
+  };
+
+  return (
+    <Dialog open={isOpen} onOpenChange={onClose}>
+      <DialogContent className="max-w-2xl max-h-[90vh] overflow-y-auto">
+        <DialogHeader>
+          <DialogTitle className="flex items-center gap-2">
+            <Workflow className="h-5 w-5" />
+            {mode === 'create' ? 'Create Sub-flow Node' : 'Edit Sub-flow Node'}
+          </DialogTitle>
+        </DialogHeader>
+
+        <div className="space-y-4">
+          <div className="space-y-2">
+            <Label>Node Name</Label>
+            <Input
+              value={data.name}
+              onChange={(e: any) => updateData('name', e.target.value)}
+              placeholder="Enter node name"
+            />
+          </div>
+
+          <div className="space-y-2">
+            <Label>Description</Label>
+            <Input
+              value={data.description || ''}
+              onChange={(e: any) => updateData('description', e.target.value)}
+              placeholder="Optional description"
+            />
+          </div>
+
+          <div className="space-y-2">
+            <Label>Page</Label>
+            <Select
+              value={data.pageId !== null ? String(data.pageId) : undefined}
+              onValueChange={(v: string) => setData(prev => ({ ...prev, pageId: Number(v), outputNodeId: undefined }))}
+            >
+              <SelectTrigger>
+                <SelectValue placeholder="Select the page to run" />
+              </SelectTrigger>
+              <SelectContent>
+                {pages.map(page => (
+                  <SelectItem key={page.id} value={String(page.id)}>
+                    {page.label}
+                  </SelectItem>
+                ))}
+              </SelectContent>
+            </Select>
+          </div>
+
+          <div className="space-y-2">
+            <Label>Output Node</Label>
+            <Select
+              value={data.outputNodeId || LAST_NODE}
+              onValueChange={(v: string) => updateData('outputNodeId', v === LAST_NODE ? undefined : v)}
+              disabled={data.pageId === null}
+            >
+              <SelectTrigger>
+                <SelectValue />
+              </SelectTrigger>
+              <SelectContent>
+                <SelectItem value={LAST_NODE}>Last node of the page</SelectItem>
+                {outputNodes.map(node => (
+                  <SelectItem key={node.id} value={node.id}>
+                    {node.label}
+                  </SelectItem>
+                ))}
+              </SelectContent>
+            </Select>
+            <p className="text-xs text-gray-500">
+              The result of this node becomes the result of the sub-flow node.
+            </p>
+          </div>
+
+          <div className="space-y-2">
+            <div className="flex items-center justify-between">
+              <Label>Inputs</Label>
+              <Button
+                size="sm"
+                variant="outline"
+                onClick={() => updateData('inputs', [...data.inputs, { key: '', value: '', enabled: true }])}
+              >
+                <Plus className="h-4 w-4 mr-1" />
+                Add Input
+              </Button>
+            </div>
+            <p className="text-xs text-gray-500">
+              Inputs are available as {'{{key}}'} in the called page. Values may reference earlier nodes with {'{{nodeId.path}}'}.
+            </p>
+            {data.inputs.map((input, index) => (
+              <div key={index} className="flex items-center gap-2">
+                <Switch
+                  checked={input.enabled}
+                  onCheckedChange={(checked: boolean) => updateInput(index, 'enabled', checked)}
+                />
+                <Input
+                  value={input.key}
+                  onChange={(e: any) => updateInput(index, 'key', e.target.value)}
+                  placeholder="Key"
+                  className="flex-1"
+                />
+                <Input
+                  value={input.value}
+                  onChange={(e: any) => updateInput(index, 'value', e.target.value)}
+                  placeholder="Value"
+                  className="flex-1"
+                />
+                <Button
+                  size="sm"
+                  variant="ghost"
+                  onClick={() => updateData('inputs', data.inputs.filter((_, i) => i !== index))}
+                >
+                  <Trash2 className="h-4 w-4" />
+                </Button>
+              </div>
+            ))}
+          </div>
+
+          {/* Footer Actions */}
+          <div className="flex justify-end gap-2 pt-4">
+            <Button variant="outline" onClick={onClose}>
+              Cancel
+            </Button>
+            <Button onClick={handleSave} disabled={data.pageId === null}>
+              <Save className="h-4 w-4 mr-2" />
+              {mode === 'create' ? 'Create Node' : 'Save Changes'}
+            </Button>
+          </div>
+        </div>
+      </DialogContent>
+    </Dialog>
+  );
+};